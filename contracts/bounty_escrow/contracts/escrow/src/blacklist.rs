@@ -2,6 +2,7 @@
 //!
 //! This module provides compliance and abuse prevention features through:
 //! - **Blacklist**: Block specific addresses from locking/receiving funds (e.g., sanctioned addresses)
+//! - **Suspensions**: Time-boxed blacklist entries that lift automatically at a given timestamp
 //! - **Whitelist Mode**: Restrict to only verified participants (optional enforcement)
 //!
 //! ## Security Model
@@ -9,9 +10,18 @@
 //! - **Admin-only access**: Only contract admin can manage blacklist/whitelist
 //! - **Persistent storage**: List changes are permanent across calls
 //! - **Efficient lookups**: O(1) existence checks for both lists
-//! - **Audit trail**: Events emitted for all list modifications
+//! - **Audit trail**: Reason and actor are stored on-chain with a per-address
+//!   history of list changes, and events are emitted for all modifications
 
-use soroban_sdk::{contracttype, symbol_short, Address, Env, Map, String};
+use crate::events::{self, legacy_events_enabled, EventKind};
+use crate::sanctions;
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Map, String, Vec};
+
+/// Maximum number of history records kept per address (oldest are dropped first)
+pub const MAX_BLACKLIST_HISTORY: u32 = 50;
+
+/// Largest page served by `get_blocked_addresses`
+pub const MAX_BLOCKED_PAGE_SIZE: u32 = 100;
 
 // ============================================================================
 // Data Structures
@@ -27,6 +37,47 @@ pub struct BlacklistConfig {
     pub whitelist_mode: bool,
 }
 
+/// An active or scheduled-to-expire blacklist entry
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BlacklistEntry {
+    /// Why the address was blocked
+    pub reason: Option<String>,
+    /// Admin that blocked the address
+    pub added_by: Address,
+    /// When the address was blocked
+    pub added_at: u64,
+    /// When the block lifts automatically (`None` = until removed)
+    pub expires_at: Option<u64>,
+}
+
+/// An address together with its blacklist entry, used for paginated review
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BlockedAddress {
+    pub address: Address,
+    pub entry: BlacklistEntry,
+}
+
+/// Kind of change recorded in an address's blacklist history
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BlacklistAction {
+    Added,
+    Removed,
+}
+
+/// One change to an address's blacklist status
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BlacklistChange {
+    pub action: BlacklistAction,
+    pub reason: Option<String>,
+    pub actor: Address,
+    pub timestamp: u64,
+    pub expires_at: Option<u64>,
+}
+
 // ============================================================================
 // Events
 // ============================================================================
@@ -37,6 +88,7 @@ pub struct BlacklistConfig {
 pub struct AddressBlacklisted {
     pub address: Address,
     pub reason: Option<String>,
+    pub expires_at: Option<u64>,
     pub timestamp: u64,
 }
 
@@ -76,18 +128,49 @@ pub struct WhitelistModeToggled {
 // Emit Functions
 // ============================================================================
 
-pub fn emit_address_blacklisted(env: &Env, address: Address, reason: Option<String>) {
+pub fn emit_address_blacklisted(
+    env: &Env,
+    actor: &Address,
+    address: Address,
+    reason: Option<String>,
+    expires_at: Option<u64>,
+) {
+    events::emit_v2(
+        env,
+        EventKind::Blacklisted,
+        0,
+        actor,
+        Some(address.clone()),
+        0,
+        0,
+    );
+    if !legacy_events_enabled(env) {
+        return;
+    }
     env.events().publish(
         (symbol_short!("blklist"), symbol_short!("add")),
         AddressBlacklisted {
             address,
             reason,
+            expires_at,
             timestamp: env.ledger().timestamp(),
         },
     );
 }
 
-pub fn emit_address_unblacklisted(env: &Env, address: Address) {
+pub fn emit_address_unblacklisted(env: &Env, actor: &Address, address: Address) {
+    events::emit_v2(
+        env,
+        EventKind::Unblacklisted,
+        0,
+        actor,
+        Some(address.clone()),
+        0,
+        0,
+    );
+    if !legacy_events_enabled(env) {
+        return;
+    }
     env.events().publish(
         (symbol_short!("blklist"), symbol_short!("rm")),
         AddressUnblacklisted {
@@ -97,7 +180,19 @@ pub fn emit_address_unblacklisted(env: &Env, address: Address) {
     );
 }
 
-pub fn emit_address_whitelisted(env: &Env, address: Address) {
+pub fn emit_address_whitelisted(env: &Env, actor: &Address, address: Address) {
+    events::emit_v2(
+        env,
+        EventKind::Whitelisted,
+        0,
+        actor,
+        Some(address.clone()),
+        0,
+        0,
+    );
+    if !legacy_events_enabled(env) {
+        return;
+    }
     env.events().publish(
         (symbol_short!("whtlist"), symbol_short!("add")),
         AddressWhitelisted {
@@ -107,7 +202,19 @@ pub fn emit_address_whitelisted(env: &Env, address: Address) {
     );
 }
 
-pub fn emit_address_unwhitelisted(env: &Env, address: Address) {
+pub fn emit_address_unwhitelisted(env: &Env, actor: &Address, address: Address) {
+    events::emit_v2(
+        env,
+        EventKind::Unwhitelisted,
+        0,
+        actor,
+        Some(address.clone()),
+        0,
+        0,
+    );
+    if !legacy_events_enabled(env) {
+        return;
+    }
     env.events().publish(
         (symbol_short!("whtlist"), symbol_short!("rm")),
         AddressUnwhitelisted {
@@ -117,7 +224,16 @@ pub fn emit_address_unwhitelisted(env: &Env, address: Address) {
     );
 }
 
-pub fn emit_whitelist_mode_toggled(env: &Env, enabled: bool) {
+pub fn emit_whitelist_mode_toggled(env: &Env, actor: &Address, enabled: bool) {
+    let kind = if enabled {
+        EventKind::WhitelistModeEnabled
+    } else {
+        EventKind::WhitelistModeDisabled
+    };
+    events::emit_v2(env, kind, 0, actor, None, 0, 0);
+    if !legacy_events_enabled(env) {
+        return;
+    }
     env.events().publish(
         (symbol_short!("whtlist"), symbol_short!("mode")),
         WhitelistModeToggled {
@@ -131,55 +247,266 @@ pub fn emit_whitelist_mode_toggled(env: &Env, enabled: bool) {
 // Public Functions
 // ============================================================================
 
-/// Adds an address to the blacklist
-pub fn add_to_blacklist(env: &Env, address: Address, reason: Option<String>) {
-    let blacklist: Map<Address, Option<String>> = env
-        .storage()
-        .persistent()
-        .get(&symbol_short!("blklist"))
-        .unwrap_or(Map::new(env));
+fn set_entry(env: &Env, address: &Address, entry: &BlacklistEntry) {
+    let key = (symbol_short!("blk_entry"), address.clone());
+    if !env.storage().persistent().has(&key) {
+        index_push(env, address);
+    }
+    env.storage().persistent().set(&key, entry);
+}
 
-    let mut new_blacklist = blacklist;
-    new_blacklist.set(address.clone(), reason.clone());
+fn remove_entry(env: &Env, address: &Address) -> bool {
+    let key = (symbol_short!("blk_entry"), address.clone());
+    if !env.storage().persistent().has(&key) {
+        return false;
+    }
+    env.storage().persistent().remove(&key);
+    index_remove(env, address);
+    true
+}
 
+fn index_len(env: &Env) -> u32 {
     env.storage()
         .persistent()
-        .set(&symbol_short!("blklist"), &new_blacklist);
+        .get(&symbol_short!("blk_count"))
+        .unwrap_or(0)
+}
 
-    emit_address_blacklisted(env, address, reason);
+/// Appends an address to the dense slot index used for pagination
+fn index_push(env: &Env, address: &Address) {
+    let len = index_len(env);
+    env.storage()
+        .persistent()
+        .set(&(symbol_short!("blk_slot"), len), address);
+    env.storage()
+        .persistent()
+        .set(&(symbol_short!("blk_pos"), address.clone()), &len);
+    env.storage()
+        .persistent()
+        .set(&symbol_short!("blk_count"), &(len + 1));
 }
 
-/// Removes an address from the blacklist
-pub fn remove_from_blacklist(env: &Env, address: Address) {
-    let blacklist: Map<Address, Option<String>> = env
-        .storage()
+/// Removes an address from the slot index by moving the last slot into its place
+fn index_remove(env: &Env, address: &Address) {
+    let pos_key = (symbol_short!("blk_pos"), address.clone());
+    let pos: u32 = match env.storage().persistent().get(&pos_key) {
+        Some(pos) => pos,
+        None => return,
+    };
+    let last = index_len(env) - 1;
+    if pos != last {
+        let moved: Address = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("blk_slot"), last))
+            .unwrap();
+        env.storage()
+            .persistent()
+            .set(&(symbol_short!("blk_slot"), pos), &moved);
+        env.storage()
+            .persistent()
+            .set(&(symbol_short!("blk_pos"), moved), &pos);
+    }
+    env.storage()
+        .persistent()
+        .remove(&(symbol_short!("blk_slot"), last));
+    env.storage().persistent().remove(&pos_key);
+    env.storage()
+        .persistent()
+        .set(&symbol_short!("blk_count"), &last);
+}
+
+/// Entries written before suspensions existed (address -> reason)
+fn get_legacy_blacklist(env: &Env) -> Map<Address, Option<String>> {
+    env.storage()
         .persistent()
         .get(&symbol_short!("blklist"))
-        .unwrap_or(Map::new(env));
+        .unwrap_or(Map::new(env))
+}
+
+fn record_change(env: &Env, address: &Address, change: BlacklistChange) {
+    let key = (symbol_short!("blk_hist"), address.clone());
+    let mut history = get_blacklist_history(env, address);
+    if history.len() >= MAX_BLACKLIST_HISTORY {
+        history.remove(0);
+    }
+    history.push_back(change);
+    env.storage().persistent().set(&key, &history);
+}
+
+/// Adds an address to the blacklist
+///
+/// With `expires_at` set the address is only blocked until that timestamp;
+/// otherwise it stays blocked until removed. Re-adding an address replaces
+/// its current entry.
+pub fn add_to_blacklist(
+    env: &Env,
+    address: Address,
+    reason: Option<String>,
+    actor: Address,
+    expires_at: Option<u64>,
+) {
+    let now = env.ledger().timestamp();
+    set_entry(
+        env,
+        &address,
+        &BlacklistEntry {
+            reason: reason.clone(),
+            added_by: actor.clone(),
+            added_at: now,
+            expires_at,
+        },
+    );
 
-    if blacklist.contains_key(address.clone()) {
-        let mut new_blacklist = blacklist;
-        new_blacklist.remove(address.clone());
+    record_change(
+        env,
+        &address,
+        BlacklistChange {
+            action: BlacklistAction::Added,
+            reason: reason.clone(),
+            actor: actor.clone(),
+            timestamp: now,
+            expires_at,
+        },
+    );
+
+    emit_address_blacklisted(env, &actor, address, reason, expires_at);
+}
+
+/// Removes an address from the blacklist
+pub fn remove_from_blacklist(env: &Env, address: Address, actor: Address) {
+    let mut removed = remove_entry(env, &address);
+
+    let mut legacy = get_legacy_blacklist(env);
+    if legacy.contains_key(address.clone()) {
+        legacy.remove(address.clone());
         env.storage()
             .persistent()
-            .set(&symbol_short!("blklist"), &new_blacklist);
-        emit_address_unblacklisted(env, address);
+            .set(&symbol_short!("blklist"), &legacy);
+        removed = true;
+    }
+
+    if removed {
+        record_change(
+            env,
+            &address,
+            BlacklistChange {
+                action: BlacklistAction::Removed,
+                reason: None,
+                actor: actor.clone(),
+                timestamp: env.ledger().timestamp(),
+                expires_at: None,
+            },
+        );
+        emit_address_unblacklisted(env, &actor, address);
+    }
+}
+
+/// Moves up to `limit` entries from the legacy list into the indexed list
+///
+/// Migrated entries keep their reason, are attributed to `actor` and never
+/// expire. Returns the number of entries moved; call repeatedly until it
+/// returns zero.
+pub fn migrate_legacy_blacklist(env: &Env, actor: Address, limit: u32) -> u32 {
+    let mut legacy = get_legacy_blacklist(env);
+    let now = env.ledger().timestamp();
+    let mut migrated = 0u32;
+
+    for (address, reason) in legacy.clone().iter() {
+        if migrated >= limit {
+            break;
+        }
+        // An address re-added since the upgrade already has a newer entry
+        if get_blacklist_entry(env, &address).is_none() {
+            set_entry(
+                env,
+                &address,
+                &BlacklistEntry {
+                    reason,
+                    added_by: actor.clone(),
+                    added_at: now,
+                    expires_at: None,
+                },
+            );
+        }
+        legacy.remove(address);
+        migrated += 1;
+    }
+
+    if legacy.is_empty() {
+        env.storage().persistent().remove(&symbol_short!("blklist"));
+    } else {
+        env.storage()
+            .persistent()
+            .set(&symbol_short!("blklist"), &legacy);
+    }
+
+    migrated
+}
+
+/// Returns the blacklist entry for an address, including expired suspensions
+pub fn get_blacklist_entry(env: &Env, address: &Address) -> Option<BlacklistEntry> {
+    env.storage()
+        .persistent()
+        .get(&(symbol_short!("blk_entry"), address.clone()))
+}
+
+/// Returns the recorded list changes for an address, oldest first
+pub fn get_blacklist_history(env: &Env, address: &Address) -> Vec<BlacklistChange> {
+    env.storage()
+        .persistent()
+        .get(&(symbol_short!("blk_hist"), address.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+fn is_entry_active(env: &Env, entry: &BlacklistEntry) -> bool {
+    match entry.expires_at {
+        Some(expires_at) => env.ledger().timestamp() < expires_at,
+        None => true,
     }
 }
 
 /// Checks if an address is blacklisted
+///
+/// Suspensions whose `expires_at` has passed no longer block the address.
 pub fn is_blacklisted(env: &Env, address: &Address) -> bool {
-    let blacklist: Map<Address, Option<String>> = env
-        .storage()
-        .persistent()
-        .get(&symbol_short!("blklist"))
-        .unwrap_or(Map::new(env));
+    if let Some(entry) = get_blacklist_entry(env, address) {
+        return is_entry_active(env, &entry);
+    }
 
-    blacklist.contains_key(address.clone())
+    get_legacy_blacklist(env).contains_key(address.clone())
+}
+
+/// Returns one page of blocked addresses
+///
+/// Pages are taken from the slot index, so each call reads at most
+/// `page_size` entries (capped at `MAX_BLOCKED_PAGE_SIZE`). Expired
+/// suspensions on the page are skipped, so a page may hold fewer entries.
+/// Legacy entries appear once moved over by `migrate_legacy_blacklist`.
+pub fn get_blocked_addresses(env: &Env, page: u32, page_size: u32) -> Vec<BlockedAddress> {
+    let mut results = Vec::new(env);
+    let page_size = page_size.min(MAX_BLOCKED_PAGE_SIZE);
+    let start = page.saturating_mul(page_size);
+    let end = start.saturating_add(page_size).min(index_len(env));
+
+    for slot in start..end {
+        let address: Address = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("blk_slot"), slot))
+            .unwrap();
+        if let Some(entry) = get_blacklist_entry(env, &address) {
+            if is_entry_active(env, &entry) {
+                results.push_back(BlockedAddress { address, entry });
+            }
+        }
+    }
+
+    results
 }
 
 /// Adds an address to the whitelist
-pub fn add_to_whitelist(env: &Env, address: Address) {
+pub fn add_to_whitelist(env: &Env, address: Address, actor: Address) {
     let whitelist: Map<Address, bool> = env
         .storage()
         .persistent()
@@ -193,11 +520,11 @@ pub fn add_to_whitelist(env: &Env, address: Address) {
         .persistent()
         .set(&symbol_short!("whtlist"), &new_whitelist);
 
-    emit_address_whitelisted(env, address);
+    emit_address_whitelisted(env, &actor, address);
 }
 
 /// Removes an address from the whitelist
-pub fn remove_from_whitelist(env: &Env, address: Address) {
+pub fn remove_from_whitelist(env: &Env, address: Address, actor: Address) {
     let whitelist: Map<Address, bool> = env
         .storage()
         .persistent()
@@ -210,7 +537,7 @@ pub fn remove_from_whitelist(env: &Env, address: Address) {
         env.storage()
             .persistent()
             .set(&symbol_short!("whtlist"), &new_whitelist);
-        emit_address_unwhitelisted(env, address);
+        emit_address_unwhitelisted(env, &actor, address);
     }
 }
 
@@ -226,12 +553,12 @@ pub fn is_whitelisted(env: &Env, address: &Address) -> bool {
 }
 
 /// Enables or disables whitelist mode
-pub fn set_whitelist_mode(env: &Env, enabled: bool) {
+pub fn set_whitelist_mode(env: &Env, enabled: bool, actor: Address) {
     env.storage()
         .persistent()
         .set(&symbol_short!("wht_mode"), &enabled);
 
-    emit_whitelist_mode_toggled(env, enabled);
+    emit_whitelist_mode_toggled(env, &actor, enabled);
 }
 
 /// Checks if whitelist mode is enabled
@@ -242,13 +569,19 @@ pub fn is_whitelist_mode_enabled(env: &Env) -> bool {
         .unwrap_or(false)
 }
 
-/// Validates if an address can participate (not blacklisted and passes whitelist check if enabled)
+/// Validates if an address can participate (not blacklisted, not on the
+/// published sanctions list, and passes whitelist check if enabled)
 pub fn is_participant_allowed(env: &Env, address: &Address) -> bool {
     // Check blacklist first (always enforced)
     if is_blacklisted(env, address) {
         return false;
     }
 
+    // Check the Merkle sanctions list (overrides, proven membership, clearance)
+    if !sanctions::is_allowed_by_sanctions(env, address) {
+        return false;
+    }
+
     // Check whitelist if enabled
     if is_whitelist_mode_enabled(env) {
         return is_whitelisted(env, address);
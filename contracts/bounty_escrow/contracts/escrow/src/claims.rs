@@ -0,0 +1,248 @@
+//! # Pull-Based Payouts Module
+//!
+//! In claim mode a release no longer pushes tokens to the contributor.
+//! It records an entitlement that the contributor later withdraws, so a
+//! recipient without a trustline cannot make the admin's release fail.
+//!
+//! ## Flow
+//!
+//! ```text
+//! release_funds / batch_release_funds / oracle release
+//!        │
+//!        ├─ PayoutMode::Push  ──► token transfer (default)
+//!        │
+//!        └─ PayoutMode::Claim ──► (ent, bounty_id) -> Entitlement
+//!                                       │
+//!                                       ▼
+//!                        claim(bounty_id) / claim_all(address)
+//! ```
+//!
+//! The sum of unclaimed entitlements is kept so solvency checks can count it
+//! as a liability of the contract.
+
+use crate::events::{self, legacy_events_enabled, EventKind};
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Vec};
+
+// ============================================================================
+// Data Structures
+// ============================================================================
+
+/// How releases deliver funds to contributors
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PayoutMode {
+    /// Transfer tokens during the release (default)
+    Push,
+    /// Record an entitlement the contributor claims later
+    Claim,
+}
+
+/// Released amount waiting to be claimed by its recipient
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Entitlement {
+    pub bounty_id: u64,
+    pub recipient: Address,
+    pub amount: i128,
+    pub created_at: u64,
+}
+
+/// Event emitted when an entitlement is recorded
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct EntitlementRecorded {
+    pub bounty_id: u64,
+    pub recipient: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+/// Event emitted when an entitlement is paid out
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct EntitlementClaimed {
+    pub bounty_id: u64,
+    pub recipient: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+/// Event emitted when the admin redirects a blocked recipient's entitlement
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct EntitlementRecovered {
+    pub bounty_id: u64,
+    pub recipient: Address,
+    pub to: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+// ============================================================================
+// Storage
+// ============================================================================
+
+pub fn get_payout_mode(env: &Env) -> PayoutMode {
+    env.storage()
+        .instance()
+        .get(&symbol_short!("pay_mode"))
+        .unwrap_or(PayoutMode::Push)
+}
+
+pub fn set_payout_mode(env: &Env, mode: PayoutMode) {
+    env.storage()
+        .instance()
+        .set(&symbol_short!("pay_mode"), &mode);
+}
+
+pub fn get_entitlement(env: &Env, bounty_id: u64) -> Option<Entitlement> {
+    env.storage()
+        .persistent()
+        .get(&(symbol_short!("ent"), bounty_id))
+}
+
+/// Bounty ids with an unclaimed entitlement for the address
+pub fn get_entitlement_ids(env: &Env, recipient: &Address) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&(symbol_short!("ent_addr"), recipient.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+fn set_entitlement_ids(env: &Env, recipient: &Address, ids: &Vec<u64>) {
+    let key = (symbol_short!("ent_addr"), recipient.clone());
+    if ids.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, ids);
+    }
+}
+
+/// Unclaimed entitlements for an address
+pub fn get_entitlements(env: &Env, recipient: &Address) -> Vec<Entitlement> {
+    let mut entitlements = Vec::new(env);
+    for bounty_id in get_entitlement_ids(env, recipient).iter() {
+        if let Some(entitlement) = get_entitlement(env, bounty_id) {
+            entitlements.push_back(entitlement);
+        }
+    }
+    entitlements
+}
+
+/// Sum of all unclaimed entitlements
+pub fn get_unclaimed_total(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&symbol_short!("ent_total"))
+        .unwrap_or(0)
+}
+
+fn adjust_unclaimed_total(env: &Env, delta: i128) {
+    let total = get_unclaimed_total(env) + delta;
+    env.storage()
+        .instance()
+        .set(&symbol_short!("ent_total"), &total);
+}
+
+// ============================================================================
+// Entitlements
+// ============================================================================
+
+/// Records an entitlement for a released bounty
+pub fn record_entitlement(env: &Env, bounty_id: u64, recipient: &Address, amount: i128) {
+    let timestamp = env.ledger().timestamp();
+    let entitlement = Entitlement {
+        bounty_id,
+        recipient: recipient.clone(),
+        amount,
+        created_at: timestamp,
+    };
+    env.storage()
+        .persistent()
+        .set(&(symbol_short!("ent"), bounty_id), &entitlement);
+
+    let mut ids = get_entitlement_ids(env, recipient);
+    ids.push_back(bounty_id);
+    set_entitlement_ids(env, recipient, &ids);
+    adjust_unclaimed_total(env, amount);
+
+    if legacy_events_enabled(env) {
+        env.events().publish(
+            (symbol_short!("entitle"), symbol_short!("record")),
+            EntitlementRecorded {
+                bounty_id,
+                recipient: recipient.clone(),
+                amount,
+                timestamp,
+            },
+        );
+    }
+}
+
+fn remove_entitlement(env: &Env, entitlement: &Entitlement) {
+    env.storage()
+        .persistent()
+        .remove(&(symbol_short!("ent"), entitlement.bounty_id));
+
+    let mut ids = get_entitlement_ids(env, &entitlement.recipient);
+    if let Some(index) = ids.first_index_of(entitlement.bounty_id) {
+        ids.remove(index);
+    }
+    set_entitlement_ids(env, &entitlement.recipient, &ids);
+    adjust_unclaimed_total(env, -entitlement.amount);
+}
+
+/// Removes an entitlement redirected by the admin and emits its canonical v2
+/// event, plus the legacy `EntitlementRecovered` when enabled
+pub fn recover_entitlement(env: &Env, entitlement: &Entitlement, admin: &Address, to: &Address) {
+    remove_entitlement(env, entitlement);
+
+    if legacy_events_enabled(env) {
+        env.events().publish(
+            (symbol_short!("entitle"), symbol_short!("recover")),
+            EntitlementRecovered {
+                bounty_id: entitlement.bounty_id,
+                recipient: entitlement.recipient.clone(),
+                to: to.clone(),
+                amount: entitlement.amount,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+    }
+    events::emit_v2(
+        env,
+        EventKind::EntitlementRecovered,
+        entitlement.bounty_id,
+        admin,
+        Some(to.clone()),
+        entitlement.amount,
+        0,
+    );
+}
+
+/// Removes an entitlement once it has been paid and emits its canonical v2
+/// event, plus the legacy `EntitlementClaimed` when enabled
+pub fn settle_entitlement(env: &Env, entitlement: &Entitlement) {
+    remove_entitlement(env, entitlement);
+
+    if legacy_events_enabled(env) {
+        env.events().publish(
+            (symbol_short!("entitle"), symbol_short!("claim")),
+            EntitlementClaimed {
+                bounty_id: entitlement.bounty_id,
+                recipient: entitlement.recipient.clone(),
+                amount: entitlement.amount,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+    }
+    events::emit_v2(
+        env,
+        EventKind::EntitlementClaimed,
+        entitlement.bounty_id,
+        &entitlement.recipient,
+        Some(entitlement.recipient.clone()),
+        entitlement.amount,
+        0,
+    );
+}
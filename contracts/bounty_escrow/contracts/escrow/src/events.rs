@@ -4,287 +4,186 @@
 //! Events provide an audit trail and enable off-chain indexing for monitoring
 //! bounty lifecycle states.
 //!
-//! ## Event Architecture
+//! ## Canonical Schema (v2)
+//!
+//! Every escrow state transition, contract lifecycle change and compliance
+//! or keeper operation emits exactly one `EscrowEventV2`. Indexers should
+//! consume this stream.
+//!
+//! The v1 events in this module, in `indexed::enhanced_events` and in the
+//! feature modules (claims, vesting, identity, kyc, oracle, sanctions,
+//! blacklist, keeper) are only published while the admin keeps legacy events
+//! enabled through `set_legacy_events`, for consumers that have not moved to
+//! v2 yet. They are off by default so each transition produces a single event.
+//!
+//! ```text
+//! topics: ("escrow_v2", EventKind, bounty_id)
+//! data:   EscrowEventV2 { version: 2, sequence, kind, bounty_id, ... }
+//! ```
 //!
 //! ```text
 //! ┌─────────────────────────────────────────────────────────────┐
 //! │                    Event Flow Diagram                        │
 //! ├─────────────────────────────────────────────────────────────┤
 //! │                                                              │
-//! │  Contract Init → BountyEscrowInitialized                    │
+//! │  Contract Init → Initialized                                │
 //! │       ↓                                                      │
-//! │  Lock Funds    → FundsLocked                                │
+//! │  Lock Funds    → Locked                                     │
 //! │       ↓                                                      │
 //! │  ┌──────────┐                                               │
 //! │  │ Decision │                                               │
 //! │  └────┬─────┘                                               │
-//! │       ├─────→ Release → FundsReleased                       │
-//! │       └─────→ Refund  → FundsRefunded                       │
+//! │       ├─────→ Release → Released / IdentityCredited /       │
+//! │       │                 StreamStarted                       │
+//! │       ├─────→ Refund  → Refunded / PartiallyRefunded        │
+//! │       └─────→ Timeout → Expired                             │
 //! └─────────────────────────────────────────────────────────────┘
 //! ```
+//!
+//! `sequence` increases by one with every v2 event, so a gap tells an
+//! indexer it missed an event. Contracts cannot see their transaction hash;
+//! indexers take it from the RPC envelope the event arrived in.
 
-use soroban_sdk::{contracttype, symbol_short, Address, Env};
+use crate::EscrowStatus;
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env};
 
 // ============================================================================
-// Contract Initialization Event
+// Canonical Event Schema (v2)
 // ============================================================================
 
-/// Event emitted when the Bounty Escrow contract is initialized.
-///
-/// # Fields
-/// * `admin` - The administrator address with release authorization
-/// * `token` - The token contract address (typically XLM/USDC)
-/// * `timestamp` - Unix timestamp of initialization
-///
-/// # Event Topic
-/// Symbol: `init`
-///
-/// # Usage
-/// This event is emitted once during contract deployment and signals
-/// that the contract is ready to accept bounty escrows.
-///
-/// # Security Considerations
-/// - Only emitted once; subsequent init attempts should fail
-/// - Admin address should be a secure backend service
-/// - Token address must be a valid Stellar token contract
-///
-/// # Example Off-chain Indexing
-/// ```javascript
-/// // Listen for initialization events
-/// stellar.events.on('init', (event) => {
-///   console.log(`Contract initialized by ${event.admin}`);
-///   console.log(`Using token: ${event.token}`);
-/// });
-/// ```
-#[contracttype]
-#[derive(Clone, Debug)]
-pub struct BountyEscrowInitialized {
-    pub admin: Address,
-    pub token: Address,
-    pub timestamp: u64,
-}
-
-/// Emits a BountyEscrowInitialized event.
-///
-/// # Arguments
-/// * `env` - The contract environment
-/// * `event` - The initialization event data
-///
-/// # Event Structure
-/// Topic: `(symbol_short!("init"),)`
-/// Data: Complete `BountyEscrowInitialized` struct
-pub fn _emit_bounty_initialized(env: &Env, event: BountyEscrowInitialized) {
-    let topics = (symbol_short!("init"),);
-    env.events().publish(topics, event.clone());
-}
-
-// ============================================================================
-// Funds Locked Event
-// ============================================================================
+/// Version carried by every `EscrowEventV2`
+pub const EVENT_SCHEMA_VERSION: u32 = 2;
 
-/// Event emitted when funds are locked in escrow for a bounty.
-///
-/// # Fields
-/// * `bounty_id` - Unique identifier for the bounty
-/// * `amount` - Amount of tokens locked (in stroops for XLM)
-/// * `depositor` - Address that deposited the funds
-/// * `deadline` - Unix timestamp after which refunds are allowed
-///
-/// # Event Topic
-/// Symbol: `f_lock`
-/// Indexed: `bounty_id` (allows filtering by specific bounty)
-///
-/// # State Transition
-/// ```text
-/// NONE → LOCKED
-/// ```
-///
-/// # Usage
-/// Emitted when a bounty creator locks funds for a task. The depositor
-/// transfers tokens to the contract, which holds them until release or refund.
-///
-/// # Security Considerations
-/// - Amount must be positive and within depositor's balance
-/// - Bounty ID must be unique (no duplicates allowed)
-/// - Deadline must be in the future
-/// - Depositor must authorize the transaction
-///
-/// # Example Usage
-/// ```rust
-/// // Lock 1000 XLM for bounty #42, deadline in 30 days
-/// let deadline = env.ledger().timestamp() + (30 * 24 * 60 * 60);
-/// escrow_client.lock_funds(&depositor, &42, &10_000_000_000, &deadline);
-/// // → Emits FundsLocked event
-/// ```
+/// State transition described by an `EscrowEventV2`
 #[contracttype]
-#[derive(Clone, Debug)]
-pub struct FundsLocked {
-    pub bounty_id: u64,
-    pub amount: i128,
-    pub depositor: Address,
-    pub deadline: u64,
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EventKind {
+    // Contract lifecycle (bounty_id = 0)
+    Initialized,
+    FeeConfigUpdated,
+    Paused,
+    Unpaused,
+    EmergencyWithdrawal,
+    // Escrow lifecycle
+    Locked,
+    Released,
+    IdentityCredited,
+    IdentityClaimed,
+    EntitlementClaimed,
+    EntitlementRecovered,
+    StreamStarted,
+    StreamWithdrawn,
+    StreamCancelled,
+    RefundApproved,
+    Refunded,
+    PartiallyRefunded,
+    Expired,
+    DeadlineExtended,
+    Compacted,
+    // Compliance and operations (bounty_id = 0)
+    KycAttested,
+    OracleKeyRotated,
+    SanctionsRootPublished,
+    Blacklisted,
+    Unblacklisted,
+    Whitelisted,
+    Unwhitelisted,
+    WhitelistModeEnabled,
+    WhitelistModeDisabled,
+    KeeperPoolFunded,
+    KeeperRewarded,
 }
 
-/// Emits a FundsLocked event.
-///
-/// # Arguments
-/// * `env` - The contract environment
-/// * `event` - The funds locked event data
-///
-/// # Event Structure
-/// Topic: `(symbol_short!("f_lock"), event.bounty_id)`
-/// Data: Complete `FundsLocked` struct
-///
-/// # Indexing Note
-/// The bounty_id is included in topics for efficient filtering
-pub fn _emit_funds_locked(env: &Env, event: FundsLocked) {
-    let topics = (symbol_short!("f_lock"), event.bounty_id);
-    env.events().publish(topics, event.clone());
-}
-
-// ============================================================================
-// Funds Released Event
-// ============================================================================
-
-/// Event emitted when escrowed funds are released to a contributor.
+/// Canonical event emitted once per state transition
 ///
 /// # Fields
-/// * `bounty_id` - The bounty identifier
-/// * `amount` - Amount transferred to recipient
-/// * `recipient` - Address receiving the funds (contributor)
-/// * `timestamp` - Unix timestamp of release
+/// * `version` - Always `EVENT_SCHEMA_VERSION`
+/// * `sequence` - Per-contract counter, starting at 1 and without gaps
+/// * `kind` - The transition
+/// * `bounty_id` - Affected bounty (0 for contract lifecycle events)
+/// * `actor` - Address that triggered the transition (the contract itself
+///   for permissionless sweeps)
+/// * `recipient` - Address receiving funds, or the participant a compliance
+///   transition applies to
+/// * `amount` - Net amount moved or locked (0 if none)
+/// * `fee` - Fee taken by the transition (0 if none)
+/// * `timestamp` / `ledger` - When the transition happened
 ///
 /// # Event Topic
-/// Symbol: `f_rel`
-/// Indexed: `bounty_id`
-///
-/// # State Transition
-/// ```text
-/// LOCKED → RELEASED (final state)
-/// ```
-///
-/// # Usage
-/// Emitted when the admin releases funds to a contributor who completed
-/// the bounty task. This is a final, irreversible action.
-///
-/// # Authorization
-/// - Only the contract admin can trigger fund release
-/// - Funds must be in LOCKED state
-/// - Cannot release funds that were already released or refunded
-///
-/// # Security Considerations
-/// - Admin authorization is critical (should be secure backend)
-/// - Recipient address should be verified off-chain before release
-/// - Once released, funds cannot be retrieved
-/// - Atomic operation: transfer + state update
-///
-/// # Example Usage
-/// ```rust
-/// // Admin releases 1000 XLM to contributor for bounty #42
-/// escrow_client.release_funds(&42, &contributor_address);
-/// // → Transfers tokens
-/// // → Updates state to Released
-/// // → Emits FundsReleased event
-/// ```
+/// `(symbol_short!("escrow_v2"), kind, bounty_id)`
 #[contracttype]
-#[derive(Clone, Debug)]
-pub struct FundsReleased {
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowEventV2 {
+    pub version: u32,
+    pub sequence: u64,
+    pub kind: EventKind,
     pub bounty_id: u64,
+    pub actor: Address,
+    pub recipient: Option<Address>,
     pub amount: i128,
-    pub recipient: Address,
+    pub fee: i128,
     pub timestamp: u64,
+    pub ledger: u32,
 }
 
-/// Emits a FundsReleased event.
-///
-/// # Arguments
-/// * `env` - The contract environment
-/// * `event` - The funds released event data
-///
-/// # Event Structure
-/// Topic: `(symbol_short!("f_rel"), event.bounty_id)`
-/// Data: Complete `FundsReleased` struct
-pub fn _emit_funds_released(env: &Env, event: FundsReleased) {
-    let topics = (symbol_short!("f_rel"), event.bounty_id);
-    env.events().publish(topics, event.clone());
+/// Sequence number of the last emitted v2 event (0 if none)
+pub fn get_event_sequence(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&symbol_short!("evt_seq"))
+        .unwrap_or(0)
+}
+
+/// Emits the canonical v2 event for a transition and returns its sequence
+pub fn emit_v2(
+    env: &Env,
+    kind: EventKind,
+    bounty_id: u64,
+    actor: &Address,
+    recipient: Option<Address>,
+    amount: i128,
+    fee: i128,
+) -> u64 {
+    let sequence = get_event_sequence(env) + 1;
+    env.storage()
+        .instance()
+        .set(&symbol_short!("evt_seq"), &sequence);
+
+    let topics = (symbol_short!("escrow_v2"), kind, bounty_id);
+    env.events().publish(
+        topics,
+        EscrowEventV2 {
+            version: EVENT_SCHEMA_VERSION,
+            sequence,
+            kind,
+            bounty_id,
+            actor: actor.clone(),
+            recipient,
+            amount,
+            fee,
+            timestamp: env.ledger().timestamp(),
+            ledger: env.ledger().sequence(),
+        },
+    );
+    sequence
 }
 
 // ============================================================================
-// Funds Refunded Event
+// Legacy Events
 // ============================================================================
 
-/// Event emitted when escrowed funds are refunded to the depositor.
-///
-/// # Fields
-/// * `bounty_id` - The bounty identifier
-/// * `amount` - Amount refunded to depositor
-/// * `refund_to` - Address receiving the refund (original depositor)
-/// * `timestamp` - Unix timestamp of refund
-///
-/// # Event Topic
-/// Symbol: `f_ref`
-/// Indexed: `bounty_id`
-///
-/// # State Transition
-/// ```text
-/// LOCKED → REFUNDED (final state)
-/// ```
-///
-/// # Usage
-/// Emitted when funds are returned to the depositor after the deadline
-/// has passed without the bounty being completed. This mechanism prevents
-/// funds from being locked indefinitely.
-///
-/// # Conditions
-/// - Deadline must have passed (timestamp > deadline)
-/// - Funds must still be in LOCKED state
-/// - Can be triggered by anyone (permissionless but conditional)
-///
-/// # Security Considerations
-/// - Time-based protection ensures funds aren't stuck
-/// - Permissionless refund prevents admin monopoly
-/// - Original depositor always receives refund
-/// - Cannot refund if already released or refunded
-///
-/// # Example Usage
-/// ```rust
-/// // After deadline passes, anyone can trigger refund
-/// // Deadline was January 1, 2025
-/// // Current time: January 15, 2025
-/// escrow_client.refund(&42);
-/// // → Transfers tokens back to depositor
-/// // → Updates state to Refunded
-/// // → Emits FundsRefunded event
-/// ```
-///
-/// # Design Rationale
-/// Permissionless refunds ensure that:
-/// 1. Depositors don't lose funds if they lose their keys
-/// 2. No admin action needed for legitimate refunds
-/// 3. System remains trustless and decentralized
-#[contracttype]
-#[derive(Clone, Debug)]
-pub struct FundsRefunded {
-    pub bounty_id: u64,
-    pub amount: i128,
-    pub refund_to: Address,
-    pub timestamp: u64,
-    pub refund_mode: crate::RefundMode,
-    pub remaining_amount: i128,
+/// Whether the v1 events are published next to the v2 stream (off by default)
+pub fn legacy_events_enabled(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&symbol_short!("evt_lgcy"))
+        .unwrap_or(false)
 }
 
-/// Emits a FundsRefunded event.
-///
-/// # Arguments
-/// * `env` - The contract environment
-/// * `event` - The funds refunded event data
-///
-/// # Event Structure
-/// Topic: `(symbol_short!("f_ref"), event.bounty_id)`
-/// Data: Complete `FundsRefunded` struct
-pub fn _emit_funds_refunded(env: &Env, event: FundsRefunded) {
-    let topics = (symbol_short!("f_ref"), event.bounty_id);
-    env.events().publish(topics, event.clone());
+pub fn set_legacy_events(env: &Env, enabled: bool) {
+    env.storage()
+        .instance()
+        .set(&symbol_short!("evt_lgcy"), &enabled);
 }
 
 #[contracttype]
@@ -305,6 +204,9 @@ pub struct FeeCollected {
 }
 
 pub fn emit_fee_collected(env: &Env, event: FeeCollected) {
+    if !legacy_events_enabled(env) {
+        return;
+    }
     let topics = (symbol_short!("fee"),);
     env.events().publish(topics, event.clone());
 }
@@ -318,6 +220,9 @@ pub struct BatchFundsLocked {
 }
 
 pub fn emit_batch_funds_locked(env: &Env, event: BatchFundsLocked) {
+    if !legacy_events_enabled(env) {
+        return;
+    }
     let topics = (symbol_short!("b_lock"),);
     env.events().publish(topics, event.clone());
 }
@@ -333,6 +238,9 @@ pub struct FeeConfigUpdated {
 }
 
 pub fn emit_fee_config_updated(env: &Env, event: FeeConfigUpdated) {
+    if !legacy_events_enabled(env) {
+        return;
+    }
     let topics = (symbol_short!("fee_cfg"),);
     env.events().publish(topics, event.clone());
 }
@@ -346,6 +254,9 @@ pub struct BatchFundsReleased {
 }
 
 pub fn emit_batch_funds_released(env: &Env, event: BatchFundsReleased) {
+    if !legacy_events_enabled(env) {
+        return;
+    }
     let topics = (symbol_short!("b_rel"),);
     env.events().publish(topics, event.clone());
 }
@@ -362,6 +273,9 @@ pub struct ContractPaused {
 }
 
 pub fn emit_contract_paused(env: &Env, event: ContractPaused) {
+    if !legacy_events_enabled(env) {
+        return;
+    }
     let topics = (symbol_short!("pause"),);
     env.events().publish(topics, event.clone());
 }
@@ -375,6 +289,9 @@ pub struct ContractUnpaused {
 }
 
 pub fn emit_contract_unpaused(env: &Env, event: ContractUnpaused) {
+    if !legacy_events_enabled(env) {
+        return;
+    }
     let topics = (symbol_short!("unpause"),);
     env.events().publish(topics, event.clone());
 }
@@ -390,6 +307,9 @@ pub struct EmergencyWithdrawal {
 }
 
 pub fn emit_emergency_withdrawal(env: &Env, event: EmergencyWithdrawal) {
+    if !legacy_events_enabled(env) {
+        return;
+    }
     let topics = (symbol_short!("ewith"),);
     env.events().publish(topics, event.clone());
 }
@@ -445,6 +365,9 @@ pub struct DeadlineExtended {
 /// Topic: `(symbol_short!("dead_ext"), event.bounty_id)`
 /// Data: Complete `DeadlineExtended` struct
 pub fn emit_deadline_extended(env: &Env, event: DeadlineExtended) {
+    if !legacy_events_enabled(env) {
+        return;
+    }
     let topics = (symbol_short!("dead_ext"), event.bounty_id);
     env.events().publish(topics, event.clone());
 }
@@ -472,6 +395,36 @@ pub struct EscrowExpired {
 }
 
 pub fn emit_escrow_expired(env: &Env, event: EscrowExpired) {
+    if !legacy_events_enabled(env) {
+        return;
+    }
     let topics = (symbol_short!("expired"), event.bounty_id);
     env.events().publish(topics, event.clone());
 }
+
+/// Event emitted when a finalized escrow is compacted into a receipt.
+///
+/// # Fields
+/// * `bounty_id` - The bounty identifier
+/// * `status` - Final status of the escrow (Released or Refunded)
+/// * `history_hash` - SHA-256 of the dropped escrow record and metadata
+/// * `timestamp` - Unix timestamp of compaction
+///
+/// # Event Topic
+/// Symbol: `compact`, bounty_id
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct EscrowCompacted {
+    pub bounty_id: u64,
+    pub status: EscrowStatus,
+    pub history_hash: BytesN<32>,
+    pub timestamp: u64,
+}
+
+pub fn emit_escrow_compacted(env: &Env, event: EscrowCompacted) {
+    if !legacy_events_enabled(env) {
+        return;
+    }
+    let topics = (symbol_short!("compact"), event.bounty_id);
+    env.events().publish(topics, event.clone());
+}
@@ -0,0 +1,235 @@
+//! # External Identity Credits Module
+//!
+//! Lets the admin release a bounty to a contributor who has not linked a
+//! Stellar wallet yet. The payout is credited to a hashed external identity
+//! (e.g. `sha256("github:<user id>")`) and held by the contract until the
+//! contributor claims it with an oracle-signed link to their address.
+//!
+//! ## Flow
+//!
+//! ```text
+//! release_to_identity(bounty_id, identity_hash, claim_window)
+//!        │  escrow → Released, full amount held by the contract
+//!        ▼
+//! (id_cred, bounty_id) -> IdentityCredit
+//!        │
+//!        ├─ claim_identity_credit(link signed by oracle quorum)
+//!        │        └─ before expires_at ──► fee taken, net paid to link.address
+//!        │
+//!        └─ reclaim_identity_credit()
+//!                 └─ after expires_at ──► full amount returned to depositor
+//! ```
+//!
+//! Links are bound to the network and escrow contract they were signed for.
+
+use crate::events::legacy_events_enabled;
+use crate::oracle::{self, OracleSignature};
+use crate::Error;
+use soroban_sdk::{contracttype, symbol_short, xdr::ToXdr, Address, BytesN, Env, Vec};
+
+// ============================================================================
+// Data Structures
+// ============================================================================
+
+/// Payout held for an external identity until it is claimed or expires
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IdentityCredit {
+    pub bounty_id: u64,
+    /// sha256 of the external identity string, e.g. "github:12345"
+    pub identity_hash: BytesN<32>,
+    /// Amount payable to the claimant (release fee already deducted)
+    pub amount: i128,
+    /// Release fee withheld until the credit is claimed
+    pub fee: i128,
+    pub depositor: Address,
+    pub credited_at: u64,
+    /// After this time the credit can only be returned to the depositor
+    pub expires_at: u64,
+}
+
+/// Statement signed by the oracles linking an identity to an address;
+/// the signed message is its XDR encoding
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IdentityLink {
+    /// Network passphrase hash the link is valid on
+    pub network_id: BytesN<32>,
+    /// Escrow contract the link is issued for
+    pub contract: Address,
+    pub identity_hash: BytesN<32>,
+    pub address: Address,
+    pub expires_at: u64,
+}
+
+/// Event emitted when a bounty is credited to an identity
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct IdentityCredited {
+    pub bounty_id: u64,
+    pub identity_hash: BytesN<32>,
+    pub amount: i128,
+    pub expires_at: u64,
+    pub timestamp: u64,
+}
+
+/// Event emitted when a credit is claimed by its linked address
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct IdentityCreditClaimed {
+    pub bounty_id: u64,
+    pub identity_hash: BytesN<32>,
+    pub claimant: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+/// Event emitted when an expired credit is returned to the depositor
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct IdentityCreditReclaimed {
+    pub bounty_id: u64,
+    pub identity_hash: BytesN<32>,
+    pub depositor: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+// ============================================================================
+// Storage
+// ============================================================================
+
+pub fn get_credit(env: &Env, bounty_id: u64) -> Option<IdentityCredit> {
+    env.storage()
+        .persistent()
+        .get(&(symbol_short!("id_cred"), bounty_id))
+}
+
+/// Bounty ids with an outstanding credit for the identity
+fn get_open_ids(env: &Env, identity_hash: &BytesN<32>) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&(symbol_short!("id_open"), identity_hash.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+fn set_open_ids(env: &Env, identity_hash: &BytesN<32>, ids: &Vec<u64>) {
+    let key = (symbol_short!("id_open"), identity_hash.clone());
+    if ids.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, ids);
+    }
+}
+
+/// Outstanding credits for an identity
+pub fn get_credits_for(env: &Env, identity_hash: &BytesN<32>) -> Vec<IdentityCredit> {
+    let mut credits = Vec::new(env);
+    for bounty_id in get_open_ids(env, identity_hash).iter() {
+        if let Some(credit) = get_credit(env, bounty_id) {
+            credits.push_back(credit);
+        }
+    }
+    credits
+}
+
+/// Sum of all outstanding credits, including withheld fees
+pub fn get_outstanding_total(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&symbol_short!("id_total"))
+        .unwrap_or(0)
+}
+
+fn adjust_outstanding_total(env: &Env, delta: i128) {
+    let total = get_outstanding_total(env) + delta;
+    env.storage()
+        .instance()
+        .set(&symbol_short!("id_total"), &total);
+}
+
+/// Stores a new credit and emits the legacy `IdentityCredited` when enabled
+pub fn add_credit(env: &Env, credit: &IdentityCredit) {
+    env.storage()
+        .persistent()
+        .set(&(symbol_short!("id_cred"), credit.bounty_id), credit);
+
+    let mut ids = get_open_ids(env, &credit.identity_hash);
+    ids.push_back(credit.bounty_id);
+    set_open_ids(env, &credit.identity_hash, &ids);
+    adjust_outstanding_total(env, credit.amount + credit.fee);
+
+    if legacy_events_enabled(env) {
+        env.events().publish(
+            (symbol_short!("identity"), symbol_short!("credit")),
+            IdentityCredited {
+                bounty_id: credit.bounty_id,
+                identity_hash: credit.identity_hash.clone(),
+                amount: credit.amount,
+                expires_at: credit.expires_at,
+                timestamp: credit.credited_at,
+            },
+        );
+    }
+}
+
+/// Removes a settled credit from storage and from its identity's index
+pub fn remove_credit(env: &Env, credit: &IdentityCredit) {
+    env.storage()
+        .persistent()
+        .remove(&(symbol_short!("id_cred"), credit.bounty_id));
+
+    let mut ids = get_open_ids(env, &credit.identity_hash);
+    if let Some(index) = ids.first_index_of(credit.bounty_id) {
+        ids.remove(index);
+    }
+    set_open_ids(env, &credit.identity_hash, &ids);
+    adjust_outstanding_total(env, -(credit.amount + credit.fee));
+}
+
+/// Checks that `link` is for this contract and that the oracle quorum signed it
+pub fn verify_link(
+    env: &Env,
+    link: &IdentityLink,
+    signatures: &Vec<OracleSignature>,
+) -> Result<(), Error> {
+    if link.network_id != env.ledger().network_id()
+        || link.contract != env.current_contract_address()
+    {
+        return Err(Error::WrongContract);
+    }
+    oracle::verify_quorum(env, &link.clone().to_xdr(env), signatures)?;
+    Ok(())
+}
+
+pub fn emit_claimed(env: &Env, credit: &IdentityCredit, claimant: &Address) {
+    if !legacy_events_enabled(env) {
+        return;
+    }
+    env.events().publish(
+        (symbol_short!("identity"), symbol_short!("claim")),
+        IdentityCreditClaimed {
+            bounty_id: credit.bounty_id,
+            identity_hash: credit.identity_hash.clone(),
+            claimant: claimant.clone(),
+            amount: credit.amount,
+            timestamp: env.ledger().timestamp(),
+        },
+    );
+}
+
+pub fn emit_reclaimed(env: &Env, credit: &IdentityCredit) {
+    if !legacy_events_enabled(env) {
+        return;
+    }
+    env.events().publish(
+        (symbol_short!("identity"), symbol_short!("reclaim")),
+        IdentityCreditReclaimed {
+            bounty_id: credit.bounty_id,
+            identity_hash: credit.identity_hash.clone(),
+            depositor: credit.depositor.clone(),
+            amount: credit.amount + credit.fee,
+            timestamp: env.ledger().timestamp(),
+        },
+    );
+}
@@ -1,5 +1,9 @@
-use crate::indexed::indexed_storage::{BountyStatus, IndexedBounty, PaginatedResult, QueryFilter};
-use crate::indexed::query_functions;
+use crate::indexed::indexed_storage::{
+    BountyStatus, ContributorStats, EarnerEntry, IndexList, IndexedBounty, PaginatedResult,
+    QueryFilter,
+};
+use crate::indexed::query_functions::{self, TimeSeriesPoint};
+use crate::indexed::time_series::{self, DailyBucket};
 use soroban_sdk::{contract, contractimpl, Address, Env, Vec};
 
 #[contract]
@@ -128,7 +132,10 @@ impl EscrowQueryContract {
         let mut results = Vec::new(&env);
         let mut total = 0u32;
 
-        for bounty_id in 0..1_000_000u64 {
+        for bounty_id in
+            crate::indexed::indexed_storage::get_status_bounty_ids(&env, &BountyStatus::Locked)
+                .iter()
+        {
             if let Some(bounty) = crate::indexed::indexed_storage::get_bounty(&env, bounty_id) {
                 if bounty.status == BountyStatus::Locked && bounty.deadline < current_time {
                     total += 1;
@@ -162,7 +169,10 @@ impl EscrowQueryContract {
         let mut results = Vec::new(&env);
         let mut total = 0u32;
 
-        for bounty_id in 0..1_000_000u64 {
+        for bounty_id in
+            crate::indexed::indexed_storage::get_status_bounty_ids(&env, &BountyStatus::Locked)
+                .iter()
+        {
             if let Some(bounty) = crate::indexed::indexed_storage::get_bounty(&env, bounty_id) {
                 if bounty.status == BountyStatus::Locked
                     && bounty.deadline > current_time
@@ -217,8 +227,46 @@ impl EscrowQueryContract {
 
     /// Get count of bounties by status
     pub fn get_bounty_count_by_status(env: Env, status: BountyStatus) -> u32 {
-        let bounties = query_functions::get_bounties_by_status(&env, status);
-        bounties.len()
+        query_functions::count_bounties_by_status(&env, status)
+    }
+
+    /// Get one page of the bounties released to a specific contributor
+    pub fn get_bounties_by_contributor(
+        env: Env,
+        contributor: Address,
+        page: u32,
+    ) -> Vec<IndexedBounty> {
+        query_functions::get_bounties_by_contributor(&env, &contributor, page)
+    }
+
+    /// Get lifetime payout statistics for a contributor
+    pub fn get_contributor_stats(env: Env, contributor: Address) -> ContributorStats {
+        crate::indexed::indexed_storage::get_contributor_stats(&env, &contributor)
+    }
+
+    /// Get the top-earners leaderboard with pagination
+    pub fn get_top_earners(env: Env, page: u32, page_size: u32) -> Vec<EarnerEntry> {
+        query_functions::get_top_earners_page(&env, page, page_size)
+    }
+
+    /// Get the aggregated volume bucket for one day (days since the Unix epoch)
+    pub fn get_daily_volume(env: Env, day: u64) -> DailyBucket {
+        time_series::get_daily_bucket(&env, day)
+    }
+
+    /// Get time-series activity from the daily volume buckets
+    pub fn get_time_series_data(
+        env: Env,
+        from: u64,
+        to: u64,
+        interval: u64,
+    ) -> Vec<TimeSeriesPoint> {
+        query_functions::get_time_series_data(&env, from, to, interval)
+    }
+
+    /// Get one page of raw bounty IDs from a paged index
+    pub fn get_index_page(env: Env, list: IndexList, page: u32) -> Vec<u64> {
+        query_functions::get_index_page_ids(&env, list, page)
     }
 
     /// Check if a bounty exists
@@ -1,3 +1,4 @@
+use crate::events::legacy_events_enabled;
 use crate::RefundMode;
 use soroban_sdk::{contracttype, symbol_short, Address, Env, String};
 
@@ -37,12 +38,18 @@ pub struct BountyEscrowInitialized {
 
 /// _emits a BountyEscrowInitialized event.
 pub fn _emit_bounty_initialized(env: &Env, event: BountyEscrowInitialized) {
+    if !legacy_events_enabled(env) {
+        return;
+    }
     let topics = (symbol_short!("init"),);
     env.events().publish(topics, event.clone());
 }
 
 /// Enhanced version with metadata
 pub fn _emit_enhanced_bounty_initialized(env: &Env, event: BountyEscrowInitialized) {
+    if !legacy_events_enabled(env) {
+        return;
+    }
     let topics = (symbol_short!("init"),);
     env.events().publish(topics, event.clone());
 }
@@ -63,6 +70,9 @@ pub struct EnhancedFundsLocked {
 }
 
 pub fn _emit_enhanced_funds_locked(env: &Env, event: EnhancedFundsLocked) {
+    if !legacy_events_enabled(env) {
+        return;
+    }
     let topics = (symbol_short!("f_lock"), event.bounty_id);
     env.events().publish(topics, event.clone());
 }
@@ -80,6 +90,9 @@ pub struct EnhancedFundsReleased {
 }
 
 pub fn _emit_enhanced_funds_released(env: &Env, event: EnhancedFundsReleased) {
+    if !legacy_events_enabled(env) {
+        return;
+    }
     let topics = (symbol_short!("f_rel"), event.bounty_id);
     env.events().publish(topics, event.clone());
 }
@@ -98,6 +111,9 @@ pub struct EnhancedFundsRefunded {
 }
 
 pub fn _emit_enhanced_funds_refunded(env: &Env, event: EnhancedFundsRefunded) {
+    if !legacy_events_enabled(env) {
+        return;
+    }
     let topics = (symbol_short!("f_ref"), event.bounty_id);
     env.events().publish(topics, event.clone());
 }
@@ -118,6 +134,9 @@ pub struct BountyStatusChanged {
 }
 
 pub fn _emit_bounty_status_changed(env: &Env, event: BountyStatusChanged) {
+    if !legacy_events_enabled(env) {
+        return;
+    }
     let topics = (symbol_short!("status"), event.bounty_id);
     env.events().publish(topics, event.clone());
 }
@@ -152,6 +171,9 @@ pub struct BountyActivity {
 }
 
 pub fn _emit_bounty_activity(env: &Env, event: BountyActivity) {
+    if !legacy_events_enabled(env) {
+        return;
+    }
     let topics = (
         symbol_short!("activity"),
         event.bounty_id,
@@ -191,103 +213,6 @@ pub fn _emit_analytics_event(env: &Env, event: AnalyticsEvent) {
     env.events().publish(topics, event.clone());
 }
 
-// ============================================================================
-// Fee Events
-// ============================================================================
-
-#[contracttype]
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub enum FeeOperationType {
-    Lock,
-    Release,
-    Refund,
-}
-
-#[contracttype]
-#[derive(Clone, Debug)]
-pub struct FeeCollected {
-    pub operation_type: FeeOperationType,
-    pub bounty_id: u64,
-    pub amount: i128,
-    pub fee_rate: i128,
-    pub recipient: Address,
-    pub timestamp: u64,
-    pub metadata: EventMetadata,
-}
-
-pub fn _emit_fee_collected(env: &Env, event: FeeCollected) {
-    let topics = (symbol_short!("fee"), event.bounty_id);
-    env.events().publish(topics, event.clone());
-}
-
-#[contracttype]
-#[derive(Clone, Debug)]
-pub struct FeeConfigUpdated {
-    pub lock_fee_rate: i128,
-    pub release_fee_rate: i128,
-    pub fee_recipient: Address,
-    pub fee_enabled: bool,
-    pub timestamp: u64,
-    pub metadata: EventMetadata,
-}
-
-pub fn _emit_fee_config_updated(env: &Env, event: FeeConfigUpdated) {
-    let topics = (symbol_short!("fee_cfg"),);
-    env.events().publish(topics, event.clone());
-}
-
-// ============================================================================
-// Batch Operation Events
-// ============================================================================
-
-#[contracttype]
-#[derive(Clone, Debug)]
-pub struct BatchFundsLocked {
-    pub bounty_ids: soroban_sdk::Vec<u64>,
-    pub count: u32,
-    pub total_amount: i128,
-    pub depositor: Address,
-    pub timestamp: u64,
-    pub metadata: EventMetadata,
-}
-
-pub fn _emit_batch_funds_locked(env: &Env, event: BatchFundsLocked) {
-    let topics = (symbol_short!("b_lock"),);
-    env.events().publish(topics, event.clone());
-}
-
-#[contracttype]
-#[derive(Clone, Debug)]
-pub struct BatchFundsReleased {
-    pub bounty_ids: soroban_sdk::Vec<u64>,
-    pub count: u32,
-    pub total_amount: i128,
-    pub recipient: Address,
-    pub timestamp: u64,
-    pub metadata: EventMetadata,
-}
-
-pub fn _emit_batch_funds_released(env: &Env, event: BatchFundsReleased) {
-    let topics = (symbol_short!("b_rel"),);
-    env.events().publish(topics, event.clone());
-}
-
-#[contracttype]
-#[derive(Clone, Debug)]
-pub struct BatchFundsRefunded {
-    pub bounty_ids: soroban_sdk::Vec<u64>,
-    pub count: u32,
-    pub total_amount: i128,
-    pub refund_to: Address,
-    pub timestamp: u64,
-    pub metadata: EventMetadata,
-}
-
-pub fn _emit_batch_funds_refunded(env: &Env, event: BatchFundsRefunded) {
-    let topics = (symbol_short!("b_ref"),);
-    env.events().publish(topics, event.clone());
-}
-
 // ============================================================================
 // Admin Events
 // ============================================================================
@@ -303,24 +228,13 @@ pub struct AdminChanged {
 }
 
 pub fn _emit_admin_changed(env: &Env, event: AdminChanged) {
+    if !legacy_events_enabled(env) {
+        return;
+    }
     let topics = (symbol_short!("admin_ch"),);
     env.events().publish(topics, event.clone());
 }
 
-#[contracttype]
-#[derive(Clone, Debug)]
-pub struct ContractPaused {
-    pub paused: bool,
-    pub paused_by: Address,
-    pub timestamp: u64,
-    pub metadata: EventMetadata,
-}
-
-pub fn _emit_contract_paused(env: &Env, event: ContractPaused) {
-    let topics = (symbol_short!("pause"),);
-    env.events().publish(topics, event.clone());
-}
-
 // ============================================================================
 // Bounty Modification Events
 // ============================================================================
@@ -337,6 +251,9 @@ pub struct BountyDeadlineExtended {
 }
 
 pub fn _emit_bounty_deadline_extended(env: &Env, event: BountyDeadlineExtended) {
+    if !legacy_events_enabled(env) {
+        return;
+    }
     let topics = (symbol_short!("dl_ext"), event.bounty_id);
     env.events().publish(topics, event.clone());
 }
@@ -354,6 +271,9 @@ pub struct BountyAmountIncreased {
 }
 
 pub fn _emit_bounty_amount_increased(env: &Env, event: BountyAmountIncreased) {
+    if !legacy_events_enabled(env) {
+        return;
+    }
     let topics = (symbol_short!("amt_inc"), event.bounty_id);
     env.events().publish(topics, event.clone());
 }
@@ -386,6 +306,9 @@ pub struct ErrorOccurred {
 }
 
 pub fn _emit_error_occurred(env: &Env, event: ErrorOccurred) {
+    if !legacy_events_enabled(env) {
+        return;
+    }
     let topics = (symbol_short!("error"), event.error_type.clone());
     env.events().publish(topics, event.clone());
 }
@@ -406,6 +329,9 @@ pub struct MilestoneCompleted {
 }
 
 pub fn _emit_milestone_completed(env: &Env, event: MilestoneCompleted) {
+    if !legacy_events_enabled(env) {
+        return;
+    }
     let topics = (
         symbol_short!("mile_cmp"),
         event.bounty_id,
@@ -24,6 +24,7 @@ pub enum BountyStatus {
     Released,
     Refunded,
     PartiallyReleased,
+    Streaming,
 }
 
 #[contracttype]
@@ -47,16 +48,216 @@ pub struct PaginatedResult {
     pub has_more: bool,
 }
 
+/// Lifetime payout statistics for a contributor.
+///
+/// All fields are zero for an address that has never received a release.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContributorStats {
+    pub contributor: Address,
+    pub payout_count: u32,
+    pub total_earned: i128,
+    pub first_payout_at: u64,
+    pub last_payout_at: u64,
+}
+
+/// Single leaderboard row, ordered by `total_earned` descending.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EarnerEntry {
+    pub contributor: Address,
+    pub total_earned: i128,
+}
+
 // ============================================================================
 // Storage Index Keys
 // ============================================================================
 
 pub const BOUNTY_INDEX: &str = "BIDX";
+/// Legacy per-bounty status flags `(SIDX, status, id) -> true`, kept for migration.
 pub const STATUS_INDEX: &str = "SIDX";
+/// Legacy per-bounty depositor flags `(DIDX, depositor, id) -> true`, kept for migration.
 pub const DEPOSITOR_INDEX: &str = "DIDX";
+/// Legacy per-bounty amount bucket flags `(AIDX, bucket, id) -> true`, kept for migration.
 pub const AMOUNT_INDEX: &str = "AIDX";
+/// Legacy per-bounty daily bucket flags `(TIDX, day, id) -> true`, kept for migration.
 pub const TIMESTAMP_INDEX: &str = "TIDX";
 
+/// Full page of bounty IDs: `(IPAGE, list, page_no) -> Vec<u64>`
+pub const INDEX_PAGE: &str = "IPAGE";
+/// List head holding the length and the partially filled last page: `(IHEAD, list) -> IndexHead`
+pub const INDEX_HEAD: &str = "IHEAD";
+/// Position of an ID inside a list: `(IPOS, list, bounty_id) -> u32`
+pub const INDEX_POSITION: &str = "IPOS";
+
+/// Recipient of a bounty's release: `(RRCP, bounty_id) -> Address`
+pub const RELEASE_RECIPIENT: &str = "RRCP";
+/// Per-contributor totals: `(CSTAT, contributor) -> ContributorStats`
+pub const CONTRIBUTOR_STATS: &str = "CSTAT";
+/// Sorted leaderboard: `TOPE -> Vec<EarnerEntry>`
+pub const TOP_EARNERS: &str = "TOPE";
+
+/// Number of contributors kept on the top-earners leaderboard.
+pub const TOP_EARNERS_CAPACITY: u32 = 100;
+
+/// Maximum number of bounty IDs stored in a single index page.
+///
+/// Appends only touch the list head until a page fills up, and removals touch
+/// at most one extra page, so the cost of maintaining an index does not grow
+/// with the number of escrows.
+pub const INDEX_PAGE_SIZE: u32 = 50;
+
+/// Identifies one of the paged secondary indices.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum IndexList {
+    /// Every indexed bounty, in insertion order
+    All,
+    /// Bounties currently in the given status
+    Status(BountyStatus),
+    /// Bounties created by the given depositor
+    Depositor(Address),
+    /// Bounties that paid out to the given contributor
+    Contributor(Address),
+}
+
+/// Head entry of a paged list.
+///
+/// `len / INDEX_PAGE_SIZE` full pages live under their own keys; the
+/// remaining `len % INDEX_PAGE_SIZE` IDs are kept inline in `tail`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IndexHead {
+    pub len: u32,
+    pub tail: Vec<u64>,
+}
+
+// ============================================================================
+// Paged List Primitives
+// ============================================================================
+
+fn get_head(env: &Env, list: &IndexList) -> IndexHead {
+    env.storage()
+        .persistent()
+        .get(&(INDEX_HEAD, list.clone()))
+        .unwrap_or(IndexHead {
+            len: 0,
+            tail: Vec::new(env),
+        })
+}
+
+fn set_head(env: &Env, list: &IndexList, head: &IndexHead) {
+    if head.len == 0 {
+        env.storage()
+            .persistent()
+            .remove(&(INDEX_HEAD, list.clone()));
+    } else {
+        env.storage()
+            .persistent()
+            .set(&(INDEX_HEAD, list.clone()), head);
+    }
+}
+
+/// Returns the number of bounty IDs stored in `list`.
+pub fn index_len(env: &Env, list: &IndexList) -> u32 {
+    get_head(env, list).len
+}
+
+/// Returns a single page of bounty IDs from `list` (empty if out of range).
+pub fn get_index_page(env: &Env, list: &IndexList, page: u32) -> Vec<u64> {
+    let head = get_head(env, list);
+    if page == head.len / INDEX_PAGE_SIZE {
+        return head.tail;
+    }
+    env.storage()
+        .persistent()
+        .get(&(INDEX_PAGE, list.clone(), page))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Returns the IDs at positions `start..end` of `list`, clamped to its length.
+pub fn get_index_range(env: &Env, list: &IndexList, start: u32, end: u32) -> Vec<u64> {
+    let end = end.min(index_len(env, list));
+    let mut bounty_ids = Vec::new(env);
+    let mut pos = start;
+    while pos < end {
+        let page_no = pos / INDEX_PAGE_SIZE;
+        let page = get_index_page(env, list, page_no);
+        let page_end = end.min((page_no + 1) * INDEX_PAGE_SIZE);
+        bounty_ids.append(&page.slice(pos % INDEX_PAGE_SIZE..page_end - page_no * INDEX_PAGE_SIZE));
+        pos = page_end;
+    }
+    bounty_ids
+}
+
+/// Checks whether `bounty_id` is a member of `list`.
+pub fn index_contains(env: &Env, list: &IndexList, bounty_id: u64) -> bool {
+    env.storage()
+        .persistent()
+        .has(&(INDEX_POSITION, list.clone(), bounty_id))
+}
+
+/// Appends `bounty_id` to `list`. Callers must not insert an ID twice.
+fn index_insert(env: &Env, list: &IndexList, bounty_id: u64) {
+    let mut head = get_head(env, list);
+    let pos = head.len;
+
+    head.tail.push_back(bounty_id);
+    head.len += 1;
+    if head.tail.len() == INDEX_PAGE_SIZE {
+        // Tail is full: flush it to its own page
+        let page_no = pos / INDEX_PAGE_SIZE;
+        env.storage()
+            .persistent()
+            .set(&(INDEX_PAGE, list.clone(), page_no), &head.tail);
+        head.tail = Vec::new(env);
+    }
+
+    set_head(env, list, &head);
+    env.storage()
+        .persistent()
+        .set(&(INDEX_POSITION, list.clone(), bounty_id), &pos);
+}
+
+/// Removes `bounty_id` from `list` by moving the last entry into its slot.
+///
+/// Ordering inside a list is therefore not stable across removals.
+fn index_remove(env: &Env, list: &IndexList, bounty_id: u64) {
+    let storage = env.storage().persistent();
+    let pos_key = (INDEX_POSITION, list.clone(), bounty_id);
+    let pos: u32 = match storage.get(&pos_key) {
+        Some(pos) => pos,
+        None => return,
+    };
+
+    let mut head = get_head(env, list);
+    if head.tail.is_empty() {
+        // Last page is full: pull it back in as the tail
+        let page_no = head.len / INDEX_PAGE_SIZE - 1;
+        head.tail = storage.get(&(INDEX_PAGE, list.clone(), page_no)).unwrap();
+        storage.remove(&(INDEX_PAGE, list.clone(), page_no));
+    }
+    let moved_id = head.tail.pop_back().unwrap();
+    head.len -= 1;
+
+    if pos != head.len {
+        let page_no = pos / INDEX_PAGE_SIZE;
+        let slot = pos % INDEX_PAGE_SIZE;
+        if page_no == head.len / INDEX_PAGE_SIZE {
+            head.tail.set(slot, moved_id);
+        } else {
+            let page_key = (INDEX_PAGE, list.clone(), page_no);
+            let mut page: Vec<u64> = storage.get(&page_key).unwrap();
+            page.set(slot, moved_id);
+            storage.set(&page_key, &page);
+        }
+        storage.set(&(INDEX_POSITION, list.clone(), moved_id), &pos);
+    }
+
+    set_head(env, list, &head);
+    storage.remove(&pos_key);
+}
+
 // ============================================================================
 // Index Management Functions
 // ============================================================================
@@ -67,45 +268,34 @@ pub fn index_bounty(env: &Env, bounty: IndexedBounty) {
     let key = (BOUNTY_INDEX, bounty.bounty_id);
     env.storage().persistent().set(&key, &bounty);
 
-    // Status index: (status, bounty_id) -> true
-    let status_key = (STATUS_INDEX, bounty.status.clone(), bounty.bounty_id);
-    env.storage().persistent().set(&status_key, &true);
-
-    // Depositor index: (depositor, bounty_id) -> true
-    let depositor_key = (DEPOSITOR_INDEX, bounty.depositor.clone(), bounty.bounty_id);
-    env.storage().persistent().set(&depositor_key, &true);
-
-    // Amount index: (amount_bucket, bounty_id) -> true
-    // Using buckets to group similar amounts
-    let amount_bucket = (bounty.amount / 1_000_000_000) as u64;
-    let amount_key = (AMOUNT_INDEX, amount_bucket, bounty.bounty_id);
-    env.storage().persistent().set(&amount_key, &true);
-
-    // Timestamp index: (timestamp_bucket, bounty_id) -> true
-    // Using daily buckets (86400 seconds = 1 day)
-    let timestamp_bucket = bounty.created_at / 86400;
-    let timestamp_key = (TIMESTAMP_INDEX, timestamp_bucket, bounty.bounty_id);
-    env.storage().persistent().set(&timestamp_key, &true);
+    // Paged secondary indices
+    index_insert(env, &IndexList::All, bounty.bounty_id);
+    index_insert(
+        env,
+        &IndexList::Status(bounty.status.clone()),
+        bounty.bounty_id,
+    );
+    index_insert(
+        env,
+        &IndexList::Depositor(bounty.depositor.clone()),
+        bounty.bounty_id,
+    );
 }
 
 /// Updates the status of a bounty and re-indexes accordingly
 pub fn update_bounty_status(env: &Env, bounty_id: u64, new_status: BountyStatus) {
     let key = (BOUNTY_INDEX, bounty_id);
     if let Some(mut bounty) = env.storage().persistent().get::<_, IndexedBounty>(&key) {
-        // Remove old status index entry
-        let old_status_key = (STATUS_INDEX, bounty.status.clone(), bounty_id);
-        env.storage().persistent().remove(&old_status_key);
+        // Move between status lists
+        index_remove(env, &IndexList::Status(bounty.status.clone()), bounty_id);
+        index_insert(env, &IndexList::Status(new_status.clone()), bounty_id);
 
         // Update bounty
-        bounty.status = new_status.clone();
+        bounty.status = new_status;
         bounty.updated_at = env.ledger().timestamp();
 
         // Save updated bounty
         env.storage().persistent().set(&key, &bounty);
-
-        // Add new status index entry
-        let new_status_key = (STATUS_INDEX, new_status, bounty_id);
-        env.storage().persistent().set(&new_status_key, &true);
     }
 }
 
@@ -119,22 +309,12 @@ pub fn get_bounty(env: &Env, bounty_id: u64) -> Option<IndexedBounty> {
 pub fn update_bounty_amount(env: &Env, bounty_id: u64, new_amount: i128) {
     let key = (BOUNTY_INDEX, bounty_id);
     if let Some(mut bounty) = env.storage().persistent().get::<_, IndexedBounty>(&key) {
-        // Remove old amount index entry
-        let old_amount_bucket = (bounty.amount / 1_000_000_000) as u64;
-        let old_amount_key = (AMOUNT_INDEX, old_amount_bucket, bounty_id);
-        env.storage().persistent().remove(&old_amount_key);
-
         // Update bounty
         bounty.amount = new_amount;
         bounty.updated_at = env.ledger().timestamp();
 
         // Save updated bounty
         env.storage().persistent().set(&key, &bounty);
-
-        // Add new amount index entry
-        let new_amount_bucket = (new_amount / 1_000_000_000) as u64;
-        let new_amount_key = (AMOUNT_INDEX, new_amount_bucket, bounty_id);
-        env.storage().persistent().set(&new_amount_key, &true);
     }
 }
 
@@ -153,19 +333,13 @@ pub fn remove_bounty(env: &Env, bounty_id: u64) {
     let key = (BOUNTY_INDEX, bounty_id);
     if let Some(bounty) = env.storage().persistent().get::<_, IndexedBounty>(&key) {
         // Remove from all indices
-        let status_key = (STATUS_INDEX, bounty.status.clone(), bounty_id);
-        env.storage().persistent().remove(&status_key);
-
-        let depositor_key = (DEPOSITOR_INDEX, bounty.depositor.clone(), bounty_id);
-        env.storage().persistent().remove(&depositor_key);
-
-        let amount_bucket = (bounty.amount / 1_000_000_000) as u64;
-        let amount_key = (AMOUNT_INDEX, amount_bucket, bounty_id);
-        env.storage().persistent().remove(&amount_key);
-
-        let timestamp_bucket = bounty.created_at / 86400;
-        let timestamp_key = (TIMESTAMP_INDEX, timestamp_bucket, bounty_id);
-        env.storage().persistent().remove(&timestamp_key);
+        index_remove(env, &IndexList::All, bounty_id);
+        index_remove(env, &IndexList::Status(bounty.status.clone()), bounty_id);
+        index_remove(
+            env,
+            &IndexList::Depositor(bounty.depositor.clone()),
+            bounty_id,
+        );
 
         // Remove primary entry
         env.storage().persistent().remove(&key);
@@ -178,30 +352,179 @@ pub fn bounty_exists(env: &Env, bounty_id: u64) -> bool {
     env.storage().persistent().has(&key)
 }
 
+/// Collects every bounty ID stored in `list`.
+pub fn get_index_ids(env: &Env, list: &IndexList) -> Vec<u64> {
+    get_index_range(env, list, 0, index_len(env, list))
+}
+
 /// Gets all bounty IDs for a specific depositor
 pub fn get_depositor_bounty_ids(env: &Env, depositor: &Address) -> Vec<u64> {
-    let mut bounty_ids = Vec::new(env);
+    get_index_ids(env, &IndexList::Depositor(depositor.clone()))
+}
+
+/// Gets all bounty IDs with a specific status
+pub fn get_status_bounty_ids(env: &Env, status: &BountyStatus) -> Vec<u64> {
+    get_index_ids(env, &IndexList::Status(status.clone()))
+}
 
-    for bounty_id in 0..1_000_000u64 {
-        let key = (DEPOSITOR_INDEX, depositor.clone(), bounty_id);
-        if env.storage().persistent().has(&key) {
-            bounty_ids.push_back(bounty_id);
+// ============================================================================
+// Contributor Index
+// ============================================================================
+
+/// Records a release payout against its recipient.
+///
+/// Stores the recipient for the bounty, adds the bounty to the contributor's
+/// list, updates their lifetime stats and the top-earners leaderboard.
+pub fn record_release(env: &Env, bounty_id: u64, recipient: &Address, amount: i128) {
+    let now = env.ledger().timestamp();
+    let storage = env.storage().persistent();
+
+    storage.set(&(RELEASE_RECIPIENT, bounty_id), recipient);
+
+    let list = IndexList::Contributor(recipient.clone());
+    if !index_contains(env, &list, bounty_id) {
+        index_insert(env, &list, bounty_id);
+    }
+
+    let mut stats = get_contributor_stats(env, recipient);
+    if stats.payout_count == 0 {
+        stats.first_payout_at = now;
+    }
+    stats.payout_count += 1;
+    stats.total_earned += amount;
+    stats.last_payout_at = now;
+    storage.set(&(CONTRIBUTOR_STATS, recipient.clone()), &stats);
+
+    update_top_earners(env, recipient, stats.total_earned);
+}
+
+/// Returns the address a bounty was released to, if any.
+pub fn get_release_recipient(env: &Env, bounty_id: u64) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&(RELEASE_RECIPIENT, bounty_id))
+}
+
+/// Returns lifetime payout stats for `contributor` (zeroed if none).
+pub fn get_contributor_stats(env: &Env, contributor: &Address) -> ContributorStats {
+    env.storage()
+        .persistent()
+        .get(&(CONTRIBUTOR_STATS, contributor.clone()))
+        .unwrap_or(ContributorStats {
+            contributor: contributor.clone(),
+            payout_count: 0,
+            total_earned: 0,
+            first_payout_at: 0,
+            last_payout_at: 0,
+        })
+}
+
+/// Returns the full leaderboard, highest earner first.
+pub fn get_top_earners(env: &Env) -> Vec<EarnerEntry> {
+    env.storage()
+        .persistent()
+        .get(&TOP_EARNERS)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Moves `contributor` to its sorted position on the bounded leaderboard.
+///
+/// The list never exceeds `TOP_EARNERS_CAPACITY` entries, so the cost is
+/// independent of the number of contributors.
+fn update_top_earners(env: &Env, contributor: &Address, total_earned: i128) {
+    let mut board = get_top_earners(env);
+
+    if let Some(pos) = board.iter().position(|e| &e.contributor == contributor) {
+        board.remove(pos as u32);
+    } else if board.len() >= TOP_EARNERS_CAPACITY {
+        match board.last() {
+            Some(last) if total_earned <= last.total_earned => return,
+            _ => {}
         }
+        board.pop_back();
     }
 
-    bounty_ids
+    let insert_at = board
+        .iter()
+        .position(|e| e.total_earned < total_earned)
+        .map(|i| i as u32)
+        .unwrap_or(board.len());
+    board.insert(
+        insert_at,
+        EarnerEntry {
+            contributor: contributor.clone(),
+            total_earned,
+        },
+    );
+
+    env.storage().persistent().set(&TOP_EARNERS, &board);
 }
 
-/// Gets all bounty IDs with a specific status
-pub fn get_status_bounty_ids(env: &Env, status: &BountyStatus) -> Vec<u64> {
-    let mut bounty_ids = Vec::new(env);
+// ============================================================================
+// Migration
+// ============================================================================
 
-    for bounty_id in 0..1_000_000u64 {
-        let key = (STATUS_INDEX, status.clone(), bounty_id);
-        if env.storage().persistent().has(&key) {
-            bounty_ids.push_back(bounty_id);
+/// Moves bounties indexed with the legacy per-ID flag keys into the paged lists.
+///
+/// Walks `limit` bounty IDs starting at `start_id`. Every primary record found
+/// is added to whichever of the `All`, `Status` and `Depositor` lists do not
+/// hold it yet, and all of its legacy per-ID flag entries are deleted,
+/// including status flags left behind for earlier statuses. The amount and
+/// timestamp flags were never queried, so they are dropped without a
+/// replacement. Ranges can be re-run safely.
+///
+/// # Returns
+/// The number of bounties added to the `All` list in this call
+pub fn migrate_legacy_index(env: &Env, start_id: u64, limit: u32) -> u32 {
+    let mut migrated = 0u32;
+    let end_id = start_id.saturating_add(limit as u64);
+
+    for bounty_id in start_id..end_id {
+        let bounty = match get_bounty(env, bounty_id) {
+            Some(bounty) => bounty,
+            None => continue,
+        };
+
+        // A bounty touched since the upgrade may already sit in some lists
+        if index_insert_missing(env, &IndexList::All, bounty_id) {
+            migrated += 1;
         }
+        index_insert_missing(env, &IndexList::Status(bounty.status.clone()), bounty_id);
+        index_insert_missing(
+            env,
+            &IndexList::Depositor(bounty.depositor.clone()),
+            bounty_id,
+        );
+
+        let storage = env.storage().persistent();
+        for status in [
+            BountyStatus::None,
+            BountyStatus::Locked,
+            BountyStatus::Released,
+            BountyStatus::Refunded,
+            BountyStatus::PartiallyReleased,
+            BountyStatus::Streaming,
+        ] {
+            storage.remove(&(STATUS_INDEX, status, bounty_id));
+        }
+        storage.remove(&(DEPOSITOR_INDEX, bounty.depositor.clone(), bounty_id));
+        storage.remove(&(
+            AMOUNT_INDEX,
+            (bounty.amount / 1_000_000_000) as u64,
+            bounty_id,
+        ));
+        storage.remove(&(TIMESTAMP_INDEX, bounty.created_at / 86400, bounty_id));
     }
 
-    bounty_ids
+    migrated
+}
+
+/// Appends `bounty_id` to `list` unless it is already a member.
+/// Returns whether it was added.
+fn index_insert_missing(env: &Env, list: &IndexList, bounty_id: u64) -> bool {
+    if index_contains(env, list, bounty_id) {
+        return false;
+    }
+    index_insert(env, list, bounty_id);
+    true
 }
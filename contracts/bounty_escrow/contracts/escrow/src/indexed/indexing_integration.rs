@@ -35,7 +35,7 @@ use crate::indexed::enhanced_events::{
     EnhancedFundsReleased,
 };
 use crate::indexed::indexed_storage::{
-    index_bounty, update_bounty_status, BountyStatus, IndexedBounty,
+    index_bounty, record_release, update_bounty_status, BountyStatus, IndexedBounty,
 };
 use crate::RefundMode;
 use soroban_sdk::{Address, Env};
@@ -114,6 +114,7 @@ pub fn on_funds_locked(
 ///
 /// # State Changes
 /// - Updates bounty status filters
+/// - Records the recipient in the contributor index and stats
 /// - Emits `EnhancedFundsReleased` event
 /// - Emits `BountyStatusChanged` event
 /// - Emits `BountyActivity` event
@@ -134,6 +135,7 @@ pub fn on_funds_released(
         BountyStatus::Released
     };
     update_bounty_status(env, bounty_id, new_status.clone());
+    record_release(env, bounty_id, recipient, amount);
 
     // Create event metadata
     let metadata = create_event_metadata(env);
@@ -325,7 +327,7 @@ pub fn _on_deadline_extended(
     new_deadline: u64,
     extended_by: &Address,
 ) {
-    use crate::indexed::enhanced_events::{BountyDeadlineExtended, _emit_bounty_deadline_extended};
+    use crate::indexed::enhanced_events::{_emit_bounty_deadline_extended, BountyDeadlineExtended};
 
     let timestamp = env.ledger().timestamp();
     let metadata = create_event_metadata(env);
@@ -368,7 +370,7 @@ pub fn _on_amount_increased(
     increase_amount: i128,
     increased_by: &Address,
 ) {
-    use crate::indexed::enhanced_events::{BountyAmountIncreased, _emit_bounty_amount_increased};
+    use crate::indexed::enhanced_events::{_emit_bounty_amount_increased, BountyAmountIncreased};
 
     let timestamp = env.ledger().timestamp();
     let metadata = create_event_metadata(env);
@@ -3,6 +3,7 @@ pub mod enhanced_events;
 pub mod indexed_storage;
 pub mod indexing_integration;
 pub mod query_functions;
+pub mod time_series;
 
 pub use enhanced_events::*;
 
@@ -22,9 +22,10 @@
 //! │   ┌─────────────────────────────────────────────────────┐   │
 //! │   │               Bounty Index (Storage)                │   │
 //! │   │                                                     │   │
-//! │   │  • BOUNTY_INDEX: Map<u64, IndexedBounty>            │   │
-//! │   │  • STATUS_INDEX: Map<(Status, u64), ()>             │   │
-//! │   │  • DEPOSITOR_INDEX: Map<(Address, u64), ()>         │   │
+//! │   │  • BOUNTY_INDEX: u64 -> IndexedBounty               │   │
+//! │   │  • IndexList::All        -> pages of bounty IDs     │   │
+//! │   │  • IndexList::Status     -> pages of bounty IDs     │   │
+//! │   │  • IndexList::Depositor  -> pages of bounty IDs     │   │
 //! │   └─────────────────────────────────────────────────────┘   │
 //! │                                                             │
 //! └─────────────────────────────────────────────────────────────┘
@@ -48,9 +49,10 @@
 //! ```
 
 use crate::indexed::indexed_storage::{
-    BountyStatus, IndexedBounty, PaginatedResult, QueryFilter, BOUNTY_INDEX, DEPOSITOR_INDEX,
-    STATUS_INDEX,
+    get_index_ids, get_index_page, get_top_earners, index_len, BountyStatus, EarnerEntry,
+    IndexList, IndexedBounty, PaginatedResult, QueryFilter, BOUNTY_INDEX, INDEX_PAGE_SIZE,
 };
+use crate::indexed::time_series::{get_daily_bucket, MAX_TIME_SERIES_DAYS, SECONDS_PER_DAY};
 use soroban_sdk::{contracttype, Address, Env, Vec};
 
 // ============================================================================
@@ -111,67 +113,26 @@ pub fn query_bounties(
 // ============================================================================
 
 fn get_filtered_bounties(env: &Env, filter: &QueryFilter) -> Vec<IndexedBounty> {
-    // Optimize by using the most selective index first
-    if let status = &filter.status {
-        return get_by_status(env, status.clone(), filter);
+    // Optimize by using the most selective index first.
+    // `BountyStatus::None` means "any status".
+    if filter.status != BountyStatus::None {
+        return get_from_list(env, &IndexList::Status(filter.status.clone()), filter);
     }
 
     if let Some(depositor) = &filter.depositor {
-        return get_by_depositor(env, depositor.clone(), filter);
+        return get_from_list(env, &IndexList::Depositor(depositor.clone()), filter);
     }
 
-    get_all_filtered(env, filter)
+    get_from_list(env, &IndexList::All, filter)
 }
 
-fn get_by_status(env: &Env, status: BountyStatus, filter: &QueryFilter) -> Vec<IndexedBounty> {
+/// Walks every entry of `list` and keeps the bounties matching `filter`.
+fn get_from_list(env: &Env, list: &IndexList, filter: &QueryFilter) -> Vec<IndexedBounty> {
     let mut results = Vec::new(env);
-    let mut bounty_id = 0u64;
 
-    loop {
-        let key = (STATUS_INDEX, status.clone(), bounty_id);
-        if env.storage().persistent().has(&key) {
-            if let Some(bounty) = get_bounty_if_matches(env, bounty_id, filter) {
-                results.push_back(bounty);
-            }
-        }
-        bounty_id += 1;
-        if bounty_id > 1_000_000 {
-            break;
-        }
-    }
-
-    results
-}
-
-fn get_by_depositor(env: &Env, depositor: Address, filter: &QueryFilter) -> Vec<IndexedBounty> {
-    let mut results = Vec::new(env);
-    let mut bounty_id = 0u64;
-
-    loop {
-        let key = (DEPOSITOR_INDEX, depositor.clone(), bounty_id);
-        if env.storage().persistent().has(&key) {
-            if let Some(bounty) = get_bounty_if_matches(env, bounty_id, filter) {
-                results.push_back(bounty);
-            }
-        }
-        bounty_id += 1;
-        if bounty_id > 1_000_000 {
-            break;
-        }
-    }
-
-    results
-}
-
-fn get_all_filtered(env: &Env, filter: &QueryFilter) -> Vec<IndexedBounty> {
-    let mut results = Vec::new(env);
-
-    for bounty_id in 0..1_000_000u64 {
-        let key = (BOUNTY_INDEX, bounty_id);
-        if let Some(bounty) = env.storage().persistent().get::<_, IndexedBounty>(&key) {
-            if matches_filter(&bounty, filter) {
-                results.push_back(bounty);
-            }
+    for bounty_id in get_index_ids(env, list).iter() {
+        if let Some(bounty) = get_bounty_if_matches(env, bounty_id, filter) {
+            results.push_back(bounty);
         }
     }
 
@@ -189,10 +150,8 @@ fn get_bounty_if_matches(env: &Env, bounty_id: u64, filter: &QueryFilter) -> Opt
 }
 
 fn matches_filter(bounty: &IndexedBounty, filter: &QueryFilter) -> bool {
-    if let ref status = filter.status {
-        if &bounty.status != status {
-            return false;
-        }
+    if filter.status != BountyStatus::None && bounty.status != filter.status {
+        return false;
     }
 
     if let Some(ref depositor) = filter.depositor {
@@ -326,14 +285,20 @@ pub fn get_recent_bounties(env: &Env, count: u32) -> Vec<IndexedBounty> {
     let mut results = Vec::new(env);
     let mut found = 0u32;
 
-    // Iterate backwards to get most recent first
-    for bounty_id in (0..1_000_000u64).rev() {
-        let key = (BOUNTY_INDEX, bounty_id);
-        if let Some(bounty) = env.storage().persistent().get::<_, IndexedBounty>(&key) {
-            results.push_back(bounty);
-            found += 1;
-            if found >= count {
-                break;
+    // Walk the insertion-ordered list backwards to get most recent first
+    let list = IndexList::All;
+    let mut page_no = index_len(env, &list).div_ceil(INDEX_PAGE_SIZE);
+    while page_no > 0 && found < count {
+        page_no -= 1;
+        let page = get_index_page(env, &list, page_no);
+        for bounty_id in page.iter().rev() {
+            let key = (BOUNTY_INDEX, bounty_id);
+            if let Some(bounty) = env.storage().persistent().get::<_, IndexedBounty>(&key) {
+                results.push_back(bounty);
+                found += 1;
+                if found >= count {
+                    break;
+                }
             }
         }
     }
@@ -384,6 +349,8 @@ pub struct BountyStats {
     pub total_released: i128,
     pub total_refunded: i128,
     pub total_partially_released: i128,
+    pub streaming_count: u32,
+    pub total_streaming: i128,
 }
 
 /// Get comprehensive statistics for the entire bounty system.
@@ -402,29 +369,33 @@ pub fn get_bounty_stats(env: &Env) -> BountyStats {
     let mut total_released = 0i128;
     let mut total_refunded = 0i128;
     let mut total_partially_released = 0i128;
-
-    for bounty_id in 0..1_000_000u64 {
-        let key = (BOUNTY_INDEX, bounty_id);
-        if let Some(bounty) = env.storage().persistent().get::<_, IndexedBounty>(&key) {
-            match bounty.status {
-                BountyStatus::Locked => {
-                    locked_count += 1;
-                    total_locked += bounty.amount;
-                }
-                BountyStatus::Released => {
-                    released_count += 1;
-                    total_released += bounty.amount;
-                }
-                BountyStatus::Refunded => {
-                    refunded_count += 1;
-                    total_refunded += bounty.amount;
-                }
-                BountyStatus::PartiallyReleased => {
-                    partially_released_count += 1;
-                    total_partially_released += bounty.amount;
-                }
-                BountyStatus::None => {}
+    let mut streaming_count = 0u32;
+    let mut total_streaming = 0i128;
+
+    let bounties = get_from_list(env, &IndexList::All, &match_all());
+    for bounty in bounties.iter() {
+        match bounty.status {
+            BountyStatus::Locked => {
+                locked_count += 1;
+                total_locked += bounty.amount;
+            }
+            BountyStatus::Released => {
+                released_count += 1;
+                total_released += bounty.amount;
             }
+            BountyStatus::Refunded => {
+                refunded_count += 1;
+                total_refunded += bounty.amount;
+            }
+            BountyStatus::PartiallyReleased => {
+                partially_released_count += 1;
+                total_partially_released += bounty.amount;
+            }
+            BountyStatus::Streaming => {
+                streaming_count += 1;
+                total_streaming += bounty.amount;
+            }
+            BountyStatus::None => {}
         }
     }
 
@@ -437,6 +408,32 @@ pub fn get_bounty_stats(env: &Env) -> BountyStats {
         total_released,
         total_refunded,
         total_partially_released,
+        streaming_count,
+        total_streaming,
+    }
+}
+
+/// Returns the number of bounties currently in `status` without loading them.
+pub fn count_bounties_by_status(env: &Env, status: BountyStatus) -> u32 {
+    index_len(env, &IndexList::Status(status))
+}
+
+/// Returns one page of bounty IDs from the paged index.
+///
+/// Pages hold at most `INDEX_PAGE_SIZE` IDs, so callers can walk a list of
+/// any size in bounded steps.
+pub fn get_index_page_ids(env: &Env, list: IndexList, page: u32) -> Vec<u64> {
+    get_index_page(env, &list, page)
+}
+
+fn match_all() -> QueryFilter {
+    QueryFilter {
+        status: BountyStatus::None,
+        depositor: None,
+        min_amount: None,
+        max_amount: None,
+        from_timestamp: None,
+        to_timestamp: None,
     }
 }
 
@@ -470,7 +467,7 @@ pub fn get_depositor_stats(env: &Env, depositor: &Address) -> DepositorStats {
                     completed_bounties += 1;
                     total_released_value += bounty.amount;
                 }
-                BountyStatus::PartiallyReleased => {
+                BountyStatus::PartiallyReleased | BountyStatus::Streaming => {
                     active_bounties += 1;
                     total_locked_value += bounty.amount;
                 }
@@ -499,58 +496,109 @@ pub struct DepositorStats {
     pub total_released_value: i128,
 }
 
+/// Get one page of the bounties released to a specific contributor.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `contributor` - Address that received the releases
+/// * `page` - Zero-based page of the contributor's index
+///
+/// # Returns
+/// * `Vec<IndexedBounty>` - Up to `INDEX_PAGE_SIZE` bounties paid out to the contributor
+pub fn get_bounties_by_contributor(
+    env: &Env,
+    contributor: &Address,
+    page: u32,
+) -> Vec<IndexedBounty> {
+    let list = IndexList::Contributor(contributor.clone());
+    let mut results = Vec::new(env);
+    for bounty_id in get_index_page(env, &list, page).iter() {
+        if let Some(bounty) = get_bounty_if_matches(env, bounty_id, &match_all()) {
+            results.push_back(bounty);
+        }
+    }
+    results
+}
+
+/// Get one page of the top-earners leaderboard.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `page` - Zero-based page index
+/// * `page_size` - Number of entries per page
+///
+/// # Returns
+/// * `Vec<EarnerEntry>` - Contributors ordered by total earned, highest first
+pub fn get_top_earners_page(env: &Env, page: u32, page_size: u32) -> Vec<EarnerEntry> {
+    let board = get_top_earners(env);
+    let mut results = Vec::new(env);
+
+    let start = page.saturating_mul(page_size);
+    let end = start.saturating_add(page_size).min(board.len());
+    for i in start..end {
+        results.push_back(board.get(i).unwrap());
+    }
+
+    results
+}
+
 /// Generate time-series data for bounty activity.
 ///
-/// Useful for generating charts and analytics.
+/// Reads the daily volume buckets maintained by `time_series::record_volume`,
+/// so the cost grows with the number of days in the range rather than with the
+/// number of bounties.
 ///
 /// # Arguments
 /// * `env` - The contract environment
-/// * `from` - Start timestamp
-/// * `to` - End timestamp
-/// * `interval` - Time interval in seconds (e.g., 86400 for daily)
+/// * `from` - Start timestamp (rounded down to the start of its day)
+/// * `to` - End timestamp (exclusive), clamped to `MAX_TIME_SERIES_DAYS` after `from`
+/// * `interval` - Time interval in seconds, rounded down to whole days (minimum one day)
 ///
 /// # Returns
 /// * `Vec<TimeSeriesPoint>` - List of data points with aggregate stats per interval
 pub fn get_time_series_data(env: &Env, from: u64, to: u64, interval: u64) -> Vec<TimeSeriesPoint> {
     let mut data_points: Vec<TimeSeriesPoint> = Vec::new(env);
-    let mut current = from;
-
-    while current < to {
-        let next = current + interval;
-
-        let bounties = get_bounties_by_date_range(env, current, next);
-        let mut locked_amount = 0i128;
-        let mut released_amount = 0i128;
-        let mut count = 0u32;
-
-        for i in 0..bounties.len() {
-            if let Some(bounty) = bounties.get(i) {
-                count += 1;
-                match bounty.status {
-                    BountyStatus::Locked | BountyStatus::PartiallyReleased => {
-                        locked_amount += bounty.amount;
-                    }
-                    BountyStatus::Released => {
-                        released_amount += bounty.amount;
-                    }
-                    _ => {}
-                }
-            }
+    let days_per_point = core::cmp::max(interval / SECONDS_PER_DAY, 1);
+    let mut day = from / SECONDS_PER_DAY;
+    let end_day = core::cmp::min(
+        to.div_ceil(SECONDS_PER_DAY),
+        day.saturating_add(MAX_TIME_SERIES_DAYS),
+    );
+
+    while day < end_day {
+        let next = core::cmp::min(day + days_per_point, end_day);
+        let mut point = TimeSeriesPoint {
+            timestamp: day * SECONDS_PER_DAY,
+            count: 0,
+            locked_amount: 0,
+            released_amount: 0,
+            refunded_amount: 0,
+            fee_amount: 0,
+        };
+
+        for d in day..next {
+            let bucket = get_daily_bucket(env, d);
+            point.count += bucket.locked_count;
+            point.locked_amount += bucket.locked_amount;
+            point.released_amount += bucket.released_amount;
+            point.refunded_amount += bucket.refunded_amount;
+            point.fee_amount += bucket.fee_amount;
         }
 
-        data_points.push_back(TimeSeriesPoint {
-            timestamp: current,
-            count,
-            locked_amount,
-            released_amount,
-        });
-
-        current = next;
+        data_points.push_back(point);
+        day = next;
     }
 
     data_points
 }
 
+/// Aggregated activity for one interval of a time series.
+///
+/// # Fields
+/// * `timestamp` - Start of the interval
+/// * `count` - Number of bounties locked during the interval
+/// * `locked_amount` / `released_amount` / `refunded_amount` - Funds moved during the interval
+/// * `fee_amount` - Fees collected during the interval
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct TimeSeriesPoint {
@@ -558,4 +606,6 @@ pub struct TimeSeriesPoint {
     pub count: u32,
     pub locked_amount: i128,
     pub released_amount: i128,
+    pub refunded_amount: i128,
+    pub fee_amount: i128,
 }
@@ -0,0 +1,135 @@
+//! # Daily Volume Buckets
+//!
+//! Maintains one aggregate record per UTC day, updated as funds move through
+//! the contract. Time-series queries read these buckets directly instead of
+//! re-scanning bounties, so their cost is proportional to the number of days
+//! requested.
+//!
+//! ```text
+//! lock / release / refund / fee ──► record_volume() ──► (DBKT, day) -> DailyBucket
+//!                                         │
+//!                                         └──► AnalyticsEvent (DailyVolume)
+//! ```
+
+use crate::indexed::enhanced_events::{_emit_analytics_event, AnalyticsEvent, AnalyticsType};
+use crate::ttl;
+use soroban_sdk::{contracttype, Env};
+
+/// Daily bucket key prefix: `(DBKT, day_index) -> DailyBucket`
+pub const DAILY_BUCKET: &str = "DBKT";
+
+/// Length of one bucket in seconds.
+pub const SECONDS_PER_DAY: u64 = 86_400;
+
+/// Maximum number of days a single time-series query reads.
+pub const MAX_TIME_SERIES_DAYS: u64 = 366;
+
+/// Kind of fund movement recorded in a bucket.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum VolumeKind {
+    Locked,
+    Released,
+    Refunded,
+    Fee,
+}
+
+/// Aggregated fund movements for a single day.
+///
+/// # Fields
+/// * `day_start` - Unix timestamp of the start of the day (UTC)
+/// * `locked_amount` / `locked_count` - Funds locked and number of locks
+/// * `released_amount` / `released_count` - Funds released and number of releases
+/// * `refunded_amount` / `refunded_count` - Funds refunded and number of refunds
+/// * `fee_amount` / `fee_count` - Fees collected and number of fee transfers
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DailyBucket {
+    pub day_start: u64,
+    pub locked_amount: i128,
+    pub locked_count: u32,
+    pub released_amount: i128,
+    pub released_count: u32,
+    pub refunded_amount: i128,
+    pub refunded_count: u32,
+    pub fee_amount: i128,
+    pub fee_count: u32,
+}
+
+impl DailyBucket {
+    fn empty(day: u64) -> Self {
+        DailyBucket {
+            day_start: day * SECONDS_PER_DAY,
+            locked_amount: 0,
+            locked_count: 0,
+            released_amount: 0,
+            released_count: 0,
+            refunded_amount: 0,
+            refunded_count: 0,
+            fee_amount: 0,
+            fee_count: 0,
+        }
+    }
+
+    /// Total number of recorded operations in the bucket.
+    pub fn operation_count(&self) -> u32 {
+        self.locked_count + self.released_count + self.refunded_count + self.fee_count
+    }
+
+    /// Total funds moved in or out of escrow (fees excluded).
+    pub fn volume(&self) -> i128 {
+        self.locked_amount + self.released_amount + self.refunded_amount
+    }
+}
+
+/// Returns the bucket for `day` (days since the Unix epoch), zeroed if untouched.
+pub fn get_daily_bucket(env: &Env, day: u64) -> DailyBucket {
+    env.storage()
+        .persistent()
+        .get(&(DAILY_BUCKET, day))
+        .unwrap_or(DailyBucket::empty(day))
+}
+
+/// Adds `count` operations totalling `amount` to today's bucket, keeps the
+/// bucket alive under the active TTL policy and emits the updated daily totals
+/// as an `AnalyticsEvent`.
+pub fn record_volume(env: &Env, kind: VolumeKind, amount: i128, count: u32) {
+    let now = env.ledger().timestamp();
+    let day = now / SECONDS_PER_DAY;
+    let mut bucket = get_daily_bucket(env, day);
+
+    match kind {
+        VolumeKind::Locked => {
+            bucket.locked_amount += amount;
+            bucket.locked_count += count;
+        }
+        VolumeKind::Released => {
+            bucket.released_amount += amount;
+            bucket.released_count += count;
+        }
+        VolumeKind::Refunded => {
+            bucket.refunded_amount += amount;
+            bucket.refunded_count += count;
+        }
+        VolumeKind::Fee => {
+            bucket.fee_amount += amount;
+            bucket.fee_count += count;
+        }
+    }
+
+    let key = (DAILY_BUCKET, day);
+    let policy = ttl::get_policy(env);
+    env.storage().persistent().set(&key, &bucket);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, policy.active_threshold, policy.active_extend_to);
+
+    _emit_analytics_event(
+        env,
+        AnalyticsEvent {
+            event_type: AnalyticsType::DailyVolume,
+            count: bucket.operation_count(),
+            total_amount: bucket.volume(),
+            timestamp: bucket.day_start,
+        },
+    );
+}
@@ -0,0 +1,161 @@
+//! # Keeper Expiry Module
+//!
+//! Lets anyone sweep overdue escrows back to their depositors in bounded
+//! batches. An off-chain keeper calls `expire_due(cursor, limit, keeper)`
+//! repeatedly, feeding each call the `next_cursor` of the previous one.
+//!
+//! ## Cursor
+//!
+//! The cursor is a position in the append-only `IndexList::All` index, so a
+//! sweep visits every escrow exactly once per pass regardless of status
+//! changes in between. It wraps to 0 once the end of the index is reached.
+//!
+//! ## Rewards
+//!
+//! Keepers can be paid a fixed reward per expired escrow. Rewards come from a
+//! dedicated pool funded with `fund_keeper_pool`, never from depositor funds;
+//! once the pool runs dry sweeps keep working without a reward.
+//!
+//! To stop depositors from farming the pool with tiny, short-lived escrows,
+//! an escrow only earns a reward once it is `grace_period` seconds past its
+//! deadline, if it held at least `min_amount`, and if the keeper is not its
+//! depositor. Escrows that miss these conditions are still refunded.
+
+use crate::events::legacy_events_enabled;
+use soroban_sdk::{contracttype, symbol_short, Address, Env};
+
+// ============================================================================
+// Data Structures
+// ============================================================================
+
+/// Reward paid to keepers and the conditions an escrow must meet to earn it
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KeeperRewardConfig {
+    /// Reward per eligible expired escrow (0 disables rewards)
+    pub reward_per_escrow: i128,
+    /// Seconds past the deadline before an escrow earns a reward
+    pub grace_period: u64,
+    /// Smallest refunded amount that earns a reward
+    pub min_amount: i128,
+}
+
+/// Outcome of one `expire_due` batch, also published as its summary event
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExpirySweep {
+    pub keeper: Address,
+    /// Index position the batch started at
+    pub cursor: u32,
+    /// Position to pass to the next call (0 once the index wrapped)
+    pub next_cursor: u32,
+    /// Escrows inspected by this batch
+    pub scanned: u32,
+    /// Escrows refunded by this batch
+    pub expired: u32,
+    pub refunded_amount: i128,
+    pub keeper_reward: i128,
+    pub timestamp: u64,
+}
+
+/// Event emitted when the keeper reward pool is topped up
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct KeeperPoolFunded {
+    pub funder: Address,
+    pub amount: i128,
+    pub pool: i128,
+    pub timestamp: u64,
+}
+
+// ============================================================================
+// Storage
+// ============================================================================
+
+/// Current reward configuration (rewards disabled if never set)
+pub fn get_reward(env: &Env) -> KeeperRewardConfig {
+    env.storage()
+        .instance()
+        .get(&symbol_short!("kpr_rwd"))
+        .unwrap_or(KeeperRewardConfig {
+            reward_per_escrow: 0,
+            grace_period: 0,
+            min_amount: 0,
+        })
+}
+
+pub fn set_reward(env: &Env, config: &KeeperRewardConfig) {
+    env.storage()
+        .instance()
+        .set(&symbol_short!("kpr_rwd"), config);
+}
+
+/// Whether refunding `amount` from an escrow of `depositor` that expired at
+/// `deadline` earns `keeper` a reward
+pub fn is_reward_eligible(
+    config: &KeeperRewardConfig,
+    now: u64,
+    deadline: u64,
+    amount: i128,
+    depositor: &Address,
+    keeper: &Address,
+) -> bool {
+    now >= deadline.saturating_add(config.grace_period)
+        && amount >= config.min_amount
+        && depositor != keeper
+}
+
+/// Tokens set aside for keeper rewards
+pub fn get_pool(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&symbol_short!("kpr_pool"))
+        .unwrap_or(0)
+}
+
+fn set_pool(env: &Env, pool: i128) {
+    env.storage()
+        .instance()
+        .set(&symbol_short!("kpr_pool"), &pool);
+}
+
+/// Adds `amount` to the pool and emits the legacy `KeeperPoolFunded` when
+/// enabled
+pub fn fund_pool(env: &Env, funder: &Address, amount: i128) -> i128 {
+    let pool = get_pool(env) + amount;
+    set_pool(env, pool);
+
+    if legacy_events_enabled(env) {
+        env.events().publish(
+            (symbol_short!("keeper"), symbol_short!("fund")),
+            KeeperPoolFunded {
+                funder: funder.clone(),
+                amount,
+                pool,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+    }
+    pool
+}
+
+/// Takes the reward for `eligible` escrows out of the pool, capped by what is
+/// left in it. Returns the amount to pay the keeper.
+pub fn take_reward(env: &Env, eligible: u32) -> i128 {
+    let pool = get_pool(env);
+    let reward = (get_reward(env).reward_per_escrow * eligible as i128).min(pool);
+    if reward > 0 {
+        set_pool(env, pool - reward);
+    }
+    reward
+}
+
+pub fn emit_sweep(env: &Env, sweep: &ExpirySweep) {
+    if !legacy_events_enabled(env) {
+        return;
+    }
+    env.events().publish(
+        (symbol_short!("keeper"), symbol_short!("sweep")),
+        sweep.clone(),
+    );
+}
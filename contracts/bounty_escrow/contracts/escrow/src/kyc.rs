@@ -0,0 +1,221 @@
+//! # KYC Attestation Module
+//!
+//! Gates large payouts on a verified identity. Off-chain verifiers sign
+//! attestations with ed25519 keys registered by the admin; the contract
+//! checks the signature once and caches the result per address.
+//!
+//! ## Flow
+//!
+//! ```text
+//! verifier signs xdr(KycAttestation { contract, subject, level, issued_at, expires_at })
+//!        │
+//!        ▼
+//! submit_kyc_attestation() ── this contract? key registered? issued? not expired?
+//!        │                    ed25519 valid? newer or higher level than cached?
+//!        ▼
+//! (kyc_att, subject) -> CachedAttestation
+//!        │
+//!        ▼
+//! release_funds / batch_release_funds
+//!        └─ amount > payout_threshold ──► cached, unexpired, level >= min_level,
+//!                                         verifier still registered
+//! ```
+
+use crate::events::{self, legacy_events_enabled, EventKind};
+use crate::Error;
+use soroban_sdk::{contracttype, symbol_short, xdr::ToXdr, Address, BytesN, Env, Vec};
+
+// ============================================================================
+// Data Structures
+// ============================================================================
+
+/// Payout gate configuration
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KycConfig {
+    /// Payouts strictly above this amount require an attestation
+    pub payout_threshold: i128,
+    /// Minimum verification level accepted for gated payouts
+    pub min_level: u32,
+}
+
+/// Statement signed by a verifier; the signed message is its XDR encoding
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KycAttestation {
+    /// Escrow contract the attestation is issued for
+    pub contract: Address,
+    pub subject: Address,
+    pub level: u32,
+    /// When the verifier issued the attestation
+    pub issued_at: u64,
+    pub expires_at: u64,
+}
+
+/// Attestation stored after its signature has been verified
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CachedAttestation {
+    pub level: u32,
+    pub issued_at: u64,
+    pub expires_at: u64,
+    pub verifier: BytesN<32>,
+    pub verified_at: u64,
+}
+
+/// Event emitted when an attestation is verified and cached
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct KycAttested {
+    pub subject: Address,
+    pub level: u32,
+    pub expires_at: u64,
+    pub verifier: BytesN<32>,
+    pub timestamp: u64,
+}
+
+// ============================================================================
+// Storage
+// ============================================================================
+
+pub fn get_config(env: &Env) -> Option<KycConfig> {
+    env.storage().instance().get(&symbol_short!("kyc_cfg"))
+}
+
+pub fn set_config(env: &Env, config: Option<KycConfig>) {
+    match config {
+        Some(config) => env
+            .storage()
+            .instance()
+            .set(&symbol_short!("kyc_cfg"), &config),
+        None => env.storage().instance().remove(&symbol_short!("kyc_cfg")),
+    }
+}
+
+pub fn get_verifiers(env: &Env) -> Vec<BytesN<32>> {
+    env.storage()
+        .instance()
+        .get(&symbol_short!("kyc_keys"))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Registers or unregisters a verifier public key
+pub fn set_verifier(env: &Env, key: BytesN<32>, enabled: bool) {
+    let mut keys = get_verifiers(env);
+    let existing = keys.first_index_of(&key);
+    match (existing, enabled) {
+        (None, true) => keys.push_back(key),
+        (Some(index), false) => {
+            keys.remove(index);
+        }
+        _ => return,
+    }
+    env.storage()
+        .instance()
+        .set(&symbol_short!("kyc_keys"), &keys);
+}
+
+pub fn get_attestation(env: &Env, subject: &Address) -> Option<CachedAttestation> {
+    env.storage()
+        .persistent()
+        .get(&(symbol_short!("kyc_att"), subject.clone()))
+}
+
+// ============================================================================
+// Verification
+// ============================================================================
+
+/// Verifies a signed attestation and caches it for its subject
+///
+/// An existing cached attestation is only replaced by one that was issued
+/// later or grants a higher level, so an old attestation cannot be replayed
+/// over a newer one. The ed25519 check traps on an invalid signature, so a
+/// bad signature aborts the call rather than returning an error.
+pub fn submit_attestation(
+    env: &Env,
+    attestation: KycAttestation,
+    verifier: BytesN<32>,
+    signature: BytesN<64>,
+) -> Result<CachedAttestation, Error> {
+    if attestation.contract != env.current_contract_address() {
+        return Err(Error::WrongContract);
+    }
+
+    if !get_verifiers(env).contains(&verifier) {
+        return Err(Error::UnknownVerifier);
+    }
+
+    let now = env.ledger().timestamp();
+    if attestation.expires_at <= now {
+        return Err(Error::AttestationExpired);
+    }
+    if attestation.issued_at > now {
+        return Err(Error::InvalidAttestation);
+    }
+
+    if let Some(existing) = get_attestation(env, &attestation.subject) {
+        if attestation.issued_at <= existing.issued_at && attestation.level <= existing.level {
+            return Err(Error::InvalidAttestation);
+        }
+    }
+
+    env.crypto()
+        .ed25519_verify(&verifier, &attestation.clone().to_xdr(env), &signature);
+
+    let cached = CachedAttestation {
+        level: attestation.level,
+        issued_at: attestation.issued_at,
+        expires_at: attestation.expires_at,
+        verifier: verifier.clone(),
+        verified_at: now,
+    };
+    env.storage().persistent().set(
+        &(symbol_short!("kyc_att"), attestation.subject.clone()),
+        &cached,
+    );
+
+    events::emit_v2(
+        env,
+        EventKind::KycAttested,
+        0,
+        &attestation.subject,
+        Some(attestation.subject.clone()),
+        0,
+        0,
+    );
+    if legacy_events_enabled(env) {
+        env.events().publish(
+            (symbol_short!("kyc"), symbol_short!("attest")),
+            KycAttested {
+                subject: attestation.subject,
+                level: attestation.level,
+                expires_at: attestation.expires_at,
+                verifier,
+                timestamp: now,
+            },
+        );
+    }
+
+    Ok(cached)
+}
+
+/// Checks that `recipient` may receive a payout of `amount`
+pub fn check_payout(env: &Env, recipient: &Address, amount: i128) -> Result<(), Error> {
+    let config = match get_config(env) {
+        Some(config) => config,
+        None => return Ok(()),
+    };
+    if amount <= config.payout_threshold {
+        return Ok(());
+    }
+
+    let cached = get_attestation(env, recipient).ok_or(Error::KycRequired)?;
+    if cached.expires_at <= env.ledger().timestamp() {
+        return Err(Error::AttestationExpired);
+    }
+    if cached.level < config.min_level || !get_verifiers(env).contains(&cached.verifier) {
+        return Err(Error::KycRequired);
+    }
+
+    Ok(())
+}
@@ -88,34 +88,59 @@
 
 #![no_std]
 mod blacklist;
+mod claims;
 mod events;
+mod identity;
 mod indexed;
+mod keeper;
+mod kyc;
+mod notifications;
+mod oracle;
+mod sanctions;
 mod test_blacklist;
 mod test_bounty_escrow;
+mod ttl;
+mod vesting;
 pub mod security {
     pub mod reentrancy_guard;
 }
 
 use security::reentrancy_guard::{ReentrancyGuard, ReentrancyGuardRAII};
 
+use anti_abuse::{AntiAbuseConfig, RateLimitOp};
 use blacklist::{
     add_to_blacklist, add_to_whitelist, is_participant_allowed, remove_from_blacklist,
-    remove_from_whitelist, set_whitelist_mode,
+    remove_from_whitelist, set_whitelist_mode, BlacklistChange, BlacklistEntry, BlockedAddress,
 };
+use claims::{Entitlement, PayoutMode};
 use events::{
     emit_batch_funds_locked, emit_batch_funds_released, emit_contract_paused,
-    emit_contract_unpaused, emit_deadline_extended, emit_emergency_withdrawal, emit_escrow_expired,
-    BatchFundsLocked, BatchFundsReleased, ContractPaused, ContractUnpaused, DeadlineExtended,
-    EmergencyWithdrawal, EscrowExpired,
+    emit_contract_unpaused, emit_deadline_extended, emit_emergency_withdrawal,
+    emit_escrow_compacted, emit_escrow_expired, BatchFundsLocked, BatchFundsReleased,
+    ContractPaused, ContractUnpaused, DeadlineExtended, EmergencyWithdrawal, EscrowCompacted,
+    EscrowExpired, EventKind,
 };
+use identity::{IdentityCredit, IdentityLink};
+use indexed::indexed_storage::{
+    self, migrate_legacy_index, ContributorStats, EarnerEntry, IndexedBounty,
+};
+use indexed::time_series::{get_daily_bucket, record_volume, DailyBucket, VolumeKind};
 use indexed::{
-    _emit_bounty_initialized, on_funds_locked, on_funds_refunded, on_funds_released,
-    BountyEscrowInitialized,
+    _emit_bounty_initialized, get_bounties_by_contributor, get_time_series_data,
+    get_top_earners_page, on_funds_locked,
+    on_funds_refunded, on_funds_released, BountyEscrowInitialized, TimeSeriesPoint,
 };
+use keeper::{ExpirySweep, KeeperRewardConfig};
+use kyc::{CachedAttestation, KycAttestation, KycConfig};
+use notifications::NotificationSweep;
+use oracle::{OracleConfig, OracleSignature, ReleaseAttestation};
+use sanctions::{SanctionsOverride, SanctionsProof, SanctionsRoot};
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, symbol_short, token, vec, Address, Env,
-    Map, String, Vec,
+    contract, contracterror, contractimpl, contracttype, symbol_short, token, vec, Address,
+    BytesN, Env, Map, String, Vec,
 };
+use ttl::{EscrowReceipt, TtlPolicy};
+use vesting::VestingStream;
 
 // ==================== MONITORING MODULE ====================
 mod monitoring {
@@ -313,6 +338,7 @@ mod monitoring {
 
 // ==================== ANTI-ABUSE MODULE ====================
 mod anti_abuse {
+    use crate::Error;
     use soroban_sdk::{contracttype, symbol_short, Address, Env};
 
     #[contracttype]
@@ -323,6 +349,15 @@ mod anti_abuse {
         pub cooldown_period: u64, // Minimum seconds between operations
     }
 
+    /// Operation classes that are rate limited independently.
+    #[contracttype]
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub enum RateLimitOp {
+        Lock,
+        Release,
+        Refund,
+    }
+
     #[contracttype]
     #[derive(Clone, Debug, Eq, PartialEq)]
     pub struct AddressState {
@@ -338,6 +373,9 @@ mod anti_abuse {
         State(Address),
         Whitelist(Address),
         Admin,
+        OpConfig(RateLimitOp),
+        Override(Address),
+        OpState(Address, RateLimitOp),
     }
 
     pub fn get_config(env: &Env) -> AntiAbuseConfig {
@@ -351,18 +389,66 @@ mod anti_abuse {
             })
     }
 
-    #[allow(dead_code)]
     pub fn set_config(env: &Env, config: AntiAbuseConfig) {
         env.storage().instance().set(&AntiAbuseKey::Config, &config);
     }
 
+    pub fn get_op_config(env: &Env, op: RateLimitOp) -> Option<AntiAbuseConfig> {
+        env.storage().instance().get(&AntiAbuseKey::OpConfig(op))
+    }
+
+    pub fn set_op_config(env: &Env, op: RateLimitOp, config: Option<AntiAbuseConfig>) {
+        match config {
+            Some(config) => env
+                .storage()
+                .instance()
+                .set(&AntiAbuseKey::OpConfig(op), &config),
+            None => env.storage().instance().remove(&AntiAbuseKey::OpConfig(op)),
+        }
+    }
+
+    pub fn get_override(env: &Env, address: &Address) -> Option<AntiAbuseConfig> {
+        env.storage()
+            .persistent()
+            .get(&AntiAbuseKey::Override(address.clone()))
+    }
+
+    pub fn set_override(env: &Env, address: Address, config: Option<AntiAbuseConfig>) {
+        let key = AntiAbuseKey::Override(address);
+        match config {
+            Some(config) => env.storage().persistent().set(&key, &config),
+            None => env.storage().persistent().remove(&key),
+        }
+    }
+
+    /// Resolves the limits for `address` performing `op`:
+    /// per-address override, then per-operation config, then the global default.
+    ///
+    /// Refunds can be triggered for a depositor by anyone, so the global default
+    /// does not apply to them; they are only limited once configured explicitly.
+    pub fn effective_config(
+        env: &Env,
+        address: &Address,
+        op: RateLimitOp,
+    ) -> Option<AntiAbuseConfig> {
+        get_override(env, address)
+            .or_else(|| get_op_config(env, op))
+            .or_else(|| match op {
+                RateLimitOp::Refund => None,
+                _ => Some(get_config(env)),
+            })
+    }
+
+    pub fn is_valid_config(config: &AntiAbuseConfig) -> bool {
+        config.window_size > 0 && config.max_operations > 0
+    }
+
     pub fn is_whitelisted(env: &Env, address: Address) -> bool {
         env.storage()
             .instance()
             .has(&AntiAbuseKey::Whitelist(address))
     }
 
-    #[allow(dead_code)]
     pub fn set_whitelist(env: &Env, address: Address, whitelisted: bool) {
         if whitelisted {
             env.storage()
@@ -385,14 +471,17 @@ mod anti_abuse {
         env.storage().instance().set(&AntiAbuseKey::Admin, &admin);
     }
 
-    pub fn check_rate_limit(env: &Env, address: Address) {
+    pub fn check_rate_limit(env: &Env, address: Address, op: RateLimitOp) -> Result<(), Error> {
         if is_whitelisted(env, address.clone()) {
-            return;
+            return Ok(());
         }
 
-        let config = get_config(env);
+        let config = match effective_config(env, &address, op) {
+            Some(config) => config,
+            None => return Ok(()),
+        };
         let now = env.ledger().timestamp();
-        let key = AntiAbuseKey::State(address.clone());
+        let key = AntiAbuseKey::OpState(address.clone(), op);
 
         let mut state: AddressState =
             env.storage()
@@ -415,7 +504,7 @@ mod anti_abuse {
                 (symbol_short!("abuse"), symbol_short!("cooldown")),
                 (address.clone(), now),
             );
-            panic!("Operation in cooldown period");
+            return Err(Error::OperationInCooldown);
         }
 
         // 2. Window check
@@ -434,7 +523,7 @@ mod anti_abuse {
                     (symbol_short!("abuse"), symbol_short!("limit")),
                     (address.clone(), now),
                 );
-                panic!("Rate limit exceeded");
+                return Err(Error::RateLimitExceeded);
             }
             state.operation_count += 1;
         }
@@ -444,6 +533,7 @@ mod anti_abuse {
 
         // Extend TTL for state (approx 1 day)
         env.storage().persistent().extend_ttl(&key, 17280, 17280);
+        Ok(())
     }
 }
 // ==================== END ANTI-ABUSE MODULE ====================
@@ -494,6 +584,43 @@ pub enum Error {
     ReentrantCall = 21,
     /// Returned when participant is blacklisted or not whitelisted
     ParticipantNotAllowed = 21,
+    /// Returned when compacting an escrow that is not Released or Refunded
+    EscrowNotFinalized = 22,
+    /// Returned when a TTL policy has zero or inconsistent values
+    InvalidTtlPolicy = 23,
+    /// Returned when an address has used up its operations for the current window
+    RateLimitExceeded = 24,
+    /// Returned when an address repeats an operation before its cooldown has elapsed
+    OperationInCooldown = 25,
+    /// Returned when a rate limit config has a zero window or zero operation limit
+    InvalidRateLimitConfig = 26,
+    /// Returned when a sanctions proof does not match the published root
+    InvalidSanctionsProof = 27,
+    /// Returned when submitting a sanctions proof before any root is published
+    SanctionsRootNotSet = 28,
+    /// Returned when a payout above the KYC threshold has no valid attestation
+    KycRequired = 29,
+    /// Returned when a KYC attestation has passed its expiry
+    AttestationExpired = 30,
+    /// Returned when an attestation is signed by a key that is not registered
+    UnknownVerifier = 31,
+    /// Returned when an oracle release is submitted before any oracles are registered
+    OracleNotConfigured = 32,
+    /// Returned when an oracle set has a zero or unreachable threshold or duplicate keys
+    InvalidOracleConfig = 33,
+    /// Returned when fewer distinct oracles signed a release than the threshold
+    OracleQuorumNotMet = 34,
+    /// Returned when an oracle release statement's nonce was already consumed
+    NonceAlreadyUsed = 35,
+    /// Returned when an identity link does not match the credit or the claimant
+    IdentityMismatch = 36,
+    /// Returned when claiming an identity credit after its claim window closed
+    ClaimExpired = 37,
+    /// Returned when a signed statement is bound to a different contract or network
+    WrongContract = 38,
+    /// Returned when a KYC attestation is issued in the future or is older
+    /// and no stronger than the cached one
+    InvalidAttestation = 39,
 }
 
 // ============================================================================
@@ -513,6 +640,7 @@ pub enum Error {
 /// * `Locked` - Funds are held in escrow, awaiting release or refund
 /// * `Released` - Funds have been transferred to contributor (final state)
 /// * `Refunded` - Funds have been returned to depositor (final state)
+/// * `Streaming` - Funds are paid out over time by a vesting stream
 ///
 /// # Invariants
 /// - Once in Released or Refunded state, no further transitions allowed
@@ -524,6 +652,7 @@ pub enum EscrowStatus {
     Released,
     Refunded,
     PartiallyRefunded,
+    Streaming,
 }
 
 #[contracttype]
@@ -712,6 +841,25 @@ pub struct AmountLimits {
 const BASIS_POINTS: i128 = 10_000;
 const MAX_FEE_RATE: i128 = 1_000; // Maximum 10% fee
 
+/// Contract balance compared with what it owes
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SolvencyReport {
+    /// Token balance held by the contract
+    pub balance: i128,
+    /// Remaining amount of Locked, PartiallyRefunded and Streaming escrows
+    pub escrowed: i128,
+    /// Released amounts waiting to be claimed by their recipients
+    pub unclaimed_entitlements: i128,
+    /// Amounts credited to external identities and not yet claimed or reclaimed
+    pub identity_credits: i128,
+    /// Tokens set aside for keeper rewards
+    pub keeper_pool: i128,
+    /// `balance` minus all liabilities
+    pub surplus: i128,
+    pub is_solvent: bool,
+}
+
 #[contracttype]
 pub enum DataKey {
     Admin,
@@ -722,13 +870,53 @@ pub enum DataKey {
     AmountLimits,        // Amount limits configuration
     RefundApproval(u64), // bounty_id -> RefundApproval
     ReentrancyGuard,
-    IsPaused, // Contract pause state
+    IsPaused,           // Contract pause state
+    TtlPolicy,          // Storage TTL policy
+    EscrowReceipt(u64), // bounty_id -> EscrowReceipt (compacted escrow)
+    EscrowedTotal,      // Sum of funds still held for open escrows
 }
 
 // ============================================================================
 // Helper Functions
 // ============================================================================
 
+/// Amount an escrow still holds on behalf of its depositor or contributor
+fn outstanding_amount(escrow: &Escrow) -> i128 {
+    match escrow.status {
+        EscrowStatus::Locked | EscrowStatus::PartiallyRefunded | EscrowStatus::Streaming => {
+            escrow.remaining_amount
+        }
+        EscrowStatus::Released | EscrowStatus::Refunded => 0,
+    }
+}
+
+/// Sum of `outstanding_amount` over all escrows
+fn get_escrowed_total(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::EscrowedTotal)
+        .unwrap_or(0)
+}
+
+/// Saves an escrow and applies the change in its outstanding amount to the
+/// running escrowed total used by the solvency report
+fn store_escrow(env: &Env, bounty_id: u64, escrow: &Escrow) {
+    let key = DataKey::Escrow(bounty_id);
+    let previous = env
+        .storage()
+        .persistent()
+        .get::<DataKey, Escrow>(&key)
+        .map(|old| outstanding_amount(&old))
+        .unwrap_or(0);
+    let delta = outstanding_amount(escrow) - previous;
+    if delta != 0 {
+        env.storage()
+            .instance()
+            .set(&DataKey::EscrowedTotal, &(get_escrowed_total(env) + delta));
+    }
+    env.storage().persistent().set(&key, escrow);
+}
+
 /// Validates that metadata doesn't exceed size limits.
 ///
 /// # Limits
@@ -866,7 +1054,7 @@ impl BountyEscrowContract {
     /// # State Changes
     /// - Sets Admin address in instance storage
     /// - Sets Token address in instance storage
-    /// - Emits BountyEscrowInitialized event
+    /// - Emits BountyEscrowInitialized and the v2 `Initialized` event
     ///
     /// # Security Considerations
     /// - Can only be called once (prevents admin takeover)
@@ -875,7 +1063,7 @@ impl BountyEscrowContract {
     /// - No authorization required (first-caller initialization)
     ///
     /// # Events
-    /// Emits: `BountyEscrowInitialized { admin, token, timestamp }`
+    /// Emits: `BountyEscrowInitialized { admin, token, timestamp }` and `EscrowEventV2`
     ///
     /// # Example
     /// ```rust
@@ -887,9 +1075,6 @@ impl BountyEscrowContract {
     /// # Gas Cost
     /// Low - Only two storage writes
     pub fn init(env: Env, admin: Address, token: Address) -> Result<(), Error> {
-        // Apply rate limiting
-        anti_abuse::check_rate_limit(&env, admin.clone());
-
         let start = env.ledger().timestamp();
         let caller = admin.clone();
 
@@ -925,16 +1110,6 @@ impl BountyEscrowContract {
             .instance()
             .set(&DataKey::AmountLimits, &amount_limits);
 
-        // Emit initialization event
-        // emit_bounty_initialized(
-        //     &env,
-        //     BountyEscrowInitialized {
-        //         admin: admin.clone(),
-        //         token,
-        //         timestamp: env.ledger().timestamp(),
-        //     },
-        // );
-
         // Emit initialization event
         _emit_bounty_initialized(
             &env,
@@ -944,6 +1119,7 @@ impl BountyEscrowContract {
                 timestamp: env.ledger().timestamp(),
             },
         );
+        events::emit_v2(&env, EventKind::Initialized, 0, &admin, None, 0, 0);
 
         // Track successful operation
         monitoring::track_operation(&env, symbol_short!("init"), caller, true);
@@ -1034,6 +1210,7 @@ impl BountyEscrowContract {
                 timestamp: env.ledger().timestamp(),
             },
         );
+        events::emit_v2(&env, EventKind::FeeConfigUpdated, 0, &admin, None, 0, 0);
 
         Ok(())
     }
@@ -1138,6 +1315,7 @@ impl BountyEscrowContract {
                 timestamp: env.ledger().timestamp(),
             },
         );
+        events::emit_v2(&env, EventKind::Paused, 0, &admin, None, 0, 0);
 
         Ok(())
     }
@@ -1165,6 +1343,7 @@ impl BountyEscrowContract {
                 timestamp: env.ledger().timestamp(),
             },
         );
+        events::emit_v2(&env, EventKind::Unpaused, 0, &admin, None, 0, 0);
 
         Ok(())
     }
@@ -1208,10 +1387,49 @@ impl BountyEscrowContract {
                 timestamp: env.ledger().timestamp(),
             },
         );
+        events::emit_v2(
+            &env,
+            EventKind::EmergencyWithdrawal,
+            0,
+            &admin,
+            Some(recipient),
+            balance,
+            0,
+        );
 
         Ok(())
     }
 
+    /// Migrate legacy index entries into the paged bounty index (admin only)
+    ///
+    /// Older deployments kept one flag entry per (status, bounty) and
+    /// (depositor, bounty) pair, which could only be listed by scanning every
+    /// possible ID. This walks `limit` bounty IDs starting at `start_id` and
+    /// moves each indexed bounty into the fixed-size index pages. Safe to
+    /// re-run over the same range.
+    ///
+    /// # Arguments
+    /// * `start_id` - First bounty ID to inspect
+    /// * `limit` - Number of IDs to inspect (1..=MAX_BATCH_SIZE)
+    ///
+    /// # Returns
+    /// * `Ok(u32)` - Number of bounties migrated by this call
+    /// * `Err(Error::InvalidBatchSize)` - If `limit` is zero or too large
+    pub fn migrate_bounty_index(env: Env, start_id: u64, limit: u32) -> Result<u32, Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if limit == 0 || limit > MAX_BATCH_SIZE {
+            return Err(Error::InvalidBatchSize);
+        }
+
+        Ok(migrate_legacy_index(&env, start_id, limit))
+    }
+
     /// Add an address to the blacklist (admin only)
     ///
     /// Blacklisted addresses cannot lock funds or receive payouts.
@@ -1238,14 +1456,100 @@ impl BountyEscrowContract {
         admin.require_auth();
 
         if blocked {
-            add_to_blacklist(&env, address, reason);
+            add_to_blacklist(&env, address, reason, admin, None);
         } else {
-            remove_from_blacklist(&env, address);
+            remove_from_blacklist(&env, address, admin);
+        }
+
+        Ok(())
+    }
+
+    /// Temporarily blacklist an address until `until` (admin only)
+    ///
+    /// The suspension lifts automatically once the ledger timestamp reaches
+    /// `until`. Suspending an already blocked address replaces its entry.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `address` - Address to suspend
+    /// * `until` - Timestamp at which the suspension lifts
+    /// * `reason` - Optional reason, stored on-chain
+    ///
+    /// # Errors
+    /// * `InvalidDeadline` - `until` is not in the future
+    ///
+    /// # Authorization
+    /// - Admin only
+    pub fn suspend_address(
+        env: Env,
+        address: Address,
+        until: u64,
+        reason: Option<String>,
+    ) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if until <= env.ledger().timestamp() {
+            return Err(Error::InvalidDeadline);
         }
 
+        add_to_blacklist(&env, address, reason, admin, Some(until));
+
         Ok(())
     }
 
+    /// Get the blacklist entry for an address, including expired suspensions (view function)
+    pub fn get_blacklist_entry(env: Env, address: Address) -> Option<BlacklistEntry> {
+        blacklist::get_blacklist_entry(&env, &address)
+    }
+
+    /// Check whether an address is currently blocked (view function)
+    pub fn is_blacklisted(env: Env, address: Address) -> bool {
+        blacklist::is_blacklisted(&env, &address)
+    }
+
+    /// Get the history of blacklist changes for an address, oldest first (view function)
+    pub fn get_blacklist_history(env: Env, address: Address) -> Vec<BlacklistChange> {
+        blacklist::get_blacklist_history(&env, &address)
+    }
+
+    /// Move entries from the legacy blacklist into the indexed list (admin only)
+    ///
+    /// Older deployments stored blocked addresses in a single map that
+    /// `get_blocked_addresses` cannot page through. Each call moves up to
+    /// `limit` entries; repeat until it returns zero.
+    ///
+    /// # Returns
+    /// * `Ok(u32)` - Number of entries moved by this call
+    /// * `Err(Error::InvalidBatchSize)` - If `limit` is zero or too large
+    pub fn migrate_legacy_blacklist(env: Env, limit: u32) -> Result<u32, Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if limit == 0 || limit > MAX_BATCH_SIZE {
+            return Err(Error::InvalidBatchSize);
+        }
+
+        Ok(blacklist::migrate_legacy_blacklist(&env, admin, limit))
+    }
+
+    /// Get one page of currently blocked addresses (view function)
+    ///
+    /// # Arguments
+    /// * `page` - Zero-based page index
+    /// * `page_size` - Number of entries per page
+    pub fn get_blocked_addresses(env: Env, page: u32, page_size: u32) -> Vec<BlockedAddress> {
+        blacklist::get_blocked_addresses(&env, page, page_size)
+    }
+
     /// Add an address to the whitelist (admin only)
     ///
     /// When whitelist mode is enabled, only whitelisted addresses can participate.
@@ -1266,9 +1570,9 @@ impl BountyEscrowContract {
         admin.require_auth();
 
         if whitelisted {
-            add_to_whitelist(&env, address);
+            add_to_whitelist(&env, address, admin);
         } else {
-            remove_from_whitelist(&env, address);
+            remove_from_whitelist(&env, address, admin);
         }
 
         Ok(())
@@ -1293,127 +1597,1458 @@ impl BountyEscrowContract {
         let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         admin.require_auth();
 
-        set_whitelist_mode(&env, enabled);
+        set_whitelist_mode(&env, enabled, admin);
 
         Ok(())
     }
 
-    /// Lock funds for a specific bounty.
+    /// Publish a new Merkle root of sanctioned addresses (admin only)
+    ///
+    /// Replaces the previous root and bumps the version, which invalidates
+    /// proofs recorded against older versions.
     ///
     /// # Arguments
     /// * `env` - The contract environment
-    /// * `depositor` - Address depositing the funds (must authorize)
-    /// * `bounty_id` - Unique identifier for this bounty
-    /// * `amount` - Token amount to lock (in smallest denomination)
-    /// * `deadline` - Unix timestamp after which refund is allowed
+    /// * `root` - Root of the sorted tree of `sha256(xdr(address))` leaves
+    /// * `leaf_count` - Number of addresses in the list
+    /// * `require_clearance` - If true, participants must submit a
+    ///   non-membership proof for this version before they can take part
     ///
     /// # Returns
-    /// * `Ok(())` - Funds successfully locked
-    /// * `Err(Error::NotInitialized)` - Contract not initialized
-    /// * `Err(Error::BountyExists)` - Bounty ID already in use
-    ///
-    /// # State Changes
-    /// - Transfers `amount` tokens from depositor to contract
-    /// - Creates Escrow record in persistent storage
-    /// - Emits FundsLocked event
+    /// The version assigned to the new root
     ///
     /// # Authorization
-    /// - Depositor must authorize the transaction
-    /// - Depositor must have sufficient token balance
-    /// - Depositor must have approved contract for token transfer
-    ///
-    /// # Security Considerations
-    /// - Bounty ID must be unique (prevents overwrites)
-    /// - Amount must be positive (enforced by token contract)
-    /// - Deadline should be reasonable (recommended: 7-90 days)
-    /// - Token transfer is atomic with state update
-    ///
-    /// # Events
-    /// Emits: `FundsLocked { bounty_id, amount, depositor, deadline }`
-    ///
-    /// # Example
-    /// ```rust
-    /// let depositor = Address::from_string("GDEPOSIT...");
-    /// let amount = 1000_0000000; // 1000 USDC
-    /// let deadline = env.ledger().timestamp() + (30 * 24 * 60 * 60); // 30 days
+    /// - Admin only
+    pub fn publish_sanctions_root(
+        env: Env,
+        root: BytesN<32>,
+        leaf_count: u32,
+        require_clearance: bool,
+    ) -> Result<u32, Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        let published = sanctions::publish_root(&env, root, leaf_count, require_clearance);
+        events::emit_v2(
+            &env,
+            EventKind::SanctionsRootPublished,
+            0,
+            &admin,
+            None,
+            0,
+            0,
+        );
+        Ok(published.version)
+    }
+
+    /// Get the currently published sanctions root (view function)
+    pub fn get_sanctions_root(env: Env) -> Option<SanctionsRoot> {
+        sanctions::get_root(&env)
+    }
+
+    /// Submit a membership or non-membership proof for an address (callable by anyone)
     ///
-    /// escrow_client.lock_funds(&depositor, &42, &amount, &deadline)?;
-    /// // Funds are now locked and can be released or refunded
-    /// ```
+    /// A valid non-membership proof clears the address for the current root
+    /// version; a valid membership proof marks it as sanctioned.
     ///
-    /// # Gas Cost
-    /// Medium - Token transfer + storage write + event emission
+    /// # Returns
+    /// * `Ok(true)` - Address cleared
+    /// * `Ok(false)` - Address proven to be on the list
+    /// * `Err(Error::SanctionsRootNotSet)` - No root has been published
+    /// * `Err(Error::InvalidSanctionsProof)` - Proof does not match the root
+    pub fn submit_sanctions_proof(
+        env: Env,
+        address: Address,
+        proof: SanctionsProof,
+    ) -> Result<bool, Error> {
+        let root = sanctions::get_root(&env).ok_or(Error::SanctionsRootNotSet)?;
+        let outcome = sanctions::verify_proof(&env, &root, &address, &proof)
+            .ok_or(Error::InvalidSanctionsProof)?;
+
+        sanctions::record_outcome(&env, &address, root.version, outcome);
+        Ok(outcome == sanctions::ProofOutcome::Cleared)
+    }
+
+    /// Set or clear a per-address override of the sanctions list (admin only)
     ///
-    /// # Common Pitfalls
-    /// - Forgetting to approve token contract before calling
-    /// - Using a bounty ID that already exists
-    /// - Setting deadline in the past or too far in the future
-    pub fn lock_funds(
+    /// `Allow` lets the address participate regardless of the Merkle list
+    /// (explicit blacklist entries still apply); `Block` always rejects it.
+    pub fn set_sanctions_override(
         env: Env,
-        depositor: Address,
-        bounty_id: u64,
-        amount: i128,
-        deadline: u64,
+        address: Address,
+        value: Option<SanctionsOverride>,
     ) -> Result<(), Error> {
-        // Apply rate limiting
-        anti_abuse::check_rate_limit(&env, depositor.clone());
-
-        // Check blacklist/whitelist
-        if !is_participant_allowed(&env, &depositor) {
-            return Err(Error::ParticipantNotAllowed);
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
         }
 
-        let start = env.ledger().timestamp();
-        let caller = depositor.clone();
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
 
-        // Check if contract is paused
-        if Self::is_paused_internal(&env) {
-            monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
-            return Err(Error::ContractPaused);
-        }
+        sanctions::set_override(&env, address, value);
+        Ok(())
+    }
 
-        // Verify depositor authorization
-        depositor.require_auth();
+    /// Get the sanctions override for an address, if any (view function)
+    pub fn get_sanctions_override(env: Env, address: Address) -> Option<SanctionsOverride> {
+        sanctions::get_override(&env, &address)
+    }
 
-        let _guard = ReentrancyGuardRAII::new(&env).map_err(|_| Error::ReentrantCall)?;
+    /// Check whether an address may currently lock funds or receive payouts (view function)
+    pub fn check_participant(env: Env, address: Address) -> bool {
+        is_participant_allowed(&env, &address)
+    }
 
-        if amount <= 0 {
-            monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
-            return Err(Error::InvalidAmount);
+    /// Configure the KYC gate for payouts, or disable it with `None` (admin only)
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `config` - Payout threshold and minimum accepted verification level
+    ///
+    /// # Authorization
+    /// - Admin only
+    pub fn set_kyc_config(env: Env, config: Option<KycConfig>) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
         }
 
-        // Check amount limits
-        let limits = Self::get_amount_limits(env.clone());
-        if amount < limits.min_lock_amount || amount > limits.max_lock_amount {
-            monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
-            env.storage().instance().remove(&DataKey::ReentrancyGuard);
-            return Err(Error::InvalidAmount);
-        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
 
-        if deadline <= env.ledger().timestamp() {
-            monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
-            return Err(Error::InvalidDeadline);
+        if let Some(config) = &config {
+            if config.payout_threshold < 0 {
+                return Err(Error::InvalidAmount);
+            }
         }
+
+        kyc::set_config(&env, config);
+        Ok(())
+    }
+
+    /// Get the KYC payout gate configuration, if enabled (view function)
+    pub fn get_kyc_config(env: Env) -> Option<KycConfig> {
+        kyc::get_config(&env)
+    }
+
+    /// Register or unregister an ed25519 verifier key (admin only)
+    ///
+    /// Unregistering a key also invalidates attestations it signed.
+    pub fn set_kyc_verifier(env: Env, key: BytesN<32>, enabled: bool) -> Result<(), Error> {
         if !env.storage().instance().has(&DataKey::Admin) {
-            monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
             return Err(Error::NotInitialized);
         }
 
-        // Prevent duplicate bounty IDs
-        if env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
-            return Err(Error::BountyExists);
-        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
 
-        // Get token contract and transfer funds
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
+        kyc::set_verifier(&env, key, enabled);
+        Ok(())
+    }
 
-        // Calculate and collect fee if enabled
-        let fee_config = Self::get_fee_config_internal(&env);
-        let fee_amount = if fee_config.fee_enabled && fee_config.lock_fee_rate > 0 {
-            Self::calculate_fee(amount, fee_config.lock_fee_rate)
-        } else {
+    /// Get the registered verifier keys (view function)
+    pub fn get_kyc_verifiers(env: Env) -> Vec<BytesN<32>> {
+        kyc::get_verifiers(&env)
+    }
+
+    /// Verify a signed KYC attestation and cache it for its subject (callable by anyone)
+    ///
+    /// The signature must be over the XDR encoding of `attestation`. Once
+    /// cached, the attestation is reused for every payout until it expires
+    /// or is replaced by a newer or higher-level one.
+    ///
+    /// # Errors
+    /// * `WrongContract` - `attestation.contract` is not this contract
+    /// * `UnknownVerifier` - `verifier` is not a registered key
+    /// * `AttestationExpired` - `expires_at` is not in the future
+    /// * `InvalidAttestation` - Issued in the future, or not newer and not
+    ///   higher level than the cached attestation
+    ///
+    /// An invalid signature aborts the call.
+    pub fn submit_kyc_attestation(
+        env: Env,
+        attestation: KycAttestation,
+        verifier: BytesN<32>,
+        signature: BytesN<64>,
+    ) -> Result<CachedAttestation, Error> {
+        kyc::submit_attestation(&env, attestation, verifier, signature)
+    }
+
+    /// Get the cached KYC attestation for an address, if any (view function)
+    pub fn get_kyc_attestation(env: Env, address: Address) -> Option<CachedAttestation> {
+        kyc::get_attestation(&env, &address)
+    }
+
+    /// Replace the oracle key set and signature threshold, or remove it with `None` (admin only)
+    ///
+    /// # Errors
+    /// * `InvalidOracleConfig` - Threshold is zero, exceeds the key count, or keys repeat
+    pub fn set_oracle_config(env: Env, config: Option<OracleConfig>) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        oracle::set_config(&env, config)
+    }
+
+    /// Get the registered oracle keys and threshold, if configured (view function)
+    pub fn get_oracle_config(env: Env) -> Option<OracleConfig> {
+        oracle::get_config(&env)
+    }
+
+    /// Replace a single oracle key, keeping the threshold (admin only)
+    ///
+    /// # Errors
+    /// * `OracleNotConfigured` - No oracle set is registered
+    /// * `UnknownVerifier` - `old_key` is not registered
+    /// * `InvalidOracleConfig` - `new_key` is already registered
+    pub fn rotate_oracle_key(
+        env: Env,
+        old_key: BytesN<32>,
+        new_key: BytesN<32>,
+    ) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        oracle::rotate_key(&env, old_key, new_key)?;
+        events::emit_v2(&env, EventKind::OracleKeyRotated, 0, &admin, None, 0, 0);
+        Ok(())
+    }
+
+    /// Check whether an oracle release nonce has been consumed (view function)
+    pub fn is_oracle_nonce_used(env: Env, nonce: u64) -> bool {
+        oracle::is_nonce_used(&env, nonce)
+    }
+
+    /// Release funds on the strength of an M-of-N oracle statement (callable by any relayer)
+    ///
+    /// Each signature must be over the XDR encoding of `attestation`. The
+    /// relayer only needs to authorize the call; admin authority is not required.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `attestation` - Network, contract, bounty, merged PR, contributor,
+    ///   expiry and nonce
+    /// * `signatures` - Oracle signatures over the attestation
+    /// * `relayer` - Address submitting the release (must authorize)
+    ///
+    /// # Errors
+    /// * `OracleNotConfigured` - No oracle set is registered
+    /// * `WrongContract` - The statement is for another network or contract
+    /// * `AttestationExpired` - `expires_at` is not in the future
+    /// * `NonceAlreadyUsed` - The statement was already used
+    /// * `UnknownVerifier` - A signature comes from an unregistered key
+    /// * `OracleQuorumNotMet` - Fewer distinct signers than the threshold
+    /// * Any error `release_funds` returns for the escrow itself
+    ///
+    /// An invalid signature aborts the call.
+    pub fn release_with_oracle_attestation(
+        env: Env,
+        attestation: ReleaseAttestation,
+        signatures: Vec<OracleSignature>,
+        relayer: Address,
+    ) -> Result<(), Error> {
+        if !is_participant_allowed(&env, &attestation.contributor) {
+            return Err(Error::ParticipantNotAllowed);
+        }
+
+        let start = env.ledger().timestamp();
+
+        let _guard = ReentrancyGuardRAII::new(&env).map_err(|_| Error::ReentrantCall)?;
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        if Self::is_paused_internal(&env) {
+            monitoring::track_operation(&env, symbol_short!("release"), relayer.clone(), false);
+            return Err(Error::ContractPaused);
+        }
+
+        anti_abuse::check_rate_limit(&env, relayer.clone(), RateLimitOp::Release)?;
+
+        relayer.require_auth();
+
+        oracle::authorize_release(&env, &attestation, &signatures, &relayer)?;
+
+        Self::execute_release(
+            env.clone(),
+            attestation.bounty_id,
+            attestation.contributor,
+            relayer,
+            start,
+        )
+    }
+
+    /// Release a bounty to a hashed external identity that has no linked wallet yet (admin only)
+    ///
+    /// The full amount is held by the contract as an `IdentityCredit` until
+    /// it is claimed or expires. The release fee is computed now but only
+    /// collected when the credit is claimed.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `bounty_id` - The bounty to release
+    /// * `identity_hash` - sha256 of the identity string, e.g. "github:12345"
+    /// * `claim_window` - Seconds the contributor has to claim the credit
+    ///
+    /// # Errors
+    /// * `InvalidDeadline` - `claim_window` is zero
+    /// * Any error `release_funds` returns for the escrow itself
+    pub fn release_to_identity(
+        env: Env,
+        bounty_id: u64,
+        identity_hash: BytesN<32>,
+        claim_window: u64,
+    ) -> Result<(), Error> {
+        let _guard = ReentrancyGuardRAII::new(&env).map_err(|_| Error::ReentrantCall)?;
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        if Self::is_paused_internal(&env) {
+            return Err(Error::ContractPaused);
+        }
+
+        anti_abuse::check_rate_limit(&env, admin.clone(), RateLimitOp::Release)?;
+
+        admin.require_auth();
+
+        if claim_window == 0 {
+            return Err(Error::InvalidDeadline);
+        }
+
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
+
+        let now = env.ledger().timestamp();
+        if now >= escrow.deadline {
+            return Err(Error::DeadlineNotPassed);
+        }
+
+        let fee_config = Self::get_fee_config_internal(&env);
+        let fee_amount = if fee_config.fee_enabled && fee_config.release_fee_rate > 0 {
+            Self::calculate_fee(escrow.amount, fee_config.release_fee_rate)
+        } else {
+            0
+        };
+        let net_amount = escrow.amount - fee_amount;
+
+        let limits = Self::get_amount_limits(env.clone());
+        if net_amount < limits.min_payout || net_amount > limits.max_payout {
+            return Err(Error::InvalidAmount);
+        }
+
+        escrow.status = EscrowStatus::Released;
+        escrow.remaining_amount = 0;
+        store_escrow(&env, bounty_id, &escrow);
+        ttl::extend_escrow_ttl(&env, bounty_id);
+        indexed_storage::update_bounty_status(
+            &env,
+            bounty_id,
+            indexed_storage::BountyStatus::Released,
+        );
+
+        identity::add_credit(
+            &env,
+            &IdentityCredit {
+                bounty_id,
+                identity_hash,
+                amount: net_amount,
+                fee: fee_amount,
+                depositor: escrow.depositor,
+                credited_at: now,
+                expires_at: now.saturating_add(claim_window),
+            },
+        );
+        events::emit_v2(
+            &env,
+            EventKind::IdentityCredited,
+            bounty_id,
+            &admin,
+            None,
+            net_amount,
+            0,
+        );
+        Ok(())
+    }
+
+    /// Claim an identity credit with an oracle-signed link to the claimant's address
+    ///
+    /// Each signature must be over the XDR encoding of `link`. The withheld
+    /// release fee is collected and the net amount paid to the claimant.
+    ///
+    /// # Errors
+    /// * `BountyNotFound` - No outstanding credit for the bounty
+    /// * `ClaimExpired` - The claim window has closed
+    /// * `IdentityMismatch` - `link` is for another identity or address
+    /// * `WrongContract` - `link` is for another network or contract
+    /// * `AttestationExpired` - `link.expires_at` is not in the future
+    /// * `OracleNotConfigured`, `UnknownVerifier`, `OracleQuorumNotMet` - Signature checks
+    /// * `KycRequired` - The credit is above the KYC threshold
+    ///
+    /// An invalid signature aborts the call.
+    pub fn claim_identity_credit(
+        env: Env,
+        bounty_id: u64,
+        claimant: Address,
+        link: IdentityLink,
+        signatures: Vec<OracleSignature>,
+    ) -> Result<(), Error> {
+        claimant.require_auth();
+
+        if !is_participant_allowed(&env, &claimant) {
+            return Err(Error::ParticipantNotAllowed);
+        }
+
+        let _guard = ReentrancyGuardRAII::new(&env).map_err(|_| Error::ReentrantCall)?;
+        if Self::is_paused_internal(&env) {
+            return Err(Error::ContractPaused);
+        }
+
+        let credit = identity::get_credit(&env, bounty_id).ok_or(Error::BountyNotFound)?;
+        let now = env.ledger().timestamp();
+        if now >= credit.expires_at {
+            return Err(Error::ClaimExpired);
+        }
+        if link.identity_hash != credit.identity_hash || link.address != claimant {
+            return Err(Error::IdentityMismatch);
+        }
+        if link.expires_at <= now {
+            return Err(Error::AttestationExpired);
+        }
+
+        identity::verify_link(&env, &link, &signatures)?;
+        kyc::check_payout(&env, &claimant, credit.amount)?;
+
+        identity::remove_credit(&env, &credit);
+
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        if credit.fee > 0 {
+            let fee_config = Self::get_fee_config_internal(&env);
+            client.transfer(
+                &env.current_contract_address(),
+                &fee_config.fee_recipient,
+                &credit.fee,
+            );
+            events::emit_fee_collected(
+                &env,
+                events::FeeCollected {
+                    operation_type: events::FeeOperationType::Release,
+                    amount: credit.fee,
+                    fee_rate: fee_config.release_fee_rate,
+                    recipient: fee_config.fee_recipient,
+                    timestamp: now,
+                },
+            );
+            record_volume(&env, VolumeKind::Fee, credit.fee, 1);
+        }
+        client.transfer(&env.current_contract_address(), &claimant, &credit.amount);
+
+        identity::emit_claimed(&env, &credit, &claimant);
+        events::emit_v2(
+            &env,
+            EventKind::IdentityClaimed,
+            bounty_id,
+            &claimant,
+            Some(claimant.clone()),
+            credit.amount,
+            credit.fee,
+        );
+        on_funds_released(&env, bounty_id, credit.amount, &claimant, 0, false);
+        record_volume(&env, VolumeKind::Released, credit.amount, 1);
+        Ok(())
+    }
+
+    /// Return an expired identity credit to the bounty's depositor (callable by anyone)
+    ///
+    /// The depositor gets back the full locked amount, including the
+    /// withheld release fee, and the refund is added to the escrow's
+    /// refund history.
+    ///
+    /// # Errors
+    /// * `BountyNotFound` - No outstanding credit for the bounty
+    /// * `DeadlineNotPassed` - The claim window is still open
+    /// * `ContractPaused` - The contract is paused
+    pub fn reclaim_identity_credit(env: Env, bounty_id: u64) -> Result<(), Error> {
+        let _guard = ReentrancyGuardRAII::new(&env).map_err(|_| Error::ReentrantCall)?;
+        if Self::is_paused_internal(&env) {
+            return Err(Error::ContractPaused);
+        }
+
+        let credit = identity::get_credit(&env, bounty_id).ok_or(Error::BountyNotFound)?;
+        if env.ledger().timestamp() < credit.expires_at {
+            return Err(Error::DeadlineNotPassed);
+        }
+
+        identity::remove_credit(&env, &credit);
+
+        let refund_amount = credit.amount + credit.fee;
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        client.transfer(
+            &env.current_contract_address(),
+            &credit.depositor,
+            &refund_amount,
+        );
+
+        if let Some(mut escrow) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
+        {
+            escrow.refund_history.push_back(RefundRecord {
+                amount: refund_amount,
+                recipient: credit.depositor.clone(),
+                mode: RefundMode::Full,
+                timestamp: env.ledger().timestamp(),
+            });
+            escrow.status = EscrowStatus::Refunded;
+            store_escrow(&env, bounty_id, &escrow);
+            ttl::extend_escrow_ttl(&env, bounty_id);
+        }
+
+        identity::emit_reclaimed(&env, &credit);
+        events::emit_v2(
+            &env,
+            EventKind::Refunded,
+            bounty_id,
+            &env.current_contract_address(),
+            Some(credit.depositor.clone()),
+            refund_amount,
+            0,
+        );
+        on_funds_refunded(
+            &env,
+            bounty_id,
+            refund_amount,
+            &credit.depositor,
+            0,
+            RefundMode::Full,
+            &credit.depositor,
+        );
+        record_volume(&env, VolumeKind::Refunded, refund_amount, 1);
+        Ok(())
+    }
+
+    /// Get the outstanding identity credit for a bounty, if any (view function)
+    pub fn get_identity_credit(env: Env, bounty_id: u64) -> Option<IdentityCredit> {
+        identity::get_credit(&env, bounty_id)
+    }
+
+    /// Get all outstanding credits for an identity hash (view function)
+    pub fn get_identity_credits(env: Env, identity_hash: BytesN<32>) -> Vec<IdentityCredit> {
+        identity::get_credits_for(&env, &identity_hash)
+    }
+
+    /// Choose whether releases transfer funds or record claimable entitlements (admin only)
+    pub fn set_payout_mode(env: Env, mode: PayoutMode) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        claims::set_payout_mode(&env, mode);
+        Ok(())
+    }
+
+    /// Get the current payout mode (view function)
+    pub fn get_payout_mode(env: Env) -> PayoutMode {
+        claims::get_payout_mode(&env)
+    }
+
+    /// Withdraw the entitlement recorded for a released bounty
+    ///
+    /// # Errors
+    /// * `BountyNotFound` - No unclaimed entitlement for the bounty
+    /// * `ParticipantNotAllowed` - The recipient is blacklisted or sanctioned
+    /// * `ContractPaused` - Contract is paused
+    pub fn claim(env: Env, bounty_id: u64) -> Result<i128, Error> {
+        let entitlement = claims::get_entitlement(&env, bounty_id).ok_or(Error::BountyNotFound)?;
+        entitlement.recipient.require_auth();
+
+        let _guard = ReentrancyGuardRAII::new(&env).map_err(|_| Error::ReentrantCall)?;
+        if Self::is_paused_internal(&env) {
+            return Err(Error::ContractPaused);
+        }
+        if !is_participant_allowed(&env, &entitlement.recipient) {
+            return Err(Error::ParticipantNotAllowed);
+        }
+
+        claims::settle_entitlement(&env, &entitlement);
+
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        client.transfer(
+            &env.current_contract_address(),
+            &entitlement.recipient,
+            &entitlement.amount,
+        );
+        Ok(entitlement.amount)
+    }
+
+    /// Withdraw every unclaimed entitlement of `recipient` in one transfer
+    ///
+    /// Returns the total amount paid, which is zero if nothing was owed.
+    pub fn claim_all(env: Env, recipient: Address) -> Result<i128, Error> {
+        recipient.require_auth();
+
+        let _guard = ReentrancyGuardRAII::new(&env).map_err(|_| Error::ReentrantCall)?;
+        if Self::is_paused_internal(&env) {
+            return Err(Error::ContractPaused);
+        }
+        if !is_participant_allowed(&env, &recipient) {
+            return Err(Error::ParticipantNotAllowed);
+        }
+
+        let mut total: i128 = 0;
+        for entitlement in claims::get_entitlements(&env, &recipient).iter() {
+            claims::settle_entitlement(&env, &entitlement);
+            total = total
+                .checked_add(entitlement.amount)
+                .ok_or(Error::InvalidAmount)?;
+        }
+
+        if total > 0 {
+            let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+            let client = token::Client::new(&env, &token_addr);
+            client.transfer(&env.current_contract_address(), &recipient, &total);
+        }
+        Ok(total)
+    }
+
+    /// Get the unclaimed entitlement for a bounty, if any (view function)
+    pub fn get_entitlement(env: Env, bounty_id: u64) -> Option<Entitlement> {
+        claims::get_entitlement(&env, bounty_id)
+    }
+
+    /// Get all unclaimed entitlements of an address (view function)
+    pub fn get_entitlements(env: Env, recipient: Address) -> Vec<Entitlement> {
+        claims::get_entitlements(&env, &recipient)
+    }
+
+    /// Get the sum of all unclaimed entitlements (view function)
+    pub fn get_unclaimed_total(env: Env) -> i128 {
+        claims::get_unclaimed_total(&env)
+    }
+
+    /// Recover an entitlement whose recipient is blacklisted or sanctioned (admin only)
+    ///
+    /// Blocked recipients cannot claim, so their entitlement would otherwise
+    /// stay locked in the contract. The admin sends it to `to` instead, for
+    /// example back to the depositor or to a compliance-held account.
+    ///
+    /// # Errors
+    /// * `BountyNotFound` - No unclaimed entitlement for the bounty
+    /// * `Unauthorized` - The recipient is still allowed to claim
+    /// * `ParticipantNotAllowed` - `to` is itself blocked
+    pub fn recover_entitlement(env: Env, bounty_id: u64, to: Address) -> Result<i128, Error> {
+        let _guard = ReentrancyGuardRAII::new(&env).map_err(|_| Error::ReentrantCall)?;
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        let entitlement = claims::get_entitlement(&env, bounty_id).ok_or(Error::BountyNotFound)?;
+        if is_participant_allowed(&env, &entitlement.recipient) {
+            return Err(Error::Unauthorized);
+        }
+        if !is_participant_allowed(&env, &to) {
+            return Err(Error::ParticipantNotAllowed);
+        }
+
+        claims::recover_entitlement(&env, &entitlement, &admin, &to);
+
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        client.transfer(&env.current_contract_address(), &to, &entitlement.amount);
+        Ok(entitlement.amount)
+    }
+
+    /// Recompute the running escrowed total from the bounty index (admin only)
+    ///
+    /// Only needed after upgrading a deployment whose escrows were locked
+    /// before the total was tracked. Page 0 resets the total; call every
+    /// page of the `All` index in order. The contract must be paused so no
+    /// escrow changes between pages.
+    ///
+    /// # Returns
+    /// * `Ok(i128)` - The escrowed total after this page
+    /// * `Err(Error::Unauthorized)` - If the contract is not paused
+    pub fn rebuild_escrowed_total(env: Env, page: u32) -> Result<i128, Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if !Self::is_paused_internal(&env) {
+            return Err(Error::Unauthorized);
+        }
+
+        let mut total = if page == 0 {
+            0
+        } else {
+            get_escrowed_total(&env)
+        };
+        let list = indexed_storage::IndexList::All;
+        for bounty_id in indexed_storage::get_index_page(&env, &list, page).iter() {
+            if let Some(escrow) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
+            {
+                total += outstanding_amount(&escrow);
+            }
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::EscrowedTotal, &total);
+        Ok(total)
+    }
+
+    /// Compare the contract balance with escrowed funds and unpaid releases (view function)
+    ///
+    /// Every figure is a running total kept up to date on lock, release and
+    /// refund, so the report costs the same regardless of the escrow count.
+    pub fn get_solvency_report(env: Env) -> Result<SolvencyReport, Error> {
+        let balance = Self::get_balance(env.clone())?;
+        let escrowed = get_escrowed_total(&env);
+
+        let unclaimed_entitlements = claims::get_unclaimed_total(&env);
+        let identity_credits = identity::get_outstanding_total(&env);
+        let keeper_pool = keeper::get_pool(&env);
+        let surplus = balance - escrowed - unclaimed_entitlements - identity_credits - keeper_pool;
+
+        Ok(SolvencyReport {
+            balance,
+            escrowed,
+            unclaimed_entitlements,
+            identity_credits,
+            keeper_pool,
+            surplus,
+            is_solvent: surplus >= 0,
+        })
+    }
+
+    /// Convert a locked escrow into a linear payout stream to `contributor` (admin only)
+    ///
+    /// The escrow's `remaining_amount` vests linearly from now over
+    /// `duration` seconds, with nothing withdrawable during the first
+    /// `cliff` seconds. The release fee is taken from each withdrawal.
+    ///
+    /// # Errors
+    /// * `InvalidDeadline` - `duration` is zero or `cliff` exceeds it
+    /// * `FundsNotLocked` - Escrow is not Locked or PartiallyRefunded
+    /// * `DeadlineNotPassed` - The escrow deadline has passed, as for `release_funds`
+    /// * `KycRequired` - The streamed amount is above the KYC threshold
+    pub fn start_vested_release(
+        env: Env,
+        bounty_id: u64,
+        contributor: Address,
+        cliff: u64,
+        duration: u64,
+    ) -> Result<VestingStream, Error> {
+        if !is_participant_allowed(&env, &contributor) {
+            return Err(Error::ParticipantNotAllowed);
+        }
+
+        let _guard = ReentrancyGuardRAII::new(&env).map_err(|_| Error::ReentrantCall)?;
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        if Self::is_paused_internal(&env) {
+            return Err(Error::ContractPaused);
+        }
+
+        anti_abuse::check_rate_limit(&env, admin.clone(), RateLimitOp::Release)?;
+
+        admin.require_auth();
+
+        if duration == 0 || cliff > duration {
+            return Err(Error::InvalidDeadline);
+        }
+
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+
+        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
+        {
+            return Err(Error::FundsNotLocked);
+        }
+
+        let now = env.ledger().timestamp();
+        if now >= escrow.deadline {
+            return Err(Error::DeadlineNotPassed);
+        }
+        if escrow.remaining_amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        kyc::check_payout(&env, &contributor, escrow.remaining_amount)?;
+
+        let stream = VestingStream {
+            bounty_id,
+            recipient: contributor,
+            depositor: escrow.depositor.clone(),
+            total: escrow.remaining_amount,
+            withdrawn: 0,
+            paid: 0,
+            start: now,
+            cliff: now.saturating_add(cliff),
+            end: now.saturating_add(duration),
+            cancelled: false,
+        };
+
+        escrow.status = EscrowStatus::Streaming;
+        store_escrow(&env, bounty_id, &escrow);
+        ttl::extend_escrow_ttl(&env, bounty_id);
+
+        vesting::start_stream(&env, &stream);
+        events::emit_v2(
+            &env,
+            EventKind::StreamStarted,
+            bounty_id,
+            &admin,
+            Some(stream.recipient.clone()),
+            stream.total,
+            0,
+        );
+        indexed_storage::update_bounty_status(
+            &env,
+            bounty_id,
+            indexed_storage::BountyStatus::Streaming,
+        );
+        Ok(stream)
+    }
+
+    /// Withdraw the accrued part of a vesting stream to its recipient
+    ///
+    /// Returns the net amount paid after the release fee, which is zero if
+    /// nothing has accrued since the last withdrawal.
+    pub fn withdraw_vested(env: Env, bounty_id: u64) -> Result<i128, Error> {
+        let mut stream = vesting::get_stream(&env, bounty_id).ok_or(Error::BountyNotFound)?;
+        stream.recipient.require_auth();
+
+        let _guard = ReentrancyGuardRAII::new(&env).map_err(|_| Error::ReentrantCall)?;
+        if Self::is_paused_internal(&env) {
+            return Err(Error::ContractPaused);
+        }
+        if !is_participant_allowed(&env, &stream.recipient) {
+            return Err(Error::ParticipantNotAllowed);
+        }
+
+        let amount = stream.withdrawable_at(env.ledger().timestamp());
+        if amount <= 0 {
+            return Ok(0);
+        }
+
+        let fee_config = Self::get_fee_config_internal(&env);
+        let fee_amount = if fee_config.fee_enabled && fee_config.release_fee_rate > 0 {
+            Self::calculate_fee(amount, fee_config.release_fee_rate)
+        } else {
+            0
+        };
+        let net_amount = amount - fee_amount;
+
+        stream.withdrawn += amount;
+        stream.paid += net_amount;
+        vesting::set_stream(&env, &stream);
+
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        client.transfer(
+            &env.current_contract_address(),
+            &stream.recipient,
+            &net_amount,
+        );
+
+        if fee_amount > 0 {
+            client.transfer(
+                &env.current_contract_address(),
+                &fee_config.fee_recipient,
+                &fee_amount,
+            );
+            events::emit_fee_collected(
+                &env,
+                events::FeeCollected {
+                    operation_type: events::FeeOperationType::Release,
+                    amount: fee_amount,
+                    fee_rate: fee_config.release_fee_rate,
+                    recipient: fee_config.fee_recipient.clone(),
+                    timestamp: env.ledger().timestamp(),
+                },
+            );
+            record_volume(&env, VolumeKind::Fee, fee_amount, 1);
+        }
+
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap();
+        escrow.remaining_amount -= amount;
+        Self::settle_stream(&env, &stream, &mut escrow);
+        store_escrow(&env, bounty_id, &escrow);
+
+        record_volume(&env, VolumeKind::Released, net_amount, 1);
+        vesting::emit_withdrawn(&env, &stream, net_amount, fee_amount);
+        events::emit_v2(
+            &env,
+            EventKind::StreamWithdrawn,
+            bounty_id,
+            &stream.recipient,
+            Some(stream.recipient.clone()),
+            net_amount,
+            fee_amount,
+        );
+        Ok(net_amount)
+    }
+
+    /// Cancel a vesting stream and refund its unvested part (depositor or admin)
+    ///
+    /// The stream is frozen at the amount vested so far, which the recipient
+    /// can still withdraw.
+    ///
+    /// # Errors
+    /// * `BountyNotFound` - The bounty has no stream
+    /// * `Unauthorized` - `caller` is neither the depositor nor the admin
+    /// * `FundsNotLocked` - The stream was already cancelled or fully paid out
+    /// * `ContractPaused` - Contract is paused
+    pub fn cancel_vested_release(env: Env, bounty_id: u64, caller: Address) -> Result<i128, Error> {
+        caller.require_auth();
+
+        let _guard = ReentrancyGuardRAII::new(&env).map_err(|_| Error::ReentrantCall)?;
+        if Self::is_paused_internal(&env) {
+            return Err(Error::ContractPaused);
+        }
+        let mut stream = vesting::get_stream(&env, bounty_id).ok_or(Error::BountyNotFound)?;
+
+        let admin: Option<Address> = env.storage().instance().get(&DataKey::Admin);
+        if caller != stream.depositor && Some(caller.clone()) != admin {
+            return Err(Error::Unauthorized);
+        }
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap();
+        if stream.cancelled
+            || stream.outstanding() == 0
+            || escrow.status != EscrowStatus::Streaming
+        {
+            return Err(Error::FundsNotLocked);
+        }
+
+        let now = env.ledger().timestamp();
+        let vested = stream.vested_at(now);
+        let refunded = stream.total - vested;
+
+        stream.total = vested;
+        stream.end = stream.end.min(now);
+        stream.cancelled = true;
+        vesting::set_stream(&env, &stream);
+
+        if refunded > 0 {
+            let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+            let client = token::Client::new(&env, &token_addr);
+            client.transfer(
+                &env.current_contract_address(),
+                &stream.depositor,
+                &refunded,
+            );
+
+            escrow.remaining_amount -= refunded;
+            escrow.refund_history.push_back(RefundRecord {
+                amount: refunded,
+                recipient: stream.depositor.clone(),
+                mode: RefundMode::Partial,
+                timestamp: now,
+            });
+            record_volume(&env, VolumeKind::Refunded, refunded, 1);
+        }
+
+        Self::settle_stream(&env, &stream, &mut escrow);
+        store_escrow(&env, bounty_id, &escrow);
+
+        vesting::emit_cancelled(&env, &stream, &caller, refunded);
+        events::emit_v2(
+            &env,
+            EventKind::StreamCancelled,
+            bounty_id,
+            &caller,
+            Some(stream.depositor.clone()),
+            refunded,
+            0,
+        );
+        Ok(refunded)
+    }
+
+    /// Finalizes the escrow once a stream owes nothing more
+    ///
+    /// A stream that paid anything counts as a single release of its total
+    /// net payout in the contributor index and stats. An escrow that is
+    /// already finalized is left untouched.
+    fn settle_stream(env: &Env, stream: &VestingStream, escrow: &mut Escrow) {
+        if stream.outstanding() > 0 || escrow.status != EscrowStatus::Streaming {
+            return;
+        }
+
+        let (status, index_status) = if stream.total > 0 {
+            indexed_storage::record_release(env, stream.bounty_id, &stream.recipient, stream.paid);
+            (
+                EscrowStatus::Released,
+                indexed_storage::BountyStatus::Released,
+            )
+        } else {
+            (
+                EscrowStatus::Refunded,
+                indexed_storage::BountyStatus::Refunded,
+            )
+        };
+        escrow.status = status;
+        escrow.remaining_amount = 0;
+        indexed_storage::update_bounty_status(env, stream.bounty_id, index_status);
+    }
+
+    /// Get the vesting stream of a bounty, if any (view function)
+    pub fn get_vesting_stream(env: Env, bounty_id: u64) -> Option<VestingStream> {
+        vesting::get_stream(&env, bounty_id)
+    }
+
+    /// Get every stream paying out to an address, including finished ones (view function)
+    pub fn get_vesting_streams(env: Env, recipient: Address) -> Vec<VestingStream> {
+        vesting::get_recipient_streams(&env, &recipient)
+    }
+
+    /// Get the amount a stream's recipient could withdraw now, before fees (view function)
+    pub fn get_withdrawable(env: Env, bounty_id: u64) -> i128 {
+        vesting::get_stream(&env, bounty_id)
+            .map(|stream| stream.withdrawable_at(env.ledger().timestamp()))
+            .unwrap_or(0)
+    }
+
+    /// Set the reward paid to keepers per expired escrow (admin only)
+    ///
+    /// Rewards are drawn from the keeper pool; 0 disables them. An escrow
+    /// only earns a reward once it is `grace_period` seconds overdue and if
+    /// it refunds at least `min_amount`.
+    pub fn set_keeper_reward(
+        env: Env,
+        reward_per_escrow: i128,
+        grace_period: u64,
+        min_amount: i128,
+    ) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if reward_per_escrow < 0 || min_amount < 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        keeper::set_reward(
+            &env,
+            &KeeperRewardConfig {
+                reward_per_escrow,
+                grace_period,
+                min_amount,
+            },
+        );
+        Ok(())
+    }
+
+    /// Get the keeper reward and its eligibility conditions (view function)
+    pub fn get_keeper_reward(env: Env) -> KeeperRewardConfig {
+        keeper::get_reward(&env)
+    }
+
+    /// Top up the keeper reward pool
+    ///
+    /// Anyone may fund the pool. The tokens are kept apart from escrowed funds
+    /// and only ever leave the contract as keeper rewards.
+    ///
+    /// # Returns
+    /// The pool balance after the deposit
+    pub fn fund_keeper_pool(env: Env, funder: Address, amount: i128) -> Result<i128, Error> {
+        if !env.storage().instance().has(&DataKey::Token) {
+            return Err(Error::NotInitialized);
+        }
+        funder.require_auth();
+
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        client.transfer(&funder, &env.current_contract_address(), &amount);
+
+        let pool = keeper::fund_pool(&env, &funder, amount);
+        events::emit_v2(
+            &env,
+            EventKind::KeeperPoolFunded,
+            0,
+            &funder,
+            None,
+            amount,
+            0,
+        );
+        Ok(pool)
+    }
+
+    /// Get the balance of the keeper reward pool (view function)
+    pub fn get_keeper_pool(env: Env) -> i128 {
+        keeper::get_pool(&env)
+    }
+
+    /// Refund overdue escrows in a bounded batch (permissionless)
+    ///
+    /// Walks `limit` positions of the bounty index starting at `cursor` and
+    /// returns every `Locked` or `PartiallyRefunded` escrow past its deadline
+    /// to its depositor, like `expire` would. The keeper is paid the
+    /// configured reward per eligible expired escrow (see
+    /// `set_keeper_reward`), capped by the keeper pool.
+    ///
+    /// # Arguments
+    /// * `cursor` - Index position to start at (`next_cursor` of the previous call)
+    /// * `limit` - Number of positions to inspect (1..=MAX_BATCH_SIZE)
+    /// * `keeper` - Address receiving the reward
+    ///
+    /// # Returns
+    /// * `Ok(ExpirySweep)` - Batch summary, also emitted as an event
+    /// * `Err(Error::InvalidBatchSize)` - If `limit` is zero or too large
+    pub fn expire_due(
+        env: Env,
+        cursor: u32,
+        limit: u32,
+        keeper: Address,
+    ) -> Result<ExpirySweep, Error> {
+        let _guard = ReentrancyGuardRAII::new(&env).map_err(|_| Error::ReentrantCall)?;
+        if Self::is_paused_internal(&env) {
+            return Err(Error::ContractPaused);
+        }
+        if !env.storage().instance().has(&DataKey::Token) {
+            return Err(Error::NotInitialized);
+        }
+        keeper.require_auth();
+
+        if limit == 0 || limit > MAX_BATCH_SIZE {
+            return Err(Error::InvalidBatchSize);
+        }
+
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        let mut balance = client.balance(&env.current_contract_address());
+
+        let list = indexed_storage::IndexList::All;
+        let len = indexed_storage::index_len(&env, &list);
+        let start = cursor.min(len);
+        let end = start.saturating_add(limit).min(len);
+        let now = env.ledger().timestamp();
+        let reward_config = keeper::get_reward(&env);
+
+        let mut expired: u32 = 0;
+        let mut eligible: u32 = 0;
+        let mut refunded_amount: i128 = 0;
+        for bounty_id in indexed_storage::get_index_range(&env, &list, start, end).iter() {
+            let escrow: Escrow = match env.storage().persistent().get(&DataKey::Escrow(bounty_id)) {
+                Some(escrow) => escrow,
+                None => continue,
+            };
+            if escrow.status != EscrowStatus::Locked
+                && escrow.status != EscrowStatus::PartiallyRefunded
+            {
+                continue;
+            }
+            if now < escrow.deadline
+                || escrow.remaining_amount <= 0
+                || escrow.remaining_amount > balance
+            {
+                continue;
+            }
+
+            if keeper::is_reward_eligible(
+                &reward_config,
+                now,
+                escrow.deadline,
+                escrow.remaining_amount,
+                &escrow.depositor,
+                &keeper,
+            ) {
+                eligible += 1;
+            }
+            let amount = Self::refund_expired_escrow(&env, &client, bounty_id, escrow);
+            balance -= amount;
+            refunded_amount += amount;
+            expired += 1;
+        }
+
+        if expired > 0 {
+            record_volume(&env, VolumeKind::Refunded, refunded_amount, expired);
+        }
+
+        let keeper_reward = keeper::take_reward(&env, eligible);
+        if keeper_reward > 0 {
+            client.transfer(&env.current_contract_address(), &keeper, &keeper_reward);
+            events::emit_v2(
+                &env,
+                EventKind::KeeperRewarded,
+                0,
+                &keeper,
+                Some(keeper.clone()),
+                keeper_reward,
+                0,
+            );
+        }
+
+        let sweep = ExpirySweep {
+            keeper,
+            cursor: start,
+            next_cursor: if end >= len { 0 } else { end },
+            scanned: end - start,
+            expired,
+            refunded_amount,
+            keeper_reward,
+            timestamp: now,
+        };
+        keeper::emit_sweep(&env, &sweep);
+        Ok(sweep)
+    }
+
+    /// Emit deadline notifications for a batch of escrows (permissionless)
+    ///
+    /// Walks up to `MAX_BATCH_SIZE` positions of the bounty index starting at
+    /// `cursor`. Open escrows whose deadline falls within `window` seconds get
+    /// a `DeadlineApproaching` notice 7 days and 1 day ahead; overdue ones get
+    /// a `RefundAvailable` notice. Each notice is emitted once per bounty.
+    ///
+    /// # Arguments
+    /// * `cursor` - Index position to start at (`next_cursor` of the previous call)
+    /// * `window` - Only deadlines at most this many seconds away are announced
+    pub fn poke_notifications(env: Env, cursor: u32, window: u64) -> NotificationSweep {
+        let list = indexed_storage::IndexList::All;
+        let len = indexed_storage::index_len(&env, &list);
+        let start = cursor.min(len);
+        let end = start.saturating_add(MAX_BATCH_SIZE).min(len);
+
+        let mut emitted: u32 = 0;
+        for bounty_id in indexed_storage::get_index_range(&env, &list, start, end).iter() {
+            let escrow: Escrow = match env.storage().persistent().get(&DataKey::Escrow(bounty_id)) {
+                Some(escrow) => escrow,
+                None => continue,
+            };
+            if escrow.status != EscrowStatus::Locked
+                && escrow.status != EscrowStatus::PartiallyRefunded
+            {
+                continue;
+            }
+            if notifications::notify_if_due(
+                &env,
+                bounty_id,
+                &escrow.depositor,
+                escrow.deadline,
+                window,
+            ) {
+                emitted += 1;
+            }
+        }
+
+        NotificationSweep {
+            next_cursor: if end >= len { 0 } else { end },
+            scanned: end - start,
+            emitted,
+        }
+    }
+
+    /// Get the most urgent deadline notice sent for a bounty (view function)
+    ///
+    /// 0 = none, 1 = 7 days left, 2 = 1 day left, 3 = refund available.
+    pub fn get_notice_level(env: Env, bounty_id: u64) -> u32 {
+        notifications::get_notice_level(&env, bounty_id)
+    }
+
+    /// Get the sequence number of the last canonical v2 event (view function)
+    ///
+    /// Indexers can compare it with the last sequence they processed to
+    /// detect missed events.
+    pub fn get_event_sequence(env: Env) -> u64 {
+        events::get_event_sequence(&env)
+    }
+
+    /// Publish the v1 events next to the v2 stream (admin only)
+    ///
+    /// Off by default, so every transition emits only its `EscrowEventV2`.
+    /// Enable it while existing consumers migrate to the v2 stream.
+    pub fn set_legacy_events(env: Env, enabled: bool) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        events::set_legacy_events(&env, enabled);
+        Ok(())
+    }
+
+    /// Whether the v1 events are published (view function)
+    pub fn get_legacy_events(env: Env) -> bool {
+        events::legacy_events_enabled(&env)
+    }
+
+    /// Lock funds for a specific bounty.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `depositor` - Address depositing the funds (must authorize)
+    /// * `bounty_id` - Unique identifier for this bounty
+    /// * `amount` - Token amount to lock (in smallest denomination)
+    /// * `deadline` - Unix timestamp after which refund is allowed
+    ///
+    /// # Returns
+    /// * `Ok(())` - Funds successfully locked
+    /// * `Err(Error::NotInitialized)` - Contract not initialized
+    /// * `Err(Error::BountyExists)` - Bounty ID already in use
+    ///
+    /// # State Changes
+    /// - Transfers `amount` tokens from depositor to contract
+    /// - Creates Escrow record in persistent storage
+    /// - Emits EnhancedFundsLocked and the v2 `Locked` event
+    ///
+    /// # Authorization
+    /// - Depositor must authorize the transaction
+    /// - Depositor must have sufficient token balance
+    /// - Depositor must have approved contract for token transfer
+    ///
+    /// # Security Considerations
+    /// - Bounty ID must be unique (prevents overwrites)
+    /// - Amount must be positive (enforced by token contract)
+    /// - Deadline should be reasonable (recommended: 7-90 days)
+    /// - Token transfer is atomic with state update
+    ///
+    /// # Events
+    /// Emits: `EnhancedFundsLocked` and `EscrowEventV2 { kind: Locked, amount, fee, .. }`
+    ///
+    /// # Example
+    /// ```rust
+    /// let depositor = Address::from_string("GDEPOSIT...");
+    /// let amount = 1000_0000000; // 1000 USDC
+    /// let deadline = env.ledger().timestamp() + (30 * 24 * 60 * 60); // 30 days
+    ///
+    /// escrow_client.lock_funds(&depositor, &42, &amount, &deadline)?;
+    /// // Funds are now locked and can be released or refunded
+    /// ```
+    ///
+    /// # Gas Cost
+    /// Medium - Token transfer + storage write + event emission
+    ///
+    /// # Common Pitfalls
+    /// - Forgetting to approve token contract before calling
+    /// - Using a bounty ID that already exists
+    /// - Setting deadline in the past or too far in the future
+    pub fn lock_funds(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        deadline: u64,
+    ) -> Result<(), Error> {
+        // Apply rate limiting
+        anti_abuse::check_rate_limit(&env, depositor.clone(), RateLimitOp::Lock)?;
+
+        // Check blacklist/whitelist
+        if !is_participant_allowed(&env, &depositor) {
+            return Err(Error::ParticipantNotAllowed);
+        }
+
+        let start = env.ledger().timestamp();
+        let caller = depositor.clone();
+
+        // Check if contract is paused
+        if Self::is_paused_internal(&env) {
+            monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
+            return Err(Error::ContractPaused);
+        }
+
+        // Verify depositor authorization
+        depositor.require_auth();
+
+        let _guard = ReentrancyGuardRAII::new(&env).map_err(|_| Error::ReentrantCall)?;
+
+        if amount <= 0 {
+            monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
+            return Err(Error::InvalidAmount);
+        }
+
+        // Check amount limits
+        let limits = Self::get_amount_limits(env.clone());
+        if amount < limits.min_lock_amount || amount > limits.max_lock_amount {
+            monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
+            env.storage().instance().remove(&DataKey::ReentrancyGuard);
+            return Err(Error::InvalidAmount);
+        }
+
+        if deadline <= env.ledger().timestamp() {
+            monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
+            return Err(Error::InvalidDeadline);
+        }
+        if !env.storage().instance().has(&DataKey::Admin) {
+            monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
+            return Err(Error::NotInitialized);
+        }
+
+        // Prevent duplicate bounty IDs, including compacted ones
+        if env.storage().persistent().has(&DataKey::Escrow(bounty_id))
+            || env
+                .storage()
+                .persistent()
+                .has(&DataKey::EscrowReceipt(bounty_id))
+        {
+            monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
+            return Err(Error::BountyExists);
+        }
+
+        // Get token contract and transfer funds
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+
+        // Calculate and collect fee if enabled
+        let fee_config = Self::get_fee_config_internal(&env);
+        let fee_amount = if fee_config.fee_enabled && fee_config.lock_fee_rate > 0 {
+            Self::calculate_fee(amount, fee_config.lock_fee_rate)
+        } else {
             0
         };
         let net_amount = amount - fee_amount;
@@ -1434,6 +3069,7 @@ impl BountyEscrowContract {
                     timestamp: env.ledger().timestamp(),
                 },
             );
+            record_volume(&env, VolumeKind::Fee, fee_amount, 1);
         }
 
         // Create escrow record
@@ -1447,21 +3083,21 @@ impl BountyEscrowContract {
         };
 
         // Store in persistent storage with extended TTL
-        env.storage()
-            .persistent()
-            .set(&DataKey::Escrow(bounty_id), &escrow);
-
-        // Emit event for off-chain indexing
-        // emit_funds_locked(
-        //     &env,
-        //     FundsLocked {
-        //         bounty_id,
-        //         amount: net_amount, // Emit net amount (after fee)
-        //         depositor: depositor.clone(),
-        //         deadline,
-        //     },
-        // );
+        store_escrow(&env, bounty_id, &escrow);
+        ttl::extend_escrow_ttl(&env, bounty_id);
+        ttl::extend_instance_ttl(&env);
+
         on_funds_locked(&env, bounty_id, amount, &depositor, deadline);
+        events::emit_v2(
+            &env,
+            EventKind::Locked,
+            bounty_id,
+            &depositor,
+            None,
+            net_amount,
+            fee_amount,
+        );
+        record_volume(&env, VolumeKind::Locked, net_amount, 1);
 
         // Track successful operation
         monitoring::track_operation(&env, symbol_short!("lock"), caller, true);
@@ -1541,16 +3177,8 @@ impl BountyEscrowContract {
             .set(&DataKey::EscrowMetadata(bounty_id), &metadata);
 
         // Extend TTL for both escrow and metadata
-        env.storage().persistent().extend_ttl(
-            &DataKey::Escrow(bounty_id),
-            1000000,  // Minimum
-            10000000, // Maximum
-        );
-        env.storage().persistent().extend_ttl(
-            &DataKey::EscrowMetadata(bounty_id),
-            1000000,  // Minimum
-            10000000, // Maximum
-        );
+        ttl::extend_escrow_ttl(&env, bounty_id);
+        ttl::extend_active_key_ttl(&env, &DataKey::EscrowMetadata(bounty_id));
 
         Ok(())
     }
@@ -1572,7 +3200,7 @@ impl BountyEscrowContract {
     /// # State Changes
     /// - Transfers tokens from contract to contributor
     /// - Updates escrow status to Released
-    /// - Emits FundsReleased event
+    /// - Emits EnhancedFundsReleased and the v2 `Released` event
     ///
     /// # Authorization
     /// - **CRITICAL**: Only admin can call this function
@@ -1586,7 +3214,7 @@ impl BountyEscrowContract {
     /// - Consider implementing multi-sig for admin
     ///
     /// # Events
-    /// Emits: `FundsReleased { bounty_id, amount, recipient, timestamp }`
+    /// Emits: `EnhancedFundsReleased` and `EscrowEventV2 { kind: Released, recipient, .. }`
     ///
     /// # Example
     /// ```rust
@@ -1631,13 +3259,27 @@ impl BountyEscrowContract {
         }
 
         // Apply rate limiting
-        anti_abuse::check_rate_limit(&env, admin.clone());
+        anti_abuse::check_rate_limit(&env, admin.clone(), RateLimitOp::Release)?;
 
         admin.require_auth();
 
+        Self::execute_release(env.clone(), bounty_id, contributor, admin, start)
+    }
+
+    /// Pays out a locked escrow once the caller has been authorized
+    ///
+    /// `actor` is the authorizing party recorded in monitoring (the admin for
+    /// `release_funds`, the relayer for oracle-attested releases).
+    fn execute_release(
+        env: Env,
+        bounty_id: u64,
+        contributor: Address,
+        actor: Address,
+        start: u64,
+    ) -> Result<(), Error> {
         // Verify bounty exists
         if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            monitoring::track_operation(&env, symbol_short!("release"), admin.clone(), false);
+            monitoring::track_operation(&env, symbol_short!("release"), actor.clone(), false);
             return Err(Error::BountyNotFound);
         }
 
@@ -1649,13 +3291,13 @@ impl BountyEscrowContract {
             .unwrap();
 
         if escrow.status != EscrowStatus::Locked {
-            monitoring::track_operation(&env, symbol_short!("release"), admin.clone(), false);
+            monitoring::track_operation(&env, symbol_short!("release"), actor.clone(), false);
             return Err(Error::FundsNotLocked);
         }
 
         let now = env.ledger().timestamp();
         if now >= escrow.deadline {
-            monitoring::track_operation(&env, symbol_short!("release"), admin.clone(), false);
+            monitoring::track_operation(&env, symbol_short!("release"), actor.clone(), false);
             env.storage().instance().remove(&DataKey::ReentrancyGuard);
             return Err(Error::DeadlineNotPassed);
         }
@@ -1664,9 +3306,7 @@ impl BountyEscrowContract {
         let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
         let client = token::Client::new(&env, &token_addr);
         escrow.status = EscrowStatus::Released;
-        env.storage()
-            .persistent()
-            .set(&DataKey::Escrow(bounty_id), &escrow);
+        store_escrow(&env, bounty_id, &escrow);
 
         // Calculate and collect fee if enabled
         let fee_config = Self::get_fee_config_internal(&env);
@@ -1680,13 +3320,23 @@ impl BountyEscrowContract {
         // Check payout amount limits
         let limits = Self::get_amount_limits(env.clone());
         if net_amount < limits.min_payout || net_amount > limits.max_payout {
-            monitoring::track_operation(&env, symbol_short!("release"), admin.clone(), false);
+            monitoring::track_operation(&env, symbol_short!("release"), actor.clone(), false);
             env.storage().instance().remove(&DataKey::ReentrancyGuard);
             return Err(Error::InvalidAmount);
         }
 
-        // Transfer net amount to contributor
-        client.transfer(&env.current_contract_address(), &contributor, &net_amount);
+        // Large payouts require a verified identity
+        if let Err(err) = kyc::check_payout(&env, &contributor, escrow.amount) {
+            monitoring::track_operation(&env, symbol_short!("release"), actor.clone(), false);
+            return Err(err);
+        }
+
+        // Transfer net amount to contributor, or leave it to be claimed
+        if claims::get_payout_mode(&env) == PayoutMode::Claim {
+            claims::record_entitlement(&env, bounty_id, &contributor, net_amount);
+        } else {
+            client.transfer(&env.current_contract_address(), &contributor, &net_amount);
+        }
 
         // Transfer fee to fee recipient if applicable
         if fee_amount > 0 {
@@ -1705,25 +3355,15 @@ impl BountyEscrowContract {
                     timestamp: env.ledger().timestamp(),
                 },
             );
+            record_volume(&env, VolumeKind::Fee, fee_amount, 1);
         }
 
         // Update escrow state - mark as released and set remaining_amount to 0
         escrow.status = EscrowStatus::Released;
         escrow.remaining_amount = 0;
-        env.storage()
-            .persistent()
-            .set(&DataKey::Escrow(bounty_id), &escrow);
-
-        // Emit release event
-        // emit_funds_released(
-        //     &env,
-        //     FundsReleased {
-        //         bounty_id,
-        //         amount: net_amount, // Emit net amount (after fee)
-        //         recipient: contributor.clone(),
-        //         timestamp: env.ledger().timestamp(),
-        //     },
-        // );
+        store_escrow(&env, bounty_id, &escrow);
+        ttl::extend_escrow_ttl(&env, bounty_id);
+        ttl::extend_instance_ttl(&env);
 
         // Emit release event
         on_funds_released(
@@ -1734,9 +3374,19 @@ impl BountyEscrowContract {
             escrow.remaining_amount,
             false,
         );
+        events::emit_v2(
+            &env,
+            EventKind::Released,
+            bounty_id,
+            &actor,
+            Some(contributor.clone()),
+            net_amount,
+            fee_amount,
+        );
+        record_volume(&env, VolumeKind::Released, net_amount, 1);
 
         // Track successful operation
-        monitoring::track_operation(&env, symbol_short!("release"), admin, true);
+        monitoring::track_operation(&env, symbol_short!("release"), actor, true);
 
         // Track performance
         let duration = env.ledger().timestamp().saturating_sub(start);
@@ -1791,6 +3441,16 @@ impl BountyEscrowContract {
         env.storage()
             .persistent()
             .set(&DataKey::RefundApproval(bounty_id), &approval);
+        ttl::extend_active_key_ttl(&env, &DataKey::RefundApproval(bounty_id));
+        events::emit_v2(
+            &env,
+            EventKind::RefundApproved,
+            bounty_id,
+            &admin,
+            Some(recipient),
+            amount,
+            0,
+        );
 
         Ok(())
     }
@@ -1809,7 +3469,7 @@ impl BountyEscrowContract {
             return Err(Error::BountyNotFound);
         }
 
-        let mut escrow: Escrow = env
+        let escrow: Escrow = env
             .storage()
             .persistent()
             .get(&DataKey::Escrow(bounty_id))
@@ -1838,6 +3498,26 @@ impl BountyEscrowContract {
             return Err(Error::InsufficientFunds);
         }
 
+        Self::refund_expired_escrow(&env, &client, bounty_id, escrow);
+        record_volume(&env, VolumeKind::Refunded, refund_amount, 1);
+
+        let duration = env.ledger().timestamp().saturating_sub(start);
+        monitoring::emit_performance(&env, symbol_short!("expire"), duration);
+
+        Ok(())
+    }
+
+    /// Returns the remaining amount of an overdue escrow to its depositor
+    ///
+    /// Callers must have checked that the escrow is refundable and past its
+    /// deadline. Returns the amount refunded.
+    fn refund_expired_escrow(
+        env: &Env,
+        client: &token::Client,
+        bounty_id: u64,
+        mut escrow: Escrow,
+    ) -> i128 {
+        let refund_amount = escrow.remaining_amount;
         client.transfer(
             &env.current_contract_address(),
             &escrow.depositor,
@@ -1854,12 +3534,16 @@ impl BountyEscrowContract {
         escrow.remaining_amount = 0;
         escrow.status = EscrowStatus::Refunded;
 
-        env.storage()
-            .persistent()
-            .set(&DataKey::Escrow(bounty_id), &escrow);
+        store_escrow(env, bounty_id, &escrow);
+        ttl::extend_escrow_ttl(env, bounty_id);
+        indexed_storage::update_bounty_status(
+            env,
+            bounty_id,
+            indexed_storage::BountyStatus::Refunded,
+        );
 
         emit_escrow_expired(
-            &env,
+            env,
             EscrowExpired {
                 bounty_id,
                 amount: refund_amount,
@@ -1868,11 +3552,16 @@ impl BountyEscrowContract {
                 timestamp: env.ledger().timestamp(),
             },
         );
-
-        let duration = env.ledger().timestamp().saturating_sub(start);
-        monitoring::emit_performance(&env, symbol_short!("expire"), duration);
-
-        Ok(())
+        events::emit_v2(
+            env,
+            EventKind::Expired,
+            bounty_id,
+            &env.current_contract_address(),
+            Some(escrow.depositor),
+            refund_amount,
+            0,
+        );
+        refund_amount
     }
 
     /// Refund funds with support for Full, Partial, and Custom refunds.
@@ -1916,6 +3605,9 @@ impl BountyEscrowContract {
             return Err(Error::FundsNotLocked);
         }
 
+        // Apply rate limiting
+        anti_abuse::check_rate_limit(&env, caller.clone(), RateLimitOp::Refund)?;
+
         // Verify deadline has passed
         let now = env.ledger().timestamp();
         let is_before_deadline = now < escrow.deadline;
@@ -2015,22 +3707,9 @@ impl BountyEscrowContract {
             escrow.status = EscrowStatus::PartiallyRefunded;
         }
 
-        env.storage()
-            .persistent()
-            .set(&DataKey::Escrow(bounty_id), &escrow);
-
-        // Emit refund event
-        // emit_funds_refunded(
-        //     &env,
-        //     FundsRefunded {
-        //         bounty_id,
-        //         amount: refund_amount,
-        //         refund_to: refund_recipient,
-        //         timestamp: env.ledger().timestamp(),
-        //         refund_mode: mode.clone(),
-        //         remaining_amount: escrow.remaining_amount,
-        //     },
-        // );
+        store_escrow(&env, bounty_id, &escrow);
+        ttl::extend_escrow_ttl(&env, bounty_id);
+        ttl::extend_instance_ttl(&env);
 
         // Emit refund event
         on_funds_refunded(
@@ -2042,6 +3721,21 @@ impl BountyEscrowContract {
             mode,
             &caller,
         );
+        let kind = if escrow.status == EscrowStatus::Refunded {
+            EventKind::Refunded
+        } else {
+            EventKind::PartiallyRefunded
+        };
+        events::emit_v2(
+            &env,
+            kind,
+            bounty_id,
+            &caller,
+            Some(refund_recipient.clone()),
+            refund_amount,
+            0,
+        );
+        record_volume(&env, VolumeKind::Refunded, refund_amount, 1);
 
         // Track successful operation
         monitoring::track_operation(&env, symbol_short!("refund"), caller, true);
@@ -2155,16 +3849,13 @@ impl BountyEscrowContract {
         escrow.deadline = new_deadline;
 
         // Store updated escrow
-        env.storage()
-            .persistent()
-            .set(&DataKey::Escrow(bounty_id), &escrow);
+        store_escrow(&env, bounty_id, &escrow);
+
+        // Notices sent for the old deadline no longer apply
+        notifications::clear_notice_level(&env, bounty_id);
 
         // Extend TTL
-        env.storage().persistent().extend_ttl(
-            &DataKey::Escrow(bounty_id),
-            1000000,  // Minimum
-            10000000, // Maximum
-        );
+        ttl::extend_escrow_ttl(&env, bounty_id);
 
         // Emit deadline extended event
         emit_deadline_extended(
@@ -2177,6 +3868,15 @@ impl BountyEscrowContract {
                 timestamp: env.ledger().timestamp(),
             },
         );
+        events::emit_v2(
+            &env,
+            EventKind::DeadlineExtended,
+            bounty_id,
+            &caller,
+            None,
+            0,
+            0,
+        );
 
         // Track successful operation
         monitoring::track_operation(&env, symbol_short!("ext_dead"), caller, true);
@@ -2188,6 +3888,256 @@ impl BountyEscrowContract {
         Ok(())
     }
 
+    // ========================================================================
+    // Anti-Abuse Configuration
+    // ========================================================================
+
+    /// Get the default rate limit applied when no narrower config exists (view function)
+    pub fn get_rate_limit_config(env: Env) -> AntiAbuseConfig {
+        anti_abuse::get_config(&env)
+    }
+
+    /// Get the rate limit configured for one operation, if any (view function)
+    pub fn get_op_rate_limit(env: Env, op: RateLimitOp) -> Option<AntiAbuseConfig> {
+        anti_abuse::get_op_config(&env, op)
+    }
+
+    /// Get the per-address rate limit override, if any (view function)
+    pub fn get_rate_limit_override(env: Env, address: Address) -> Option<AntiAbuseConfig> {
+        anti_abuse::get_override(&env, &address)
+    }
+
+    /// Get the rate limit that applies to `address` performing `op` (view function)
+    ///
+    /// Resolution order: per-address override, per-operation config, default.
+    /// Returns `None` for refunds when no refund limit has been configured.
+    pub fn get_effective_rate_limit(
+        env: Env,
+        address: Address,
+        op: RateLimitOp,
+    ) -> Option<AntiAbuseConfig> {
+        anti_abuse::effective_config(&env, &address, op)
+    }
+
+    /// Check whether an address is exempt from rate limiting (view function)
+    pub fn is_rate_limit_exempt(env: Env, address: Address) -> bool {
+        anti_abuse::is_whitelisted(&env, address)
+    }
+
+    /// Update the default rate limit (admin only)
+    ///
+    /// # Returns
+    /// * `Ok(())` - Config updated
+    /// * `Err(Error::InvalidRateLimitConfig)` - Zero window or zero operation limit
+    pub fn set_rate_limit_config(env: Env, config: AntiAbuseConfig) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if !anti_abuse::is_valid_config(&config) {
+            return Err(Error::InvalidRateLimitConfig);
+        }
+
+        anti_abuse::set_config(&env, config);
+        Ok(())
+    }
+
+    /// Set or clear the rate limit for one operation (admin only)
+    ///
+    /// # Arguments
+    /// * `op` - Operation the limit applies to (`Lock`, `Release` or `Refund`)
+    /// * `config` - New limit, or `None` to fall back to the default
+    pub fn set_op_rate_limit(
+        env: Env,
+        op: RateLimitOp,
+        config: Option<AntiAbuseConfig>,
+    ) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if let Some(config) = &config {
+            if !anti_abuse::is_valid_config(config) {
+                return Err(Error::InvalidRateLimitConfig);
+            }
+        }
+
+        anti_abuse::set_op_config(&env, op, config);
+        Ok(())
+    }
+
+    /// Set or clear a rate limit override for one address (admin only)
+    ///
+    /// The override applies to every operation performed by `address` and
+    /// takes precedence over per-operation and default limits.
+    pub fn set_rate_limit_override(
+        env: Env,
+        address: Address,
+        config: Option<AntiAbuseConfig>,
+    ) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if let Some(config) = &config {
+            if !anti_abuse::is_valid_config(config) {
+                return Err(Error::InvalidRateLimitConfig);
+            }
+        }
+
+        anti_abuse::set_override(&env, address, config);
+        Ok(())
+    }
+
+    /// Exempt an address from rate limiting, or remove the exemption (admin only)
+    pub fn set_rate_limit_exempt(env: Env, address: Address, exempt: bool) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        anti_abuse::set_whitelist(&env, address, exempt);
+        Ok(())
+    }
+
+    // ========================================================================
+    // Storage TTL Management
+    // ========================================================================
+
+    /// Get the TTL policy applied to escrow storage entries (view function)
+    pub fn get_ttl_policy(env: Env) -> TtlPolicy {
+        ttl::get_policy(&env)
+    }
+
+    /// Update the TTL policy (admin only)
+    ///
+    /// # Arguments
+    /// * `policy` - New policy; `active_threshold` must not exceed `active_extend_to`
+    ///
+    /// # Returns
+    /// * `Ok(())` - Policy updated
+    /// * `Err(Error::InvalidTtlPolicy)` - Zero or inconsistent values
+    pub fn set_ttl_policy(env: Env, policy: TtlPolicy) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if policy.active_threshold == 0
+            || policy.active_extend_to == 0
+            || policy.receipt_extend_to == 0
+            || policy.active_threshold > policy.active_extend_to
+        {
+            return Err(Error::InvalidTtlPolicy);
+        }
+
+        ttl::set_policy(&env, &policy);
+        Ok(())
+    }
+
+    /// Extend the storage TTL of an escrow (callable by anyone)
+    ///
+    /// Keeps long-running bounties from being archived by the ledger. For
+    /// compacted escrows the receipt is extended instead.
+    ///
+    /// # Returns
+    /// * `Ok(())` - TTL extended
+    /// * `Err(Error::BountyNotFound)` - Neither an escrow nor a receipt exists
+    pub fn bump_escrow(env: Env, bounty_id: u64) -> Result<(), Error> {
+        if env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            ttl::extend_all_escrow_ttl(&env, bounty_id);
+            return Ok(());
+        }
+
+        if env
+            .storage()
+            .persistent()
+            .has(&DataKey::EscrowReceipt(bounty_id))
+        {
+            ttl::extend_receipt_ttl(&env, bounty_id);
+            return Ok(());
+        }
+
+        Err(Error::BountyNotFound)
+    }
+
+    /// Replace a finalized escrow with a compact receipt (admin only)
+    ///
+    /// Drops the full escrow record, its refund history, metadata and any
+    /// pending refund approval. What remains is an `EscrowReceipt` holding the
+    /// amounts, recipients and a SHA-256 hash of the dropped data, so the
+    /// history can still be verified against off-chain copies.
+    ///
+    /// # Returns
+    /// * `Ok(EscrowReceipt)` - The stored receipt
+    /// * `Err(Error::BountyNotFound)` - Escrow doesn't exist (or is already compacted)
+    /// * `Err(Error::EscrowNotFinalized)` - Escrow is not Released or Refunded,
+    ///   or its payout is still held as an unclaimed identity credit
+    ///
+    /// # Events
+    /// Emits: `EscrowCompacted { bounty_id, status, history_hash, timestamp }`
+    pub fn finalize_and_compact(env: Env, bounty_id: u64) -> Result<EscrowReceipt, Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+
+        if escrow.status != EscrowStatus::Released && escrow.status != EscrowStatus::Refunded {
+            return Err(Error::EscrowNotFinalized);
+        }
+        // An outstanding credit can still be reclaimed by the depositor
+        if identity::get_credit(&env, bounty_id).is_some() {
+            return Err(Error::EscrowNotFinalized);
+        }
+
+        let metadata: Option<EscrowMetadata> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::EscrowMetadata(bounty_id));
+        let receipt = ttl::build_receipt(&env, bounty_id, &escrow, &metadata);
+
+        let storage = env.storage().persistent();
+        storage.remove(&DataKey::Escrow(bounty_id));
+        storage.remove(&DataKey::EscrowMetadata(bounty_id));
+        storage.remove(&DataKey::RefundApproval(bounty_id));
+        storage.set(&DataKey::EscrowReceipt(bounty_id), &receipt);
+        ttl::extend_receipt_ttl(&env, bounty_id);
+
+        emit_escrow_compacted(
+            &env,
+            EscrowCompacted {
+                bounty_id,
+                status: receipt.status.clone(),
+                history_hash: receipt.history_hash.clone(),
+                timestamp: receipt.finalized_at,
+            },
+        );
+        events::emit_v2(&env, EventKind::Compacted, bounty_id, &admin, None, 0, 0);
+
+        Ok(receipt)
+    }
+
     // ========================================================================
     // View Functions (Read-only)
     // ========================================================================
@@ -2223,6 +4173,73 @@ impl BountyEscrowContract {
             .unwrap())
     }
 
+    /// Retrieves the receipt left behind by `finalize_and_compact`.
+    ///
+    /// # Returns
+    /// * `Ok(EscrowReceipt)` - The compacted escrow receipt
+    /// * `Err(Error::BountyNotFound)` - No receipt exists for this bounty
+    pub fn get_escrow_receipt(env: Env, bounty_id: u64) -> Result<EscrowReceipt, Error> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::EscrowReceipt(bounty_id))
+            .ok_or(Error::BountyNotFound)
+    }
+
+    /// Retrieves lifetime payout statistics for a contributor.
+    ///
+    /// # Returns
+    /// * `ContributorStats` - Payout count, total earned, first and last payout
+    ///   timestamps (all zero if the address was never paid)
+    pub fn get_contributor_stats(env: Env, contributor: Address) -> ContributorStats {
+        indexed_storage::get_contributor_stats(&env, &contributor)
+    }
+
+    /// Retrieves one page of the bounties released to a contributor.
+    ///
+    /// # Arguments
+    /// * `contributor` - Address that received the releases
+    /// * `page` - Zero-based page index (`INDEX_PAGE_SIZE` bounties per page)
+    pub fn get_contributor_bounties(
+        env: Env,
+        contributor: Address,
+        page: u32,
+    ) -> Vec<IndexedBounty> {
+        get_bounties_by_contributor(&env, &contributor, page)
+    }
+
+    /// Retrieves one page of the top-earners leaderboard.
+    ///
+    /// The leaderboard keeps the `TOP_EARNERS_CAPACITY` highest-earning
+    /// contributors, ordered by total earned.
+    ///
+    /// # Arguments
+    /// * `page` - Zero-based page index
+    /// * `page_size` - Number of entries per page
+    pub fn get_top_earners(env: Env, page: u32, page_size: u32) -> Vec<EarnerEntry> {
+        get_top_earners_page(&env, page, page_size)
+    }
+
+    /// Retrieves the aggregated volume bucket for one day.
+    ///
+    /// # Arguments
+    /// * `day` - Days since the Unix epoch (`timestamp / 86_400`)
+    ///
+    /// # Returns
+    /// The day's locked, released, refunded and fee totals (zeroed if no activity)
+    pub fn get_daily_volume(env: Env, day: u64) -> DailyBucket {
+        get_daily_bucket(&env, day)
+    }
+
+    /// Retrieves time-series activity built from the daily volume buckets.
+    ///
+    /// # Arguments
+    /// * `from` - Start timestamp (rounded down to the start of its day)
+    /// * `to` - End timestamp (exclusive); at most `MAX_TIME_SERIES_DAYS` days are read
+    /// * `interval` - Seconds per data point, rounded down to whole days (minimum one day)
+    pub fn get_time_series(env: Env, from: u64, to: u64, interval: u64) -> Vec<TimeSeriesPoint> {
+        get_time_series_data(&env, from, to, interval)
+    }
+
     /// Retrieves metadata for a specific bounty.
     ///
     /// # Arguments
@@ -2472,6 +4489,10 @@ impl BountyEscrowContract {
                 .storage()
                 .persistent()
                 .has(&DataKey::Escrow(item.bounty_id))
+                || env
+                    .storage()
+                    .persistent()
+                    .has(&DataKey::EscrowReceipt(item.bounty_id))
             {
                 return Err(Error::BountyExists);
             }
@@ -2487,6 +4508,11 @@ impl BountyEscrowContract {
                 return Err(Error::InvalidAmount);
             }
 
+            // Blacklisted or sanctioned depositors cannot lock funds
+            if !is_participant_allowed(&env, &item.depositor) {
+                return Err(Error::ParticipantNotAllowed);
+            }
+
             // Check for duplicate bounty_ids in the batch
             let mut count = 0u32;
             for other_item in items.iter() {
@@ -2512,6 +4538,8 @@ impl BountyEscrowContract {
             }
             if !found {
                 seen_depositors.push_back(item.depositor.clone());
+                // A batch counts as one lock operation per depositor
+                anti_abuse::check_rate_limit(&env, item.depositor.clone(), RateLimitOp::Lock)?;
                 item.depositor.require_auth();
             }
         }
@@ -2533,20 +4561,8 @@ impl BountyEscrowContract {
             };
 
             // Store escrow
-            env.storage()
-                .persistent()
-                .set(&DataKey::Escrow(item.bounty_id), &escrow);
-
-            // Emit individual event for each locked bounty
-            // emit_funds_locked(
-            //     &env,
-            //     FundsLocked {
-            //         bounty_id: item.bounty_id,
-            //         amount: item.amount,
-            //         depositor: item.depositor.clone(),
-            //         deadline: item.deadline,
-            //     },
-            // );
+            store_escrow(&env, item.bounty_id, &escrow);
+            ttl::extend_escrow_ttl(&env, item.bounty_id);
 
             // Emit individual event for each locked bounty
             on_funds_locked(
@@ -2556,16 +4572,29 @@ impl BountyEscrowContract {
                 &item.depositor,
                 item.deadline,
             );
+            events::emit_v2(
+                &env,
+                EventKind::Locked,
+                item.bounty_id,
+                &item.depositor,
+                None,
+                item.amount,
+                0,
+            );
 
             locked_count += 1;
         }
+        ttl::extend_instance_ttl(&env);
+
+        let total_amount: i128 = items.iter().map(|i| i.amount).sum();
+        record_volume(&env, VolumeKind::Locked, total_amount, locked_count);
 
         // Emit batch event
         emit_batch_funds_locked(
             &env,
             BatchFundsLocked {
                 count: locked_count,
-                total_amount: items.iter().map(|i| i.amount).sum(),
+                total_amount,
                 timestamp,
             },
         );
@@ -2611,6 +4640,8 @@ impl BountyEscrowContract {
         }
 
         let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        // The whole batch counts as one release operation
+        anti_abuse::check_rate_limit(&env, admin.clone(), RateLimitOp::Release)?;
         admin.require_auth();
 
         let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
@@ -2655,6 +4686,14 @@ impl BountyEscrowContract {
                 return Err(Error::InvalidAmount);
             }
 
+            // Blacklisted or sanctioned contributors cannot receive payouts
+            if !is_participant_allowed(&env, &item.contributor) {
+                return Err(Error::ParticipantNotAllowed);
+            }
+
+            // Large payouts require a verified identity
+            kyc::check_payout(&env, &item.contributor, escrow.amount)?;
+
             // Check for duplicate bounty_ids in the batch
             let mut count = 0u32;
             for other_item in items.iter() {
@@ -2672,6 +4711,7 @@ impl BountyEscrowContract {
         }
 
         // Process all items (atomic - all succeed or all fail)
+        let payout_mode = claims::get_payout_mode(&env);
         let mut released_count = 0u32;
         for item in items.iter() {
             let mut escrow: Escrow = env
@@ -2680,25 +4720,18 @@ impl BountyEscrowContract {
                 .get(&DataKey::Escrow(item.bounty_id))
                 .unwrap();
 
-            // Transfer funds to contributor
-            client.transfer(&contract_address, &item.contributor, &escrow.amount);
+            // Transfer funds to contributor, or leave them to be claimed
+            if payout_mode == PayoutMode::Claim {
+                claims::record_entitlement(&env, item.bounty_id, &item.contributor, escrow.amount);
+            } else {
+                client.transfer(&contract_address, &item.contributor, &escrow.amount);
+            }
 
             // Update escrow status
             escrow.status = EscrowStatus::Released;
-            env.storage()
-                .persistent()
-                .set(&DataKey::Escrow(item.bounty_id), &escrow);
-
-            // Emit individual event for each released bounty
-            // emit_funds_released(
-            //     &env,
-            //     FundsReleased {
-            //         bounty_id: item.bounty_id,
-            //         amount: escrow.amount,
-            //         recipient: item.contributor.clone(),
-            //         timestamp,
-            //     },
-            // );
+            escrow.remaining_amount = 0;
+            store_escrow(&env, item.bounty_id, &escrow);
+            ttl::extend_escrow_ttl(&env, item.bounty_id);
 
             // Emit individual event for each released bounty
             on_funds_released(
@@ -2709,9 +4742,20 @@ impl BountyEscrowContract {
                 escrow.remaining_amount,
                 false,
             );
+            events::emit_v2(
+                &env,
+                EventKind::Released,
+                item.bounty_id,
+                &admin,
+                Some(item.contributor.clone()),
+                escrow.amount,
+                0,
+            );
 
             released_count += 1;
         }
+        ttl::extend_instance_ttl(&env);
+        record_volume(&env, VolumeKind::Released, total_amount, released_count);
 
         // Emit batch event
         emit_batch_funds_released(
@@ -2740,3 +4784,33 @@ mod test_edge_cases;
 mod pause_tests;
 #[cfg(test)]
 mod test_invalid_inputs;
+#[cfg(test)]
+mod test_index_pages;
+#[cfg(test)]
+mod test_setup;
+#[cfg(test)]
+mod test_ttl;
+#[cfg(test)]
+mod test_contributor_stats;
+#[cfg(test)]
+mod test_time_series;
+#[cfg(test)]
+mod test_rate_limits;
+#[cfg(test)]
+mod test_sanctions;
+#[cfg(test)]
+mod test_kyc;
+#[cfg(test)]
+mod test_oracle;
+#[cfg(test)]
+mod test_identity;
+#[cfg(test)]
+mod test_claims;
+#[cfg(test)]
+mod test_vesting;
+#[cfg(test)]
+mod test_expiry_sweep;
+#[cfg(test)]
+mod test_notifications;
+#[cfg(test)]
+mod test_events_v2;
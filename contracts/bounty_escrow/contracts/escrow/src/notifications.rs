@@ -0,0 +1,117 @@
+//! # Deadline Notifications Module
+//!
+//! Lets the contract announce approaching deadlines itself so notifiers can
+//! subscribe to `notify` events instead of polling every escrow.
+//!
+//! ## Levels
+//!
+//! Each escrow moves through increasingly urgent notice levels:
+//!
+//! ```text
+//! None ──► 7 days left ──► 1 day left ──► refund available
+//! ```
+//!
+//! `poke_notifications` emits a `NotificationEvent` when an escrow reaches a
+//! level it has not been notified about yet and records the level, so every
+//! notice goes out at most once. An escrow first seen inside the last day
+//! only gets the 1-day notice. Extending a deadline clears the recorded
+//! level so the new deadline is announced from scratch.
+
+use crate::indexed::{create_event_metadata, NotificationEvent, NotificationType};
+use soroban_sdk::{contracttype, symbol_short, Address, Env, String};
+
+/// Lead time of the first deadline notice
+pub const FIRST_NOTICE_LEAD: u64 = 7 * 24 * 60 * 60;
+/// Lead time of the final deadline notice
+pub const FINAL_NOTICE_LEAD: u64 = 24 * 60 * 60;
+
+const LEVEL_FIRST_NOTICE: u32 = 1;
+const LEVEL_FINAL_NOTICE: u32 = 2;
+const LEVEL_REFUND_AVAILABLE: u32 = 3;
+
+/// Outcome of one `poke_notifications` batch
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NotificationSweep {
+    /// Position to pass to the next call (0 once the index wrapped)
+    pub next_cursor: u32,
+    /// Escrows inspected by this batch
+    pub scanned: u32,
+    /// Notifications emitted by this batch
+    pub emitted: u32,
+}
+
+/// Most urgent notice already sent for a bounty (0 if none)
+pub fn get_notice_level(env: &Env, bounty_id: u64) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&(symbol_short!("notice"), bounty_id))
+        .unwrap_or(0)
+}
+
+fn set_notice_level(env: &Env, bounty_id: u64, level: u32) {
+    env.storage()
+        .persistent()
+        .set(&(symbol_short!("notice"), bounty_id), &level);
+}
+
+/// Forgets the notices sent for a bounty, e.g. after its deadline moved
+pub fn clear_notice_level(env: &Env, bounty_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&(symbol_short!("notice"), bounty_id));
+}
+
+/// Notice level an open escrow has reached at `now`, if any
+fn due_level(now: u64, deadline: u64, window: u64) -> u32 {
+    if now >= deadline {
+        return LEVEL_REFUND_AVAILABLE;
+    }
+    let left = deadline - now;
+    if left > window {
+        return 0;
+    }
+    if left <= FINAL_NOTICE_LEAD {
+        LEVEL_FINAL_NOTICE
+    } else if left <= FIRST_NOTICE_LEAD {
+        LEVEL_FIRST_NOTICE
+    } else {
+        0
+    }
+}
+
+/// Emits the notice an open escrow is due, if it was not sent before.
+/// Returns whether a notification was emitted.
+pub fn notify_if_due(
+    env: &Env,
+    bounty_id: u64,
+    depositor: &Address,
+    deadline: u64,
+    window: u64,
+) -> bool {
+    let now = env.ledger().timestamp();
+    let level = due_level(now, deadline, window);
+    if level <= get_notice_level(env, bounty_id) {
+        return false;
+    }
+
+    let (notification_type, message) = match level {
+        LEVEL_REFUND_AVAILABLE => (NotificationType::RefundAvailable, "Deadline passed"),
+        LEVEL_FINAL_NOTICE => (NotificationType::DeadlineApproaching, "Deadline in 1 day"),
+        _ => (NotificationType::DeadlineApproaching, "Deadline in 7 days"),
+    };
+    set_notice_level(env, bounty_id, level);
+
+    crate::indexed::_emit_notification(
+        env,
+        NotificationEvent {
+            notification_type,
+            bounty_id,
+            recipient: depositor.clone(),
+            message: String::from_str(env, message),
+            timestamp: now,
+            metadata: create_event_metadata(env),
+        },
+    );
+    true
+}
@@ -0,0 +1,245 @@
+//! # Oracle-Attested Release Module
+//!
+//! Lets a quorum of off-chain oracles authorize a payout instead of the
+//! admin. Oracles watch the source repository and, once a pull request is
+//! merged, sign a statement binding the bounty to the PR and its author.
+//! Any relayer can then submit the statement with enough signatures.
+//!
+//! ## Flow
+//!
+//! ```text
+//! oracles sign xdr(ReleaseAttestation { network_id, contract, bounty_id,
+//!                                       repo, pr_number, contributor,
+//!                                       expires_at, nonce })
+//!        │
+//!        ▼
+//! release_with_oracle_attestation()
+//!        ├─ this network and contract, not expired, nonce unused
+//!        ├─ >= threshold distinct registered keys, each ed25519 valid
+//!        └─ nonce marked used ──► normal release path
+//! ```
+//!
+//! Signer keys and the threshold are managed by the admin. Rotating a key
+//! keeps the threshold and invalidates statements that still need the old key.
+
+use crate::events::legacy_events_enabled;
+use crate::Error;
+use soroban_sdk::{
+    contracttype, symbol_short, xdr::ToXdr, Address, Bytes, BytesN, Env, String, Vec,
+};
+
+// ============================================================================
+// Data Structures
+// ============================================================================
+
+/// Registered oracle keys and the number of signatures required (M-of-N)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OracleConfig {
+    pub keys: Vec<BytesN<32>>,
+    pub threshold: u32,
+}
+
+/// Statement signed by the oracles; the signed message is its XDR encoding
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReleaseAttestation {
+    /// Network passphrase hash the statement is valid on
+    pub network_id: BytesN<32>,
+    /// Escrow contract the statement is issued for
+    pub contract: Address,
+    pub bounty_id: u64,
+    /// Repository the pull request was merged into, e.g. "org/repo"
+    pub repo: String,
+    pub pr_number: u64,
+    pub contributor: Address,
+    pub expires_at: u64,
+    /// Single-use value preventing the statement from being replayed
+    pub nonce: u64,
+}
+
+/// A single oracle signature over a `ReleaseAttestation`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OracleSignature {
+    pub key: BytesN<32>,
+    pub signature: BytesN<64>,
+}
+
+/// Event emitted when a release is authorized by the oracles
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct OracleReleaseAuthorized {
+    pub bounty_id: u64,
+    pub repo: String,
+    pub pr_number: u64,
+    pub contributor: Address,
+    pub nonce: u64,
+    pub signers: u32,
+    pub relayer: Address,
+    pub timestamp: u64,
+}
+
+/// Event emitted when an oracle key is replaced
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct OracleKeyRotated {
+    pub old_key: BytesN<32>,
+    pub new_key: BytesN<32>,
+    pub timestamp: u64,
+}
+
+// ============================================================================
+// Storage
+// ============================================================================
+
+pub fn get_config(env: &Env) -> Option<OracleConfig> {
+    env.storage().instance().get(&symbol_short!("orc_cfg"))
+}
+
+fn is_valid_config(config: &OracleConfig) -> bool {
+    if config.threshold == 0 || config.threshold > config.keys.len() {
+        return false;
+    }
+    for (i, key) in config.keys.iter().enumerate() {
+        if config.keys.first_index_of(&key) != Some(i as u32) {
+            return false;
+        }
+    }
+    true
+}
+
+/// Replaces the oracle set, or removes it with `None`
+pub fn set_config(env: &Env, config: Option<OracleConfig>) -> Result<(), Error> {
+    match config {
+        Some(config) => {
+            if !is_valid_config(&config) {
+                return Err(Error::InvalidOracleConfig);
+            }
+            env.storage()
+                .instance()
+                .set(&symbol_short!("orc_cfg"), &config);
+        }
+        None => env.storage().instance().remove(&symbol_short!("orc_cfg")),
+    }
+    Ok(())
+}
+
+/// Replaces `old_key` with `new_key`, keeping the threshold
+pub fn rotate_key(env: &Env, old_key: BytesN<32>, new_key: BytesN<32>) -> Result<(), Error> {
+    let mut config = get_config(env).ok_or(Error::OracleNotConfigured)?;
+    let index = config
+        .keys
+        .first_index_of(&old_key)
+        .ok_or(Error::UnknownVerifier)?;
+    if config.keys.contains(&new_key) {
+        return Err(Error::InvalidOracleConfig);
+    }
+
+    config.keys.set(index, new_key.clone());
+    env.storage()
+        .instance()
+        .set(&symbol_short!("orc_cfg"), &config);
+
+    if legacy_events_enabled(env) {
+        env.events().publish(
+            (symbol_short!("oracle"), symbol_short!("rotate")),
+            OracleKeyRotated {
+                old_key,
+                new_key,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+    }
+    Ok(())
+}
+
+pub fn is_nonce_used(env: &Env, nonce: u64) -> bool {
+    env.storage()
+        .persistent()
+        .has(&(symbol_short!("orc_nonce"), nonce))
+}
+
+// ============================================================================
+// Verification
+// ============================================================================
+
+/// Checks that at least `threshold` distinct registered oracles signed `message`
+///
+/// Signatures from unregistered keys are rejected; duplicate signatures from
+/// the same key count once. An invalid signature aborts the call. Returns the
+/// number of distinct signers.
+pub fn verify_quorum(
+    env: &Env,
+    message: &Bytes,
+    signatures: &Vec<OracleSignature>,
+) -> Result<u32, Error> {
+    let config = get_config(env).ok_or(Error::OracleNotConfigured)?;
+
+    let mut signers: Vec<BytesN<32>> = Vec::new(env);
+    for entry in signatures.iter() {
+        if !config.keys.contains(&entry.key) {
+            return Err(Error::UnknownVerifier);
+        }
+        if signers.contains(&entry.key) {
+            continue;
+        }
+        env.crypto()
+            .ed25519_verify(&entry.key, message, &entry.signature);
+        signers.push_back(entry.key);
+    }
+
+    if signers.len() < config.threshold {
+        return Err(Error::OracleQuorumNotMet);
+    }
+    Ok(signers.len())
+}
+
+/// Checks the quorum of signatures over `attestation` and consumes its nonce
+pub fn authorize_release(
+    env: &Env,
+    attestation: &ReleaseAttestation,
+    signatures: &Vec<OracleSignature>,
+    relayer: &Address,
+) -> Result<(), Error> {
+    if get_config(env).is_none() {
+        return Err(Error::OracleNotConfigured);
+    }
+
+    if attestation.network_id != env.ledger().network_id()
+        || attestation.contract != env.current_contract_address()
+    {
+        return Err(Error::WrongContract);
+    }
+
+    let now = env.ledger().timestamp();
+    if attestation.expires_at <= now {
+        return Err(Error::AttestationExpired);
+    }
+    if is_nonce_used(env, attestation.nonce) {
+        return Err(Error::NonceAlreadyUsed);
+    }
+
+    let signers = verify_quorum(env, &attestation.clone().to_xdr(env), signatures)?;
+
+    env.storage()
+        .persistent()
+        .set(&(symbol_short!("orc_nonce"), attestation.nonce), &true);
+
+    if legacy_events_enabled(env) {
+        env.events().publish(
+            (symbol_short!("oracle"), symbol_short!("release")),
+            OracleReleaseAuthorized {
+                bounty_id: attestation.bounty_id,
+                repo: attestation.repo.clone(),
+                pr_number: attestation.pr_number,
+                contributor: attestation.contributor.clone(),
+                nonce: attestation.nonce,
+                signers,
+                relayer: relayer.clone(),
+                timestamp: now,
+            },
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,345 @@
+//! # Merkle Sanctions List Module
+//!
+//! Lets the admin publish a whole sanctions list as a single Merkle root
+//! instead of one blacklist entry per address.
+//!
+//! ## Tree Layout
+//!
+//! ```text
+//! leaf  = sha256(xdr(address))          leaves sorted ascending, padded with
+//! node  = sha256(left || right)         zero hashes to the next power of two
+//! root  = top node (zero hash for an empty list)
+//! ```
+//!
+//! Because leaves are sorted, an address can be proven *absent* by showing the
+//! two adjacent leaves that surround its hash (or the first/last leaf when it
+//! falls outside the range).
+//!
+//! ## Flow
+//!
+//! ```text
+//! publish_sanctions_root(root, leaf_count, require_clearance)  ── version += 1
+//!        │
+//!        ▼
+//! submit_sanctions_proof(address, proof)
+//!        ├─ NonMember ──► clearance recorded for this version, hit cleared
+//!        └─ Member    ──► address marked sanctioned
+//!        │
+//!        ▼
+//! is_participant_allowed() ── override → sanctioned? → clearance required?
+//! ```
+//!
+//! Clearances are tied to the root version, so publishing a new root
+//! invalidates them. Membership marks persist across versions until a
+//! non-membership proof against a later root disproves them. Per-address
+//! overrides set by the admin take precedence over the Merkle list.
+//!
+//! Without `require_clearance` the list is enforced only for addresses that
+//! someone has proven to be members; deployments that must screen every
+//! participant should publish roots with `require_clearance` set.
+
+use crate::events::legacy_events_enabled;
+use soroban_sdk::{contracttype, symbol_short, xdr::ToXdr, Address, Bytes, BytesN, Env, Vec};
+
+// ============================================================================
+// Data Structures
+// ============================================================================
+
+/// The currently published sanctions root
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SanctionsRoot {
+    /// Incremented on every publish; proofs are checked against this version
+    pub version: u32,
+    /// Root of the sorted Merkle tree of sanctioned address hashes
+    pub root: BytesN<32>,
+    /// Number of (non-padding) leaves in the tree
+    pub leaf_count: u32,
+    /// If true, participants need a non-membership proof for this version
+    pub require_clearance: bool,
+    pub published_at: u64,
+}
+
+/// Inclusion proof for a single leaf of the sorted tree
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SortedLeafProof {
+    pub leaf: BytesN<32>,
+    pub index: u32,
+    pub siblings: Vec<BytesN<32>>,
+}
+
+/// Two adjacent leaves surrounding an absent address hash
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdjacentLeaves {
+    pub low: SortedLeafProof,
+    pub high: SortedLeafProof,
+}
+
+/// Evidence that an address hash is not a leaf of the sorted tree
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum NonMembershipProof {
+    /// The published list is empty
+    Empty,
+    /// The hash sorts before the first leaf
+    BeforeFirst(SortedLeafProof),
+    /// The hash sorts after the last leaf
+    AfterLast(SortedLeafProof),
+    /// The hash sorts strictly between two adjacent leaves
+    Between(AdjacentLeaves),
+}
+
+/// Proof submitted about an address against the current root
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SanctionsProof {
+    /// The address hash is a leaf of the tree
+    Member(SortedLeafProof),
+    /// The address hash lies strictly between two adjacent leaves
+    NonMember(NonMembershipProof),
+}
+
+/// Admin decision that takes precedence over the Merkle list
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SanctionsOverride {
+    Allow,
+    Block,
+}
+
+/// Outcome of a verified proof
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ProofOutcome {
+    Cleared,
+    Sanctioned,
+}
+
+// ============================================================================
+// Events
+// ============================================================================
+
+/// Event emitted when a new sanctions root is published
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct SanctionsRootPublished {
+    pub version: u32,
+    pub root: BytesN<32>,
+    pub leaf_count: u32,
+    pub require_clearance: bool,
+    pub timestamp: u64,
+}
+
+pub fn emit_sanctions_root_published(env: &Env, root: &SanctionsRoot) {
+    if !legacy_events_enabled(env) {
+        return;
+    }
+    env.events().publish(
+        (symbol_short!("sanction"), symbol_short!("root")),
+        SanctionsRootPublished {
+            version: root.version,
+            root: root.root.clone(),
+            leaf_count: root.leaf_count,
+            require_clearance: root.require_clearance,
+            timestamp: root.published_at,
+        },
+    );
+}
+
+// ============================================================================
+// Storage
+// ============================================================================
+
+pub fn get_root(env: &Env) -> Option<SanctionsRoot> {
+    env.storage().instance().get(&symbol_short!("sanc_root"))
+}
+
+/// Publishes a new root and returns it with its assigned version
+pub fn publish_root(
+    env: &Env,
+    root: BytesN<32>,
+    leaf_count: u32,
+    require_clearance: bool,
+) -> SanctionsRoot {
+    let version = get_root(env).map(|r| r.version + 1).unwrap_or(1);
+    let published = SanctionsRoot {
+        version,
+        root,
+        leaf_count,
+        require_clearance,
+        published_at: env.ledger().timestamp(),
+    };
+    env.storage()
+        .instance()
+        .set(&symbol_short!("sanc_root"), &published);
+    emit_sanctions_root_published(env, &published);
+    published
+}
+
+pub fn get_override(env: &Env, address: &Address) -> Option<SanctionsOverride> {
+    env.storage()
+        .persistent()
+        .get(&(symbol_short!("sanc_ovr"), address.clone()))
+}
+
+pub fn set_override(env: &Env, address: Address, value: Option<SanctionsOverride>) {
+    let key = (symbol_short!("sanc_ovr"), address);
+    match value {
+        Some(value) => env.storage().persistent().set(&key, &value),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+/// Root version for which the address has a cleared (non-member) proof
+pub fn get_clearance(env: &Env, address: &Address) -> Option<u32> {
+    env.storage()
+        .persistent()
+        .get(&(symbol_short!("sanc_clr"), address.clone()))
+}
+
+/// Root version under which the address was last proven to be on the list
+pub fn get_sanctioned(env: &Env, address: &Address) -> Option<u32> {
+    env.storage()
+        .persistent()
+        .get(&(symbol_short!("sanc_hit"), address.clone()))
+}
+
+/// Stores the outcome of a verified proof for the given root version
+pub fn record_outcome(env: &Env, address: &Address, version: u32, outcome: ProofOutcome) {
+    let (set_key, clear_key) = match outcome {
+        ProofOutcome::Cleared => (symbol_short!("sanc_clr"), symbol_short!("sanc_hit")),
+        ProofOutcome::Sanctioned => (symbol_short!("sanc_hit"), symbol_short!("sanc_clr")),
+    };
+    env.storage()
+        .persistent()
+        .set(&(set_key, address.clone()), &version);
+    env.storage()
+        .persistent()
+        .remove(&(clear_key, address.clone()));
+}
+
+// ============================================================================
+// Merkle Verification
+// ============================================================================
+
+/// Hash of an address as stored in the tree's leaves
+pub fn leaf_hash(env: &Env, address: &Address) -> BytesN<32> {
+    env.crypto().sha256(&address.clone().to_xdr(env)).to_bytes()
+}
+
+/// Hash of two child nodes
+pub fn node_hash(env: &Env, left: &BytesN<32>, right: &BytesN<32>) -> BytesN<32> {
+    let mut data = Bytes::new(env);
+    data.append(&Bytes::from(left.clone()));
+    data.append(&Bytes::from(right.clone()));
+    env.crypto().sha256(&data).to_bytes()
+}
+
+/// Number of levels above the leaves for a tree with `leaf_count` leaves
+pub fn tree_depth(leaf_count: u32) -> u32 {
+    let mut depth = 0;
+    while (1u64 << depth) < leaf_count as u64 {
+        depth += 1;
+    }
+    depth
+}
+
+/// Checks that `proof.leaf` sits at `proof.index` of the published tree
+fn verify_leaf(env: &Env, root: &SanctionsRoot, proof: &SortedLeafProof) -> bool {
+    if proof.index >= root.leaf_count || proof.siblings.len() != tree_depth(root.leaf_count) {
+        return false;
+    }
+
+    let mut node = proof.leaf.clone();
+    let mut index = proof.index;
+    for sibling in proof.siblings.iter() {
+        node = if index.is_multiple_of(2) {
+            node_hash(env, &node, &sibling)
+        } else {
+            node_hash(env, &sibling, &node)
+        };
+        index /= 2;
+    }
+
+    node == root.root
+}
+
+/// Verifies a proof about `address` against the current root
+///
+/// Returns `None` if the proof is malformed or does not match the root.
+pub fn verify_proof(
+    env: &Env,
+    root: &SanctionsRoot,
+    address: &Address,
+    proof: &SanctionsProof,
+) -> Option<ProofOutcome> {
+    let target = leaf_hash(env, address);
+
+    match proof {
+        SanctionsProof::Member(leaf) => {
+            if leaf.leaf == target && verify_leaf(env, root, leaf) {
+                Some(ProofOutcome::Sanctioned)
+            } else {
+                None
+            }
+        }
+        SanctionsProof::NonMember(proof) => {
+            let valid = match proof {
+                NonMembershipProof::Empty => root.leaf_count == 0,
+                NonMembershipProof::BeforeFirst(high) => {
+                    high.index == 0 && target < high.leaf && verify_leaf(env, root, high)
+                }
+                NonMembershipProof::AfterLast(low) => {
+                    low.index + 1 == root.leaf_count
+                        && low.leaf < target
+                        && verify_leaf(env, root, low)
+                }
+                NonMembershipProof::Between(pair) => {
+                    pair.low.index + 1 == pair.high.index
+                        && pair.low.leaf < target
+                        && target < pair.high.leaf
+                        && verify_leaf(env, root, &pair.low)
+                        && verify_leaf(env, root, &pair.high)
+                }
+            };
+
+            if valid {
+                Some(ProofOutcome::Cleared)
+            } else {
+                None
+            }
+        }
+    }
+}
+
+// ============================================================================
+// Participant Check
+// ============================================================================
+
+/// Applies the published sanctions list to an address
+///
+/// Returns `true` when no root has been published.
+pub fn is_allowed_by_sanctions(env: &Env, address: &Address) -> bool {
+    let root = match get_root(env) {
+        Some(root) => root,
+        None => return true,
+    };
+
+    match get_override(env, address) {
+        Some(SanctionsOverride::Allow) => return true,
+        Some(SanctionsOverride::Block) => return false,
+        None => {}
+    }
+
+    // A proven hit stands until a non-membership proof clears it
+    if get_sanctioned(env, address).is_some() {
+        return false;
+    }
+
+    if root.require_clearance {
+        return get_clearance(env, address) == Some(root.version);
+    }
+
+    true
+}
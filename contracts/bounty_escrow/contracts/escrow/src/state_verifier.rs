@@ -43,9 +43,10 @@ pub fn check_escrow_integrity(env: &Env, bounty_id: u64) -> bool {
     let contract_balance = client.balance(&env.current_contract_address());
     
     // For Locked escrows, verify funds are actually in the contract
-    // Note: This is a loose check as contract balance holds ALL locked funds
+    // Note: This is a loose check as contract balance holds ALL locked funds.
+    // Unclaimed entitlements are owed to recipients, so they are not available.
     if escrow.status == EscrowStatus::Locked {
-        contract_balance >= escrow.amount
+        contract_balance - crate::claims::get_unclaimed_total(env) >= escrow.amount
     } else {
         true
     }
@@ -1,7 +1,12 @@
 #[cfg(test)]
 mod blacklist_tests {
-    use crate::BountyEscrowContract;
-    use soroban_sdk::{testutils::Address as _, Address, Env};
+    use crate::blacklist::{BlacklistAction, MAX_BLACKLIST_HISTORY};
+    use crate::{BountyEscrowContract, BountyEscrowContractClient, Error};
+    use soroban_sdk::{
+        symbol_short,
+        testutils::{Address as _, Ledger},
+        Address, Env, Map, String,
+    };
 
     fn create_test_contract(env: &Env) -> (Address, BountyEscrowContract) {
         let contract_id = env.register_contract(None, BountyEscrowContract);
@@ -74,4 +79,174 @@ mod blacklist_tests {
             );
         });
     }
+
+    fn setup_client(env: &Env) -> (BountyEscrowContractClient<'_>, Address) {
+        env.mock_all_auths();
+        env.ledger().set_timestamp(1_000);
+
+        let admin = Address::generate(env);
+        let token = Address::generate(env);
+        let (contract_id, _) = create_test_contract(env);
+        let client = BountyEscrowContractClient::new(env, &contract_id);
+        client.init(&admin, &token);
+        (client, admin)
+    }
+
+    #[test]
+    fn test_suspension_lifts_at_expiry() {
+        let env = Env::default();
+        let (client, admin) = setup_client(&env);
+        let user = Address::generate(&env);
+        let reason = String::from_str(&env, "chargeback review");
+
+        client.suspend_address(&user, &5_000, &Some(reason.clone()));
+        assert!(client.is_blacklisted(&user));
+
+        let entry = client.get_blacklist_entry(&user).unwrap();
+        assert_eq!(entry.reason, Some(reason));
+        assert_eq!(entry.added_by, admin);
+        assert_eq!(entry.added_at, 1_000);
+        assert_eq!(entry.expires_at, Some(5_000));
+
+        env.ledger().set_timestamp(4_999);
+        assert!(client.is_blacklisted(&user));
+        env.ledger().set_timestamp(5_000);
+        assert!(!client.is_blacklisted(&user));
+    }
+
+    #[test]
+    fn test_suspension_must_end_in_future() {
+        let env = Env::default();
+        let (client, _) = setup_client(&env);
+        let user = Address::generate(&env);
+
+        assert_eq!(
+            client.try_suspend_address(&user, &1_000, &None),
+            Err(Ok(Error::InvalidDeadline))
+        );
+    }
+
+    #[test]
+    fn test_blacklist_history_records_changes() {
+        let env = Env::default();
+        let (client, admin) = setup_client(&env);
+        let user = Address::generate(&env);
+
+        client.set_blacklist(&user, &true, &Some(String::from_str(&env, "spam")));
+        env.ledger().set_timestamp(2_000);
+        client.set_blacklist(&user, &false, &None);
+        // Removing an address that is not listed records nothing
+        client.set_blacklist(&user, &false, &None);
+
+        let history = client.get_blacklist_history(&user);
+        assert_eq!(history.len(), 2);
+        let added = history.get(0).unwrap();
+        assert_eq!(added.action, BlacklistAction::Added);
+        assert_eq!(added.actor, admin);
+        assert_eq!(added.expires_at, None);
+        let removed = history.get(1).unwrap();
+        assert_eq!(removed.action, BlacklistAction::Removed);
+        assert_eq!(removed.timestamp, 2_000);
+        assert!(client.get_blacklist_entry(&user).is_none());
+    }
+
+    #[test]
+    fn test_blacklist_history_is_bounded() {
+        let env = Env::default();
+        let (client, _) = setup_client(&env);
+        let user = Address::generate(&env);
+
+        for _ in 0..(MAX_BLACKLIST_HISTORY / 2 + 1) {
+            client.set_blacklist(&user, &true, &None);
+            client.set_blacklist(&user, &false, &None);
+        }
+
+        let history = client.get_blacklist_history(&user);
+        assert_eq!(history.len(), MAX_BLACKLIST_HISTORY);
+        assert_eq!(history.get(0).unwrap().action, BlacklistAction::Added);
+    }
+
+    #[test]
+    fn test_blocked_addresses_paginated_and_skip_expired() {
+        let env = Env::default();
+        let (client, _) = setup_client(&env);
+        let permanent_a = Address::generate(&env);
+        let permanent_b = Address::generate(&env);
+        let suspended = Address::generate(&env);
+
+        client.set_blacklist(&permanent_a, &true, &None);
+        client.set_blacklist(&permanent_b, &true, &None);
+        client.suspend_address(&suspended, &2_000, &None);
+
+        assert_eq!(client.get_blocked_addresses(&0, &10).len(), 3);
+        assert_eq!(client.get_blocked_addresses(&0, &2).len(), 2);
+        assert_eq!(client.get_blocked_addresses(&1, &2).len(), 1);
+
+        env.ledger().set_timestamp(2_000);
+        let blocked = client.get_blocked_addresses(&0, &10);
+        assert_eq!(blocked.len(), 2);
+        for item in blocked.iter() {
+            assert!(item.address != suspended);
+        }
+        assert_eq!(client.get_blocked_addresses(&1, &2).len(), 0);
+    }
+
+    #[test]
+    fn test_blocked_addresses_page_after_removal() {
+        let env = Env::default();
+        let (client, _) = setup_client(&env);
+        let first = Address::generate(&env);
+        let middle = Address::generate(&env);
+        let last = Address::generate(&env);
+
+        client.set_blacklist(&first, &true, &None);
+        client.set_blacklist(&middle, &true, &None);
+        client.set_blacklist(&last, &true, &None);
+        // Re-adding keeps a single slot per address
+        client.set_blacklist(&middle, &true, &None);
+        client.set_blacklist(&middle, &false, &None);
+
+        let blocked = client.get_blocked_addresses(&0, &10);
+        assert_eq!(blocked.len(), 2);
+        for item in blocked.iter() {
+            assert!(item.address != middle);
+        }
+        assert_eq!(client.get_blocked_addresses(&1, &1).len(), 1);
+        assert_eq!(client.get_blocked_addresses(&2, &1).len(), 0);
+    }
+
+    #[test]
+    fn test_legacy_entries_are_migrated_into_pages() {
+        let env = Env::default();
+        let (client, admin) = setup_client(&env);
+        let legacy_a = Address::generate(&env);
+        let legacy_b = Address::generate(&env);
+        let reason = String::from_str(&env, "legacy");
+
+        env.as_contract(&client.address, || {
+            let mut legacy: Map<Address, Option<String>> = Map::new(&env);
+            legacy.set(legacy_a.clone(), Some(reason.clone()));
+            legacy.set(legacy_b.clone(), None);
+            env.storage()
+                .persistent()
+                .set(&symbol_short!("blklist"), &legacy);
+        });
+
+        assert!(client.is_blacklisted(&legacy_a));
+        assert_eq!(client.get_blocked_addresses(&0, &10).len(), 0);
+
+        assert_eq!(client.migrate_legacy_blacklist(&1), 1);
+        assert_eq!(client.migrate_legacy_blacklist(&10), 1);
+        assert_eq!(client.migrate_legacy_blacklist(&10), 0);
+
+        assert_eq!(client.get_blocked_addresses(&0, &10).len(), 2);
+        let entry = client.get_blacklist_entry(&legacy_a).unwrap();
+        assert_eq!(entry.reason, Some(reason));
+        assert_eq!(entry.added_by, admin);
+        assert_eq!(entry.expires_at, None);
+
+        client.set_blacklist(&legacy_b, &false, &None);
+        assert!(!client.is_blacklisted(&legacy_b));
+        assert_eq!(client.get_blocked_addresses(&0, &10).len(), 1);
+    }
 }
@@ -0,0 +1,198 @@
+//! # Pull-Based Payout Tests
+//!
+//! Covers claim mode releases, `claim` / `claim_all`, entitlement queries,
+//! recovery of blocked entitlements and the solvency report.
+
+#![cfg(test)]
+
+use crate::test_setup::EscrowFixture;
+use crate::*;
+use soroban_sdk::{testutils::Ledger, vec, String};
+
+/// Fixture with releases paid out in claim mode
+fn claim_setup<'a>() -> EscrowFixture<'a> {
+    let setup = EscrowFixture::at(1_000);
+    setup.escrow.set_payout_mode(&PayoutMode::Claim);
+    setup
+}
+
+fn lock_and_release(setup: &EscrowFixture, bounty_id: u64, amount: i128) {
+    setup.lock(bounty_id, amount);
+    setup.escrow.release_funds(&bounty_id, &setup.contributor);
+}
+
+#[test]
+fn test_default_mode_pushes_funds() {
+    let setup = claim_setup();
+    setup.escrow.set_payout_mode(&PayoutMode::Push);
+    assert_eq!(setup.escrow.get_payout_mode(), PayoutMode::Push);
+
+    lock_and_release(&setup, 1, 1_000);
+    assert_eq!(setup.token.balance(&setup.contributor), 1_000);
+    assert!(setup.escrow.get_entitlement(&1).is_none());
+}
+
+#[test]
+fn test_release_records_entitlement_and_claim_pays_it() {
+    let setup = claim_setup();
+    lock_and_release(&setup, 1, 1_000);
+
+    assert_eq!(setup.token.balance(&setup.contributor), 0);
+    assert_eq!(
+        setup.escrow.get_escrow_info(&1).status,
+        EscrowStatus::Released
+    );
+    let entitlement = setup.escrow.get_entitlement(&1).unwrap();
+    assert_eq!(entitlement.recipient, setup.contributor);
+    assert_eq!(entitlement.amount, 1_000);
+    assert_eq!(setup.escrow.get_unclaimed_total(), 1_000);
+
+    assert_eq!(setup.escrow.claim(&1), 1_000);
+    assert_eq!(setup.token.balance(&setup.contributor), 1_000);
+    assert!(setup.escrow.get_entitlement(&1).is_none());
+    assert_eq!(setup.escrow.get_unclaimed_total(), 0);
+    assert_eq!(setup.escrow.try_claim(&1), Err(Ok(Error::BountyNotFound)));
+}
+
+#[test]
+fn test_claim_all_pays_every_entitlement() {
+    let setup = claim_setup();
+    lock_and_release(&setup, 1, 1_000);
+    lock_and_release(&setup, 2, 2_500);
+
+    // Batch operations are subject to the same cooldown as single ones
+    let now = setup.env.ledger().timestamp() + 61;
+    setup.env.ledger().set_timestamp(now);
+    let deadline = now + 10_000;
+    setup.escrow.batch_lock_funds(&vec![
+        &setup.env,
+        LockFundsItem {
+            bounty_id: 3,
+            depositor: setup.depositor.clone(),
+            amount: 500,
+            deadline,
+        },
+    ]);
+    setup.escrow.batch_release_funds(&vec![
+        &setup.env,
+        ReleaseFundsItem {
+            bounty_id: 3,
+            contributor: setup.contributor.clone(),
+        },
+    ]);
+
+    assert_eq!(setup.escrow.get_entitlements(&setup.contributor).len(), 3);
+    assert_eq!(setup.escrow.get_unclaimed_total(), 4_000);
+
+    assert_eq!(setup.escrow.claim_all(&setup.contributor), 4_000);
+    assert_eq!(setup.token.balance(&setup.contributor), 4_000);
+    assert_eq!(setup.escrow.get_entitlements(&setup.contributor).len(), 0);
+    assert_eq!(setup.escrow.claim_all(&setup.contributor), 0);
+}
+
+#[test]
+fn test_blocked_recipient_cannot_claim() {
+    let setup = claim_setup();
+    lock_and_release(&setup, 1, 1_000);
+
+    setup.escrow.set_blacklist(
+        &setup.contributor,
+        &true,
+        &Some(String::from_str(&setup.env, "fraud")),
+    );
+    assert_eq!(
+        setup.escrow.try_claim(&1),
+        Err(Ok(Error::ParticipantNotAllowed))
+    );
+    assert_eq!(setup.escrow.get_unclaimed_total(), 1_000);
+}
+
+#[test]
+fn test_solvency_counts_unclaimed_entitlements() {
+    let setup = claim_setup();
+    lock_and_release(&setup, 1, 1_000);
+    let deadline = setup.env.ledger().timestamp() + 61 + 10_000;
+    setup.env.ledger().set_timestamp(deadline - 10_000);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &3_000, &deadline);
+
+    let report = setup.escrow.get_solvency_report();
+    assert_eq!(report.balance, 4_000);
+    assert_eq!(report.escrowed, 3_000);
+    assert_eq!(report.unclaimed_entitlements, 1_000);
+    assert_eq!(report.identity_credits, 0);
+    assert_eq!(report.surplus, 0);
+    assert!(report.is_solvent);
+
+    setup.escrow.claim(&1);
+    let report = setup.escrow.get_solvency_report();
+    assert_eq!(report.balance, 3_000);
+    assert_eq!(report.unclaimed_entitlements, 0);
+    assert!(report.is_solvent);
+}
+
+#[test]
+fn test_admin_recovers_blocked_entitlement() {
+    let setup = claim_setup();
+    lock_and_release(&setup, 1, 1_000);
+
+    // Nothing to recover while the recipient can still claim
+    assert_eq!(
+        setup.escrow.try_recover_entitlement(&1, &setup.depositor),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    setup.escrow.set_blacklist(&setup.contributor, &true, &None);
+    assert_eq!(
+        setup.escrow.try_recover_entitlement(&1, &setup.contributor),
+        Err(Ok(Error::ParticipantNotAllowed))
+    );
+
+    assert_eq!(
+        setup.escrow.recover_entitlement(&1, &setup.depositor),
+        1_000
+    );
+    assert_eq!(setup.token.balance(&setup.depositor), 1_000_000);
+    assert!(setup.escrow.get_entitlement(&1).is_none());
+    assert_eq!(setup.escrow.get_unclaimed_total(), 0);
+    assert!(setup.escrow.get_solvency_report().is_solvent);
+}
+
+#[test]
+fn test_escrowed_total_tracks_refunds_and_rebuilds() {
+    let setup = claim_setup();
+    let now = setup.env.ledger().timestamp();
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &2_000, &(now + 100));
+    setup.env.ledger().set_timestamp(now + 61);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &3_000, &(now + 10_000));
+    assert_eq!(setup.escrow.get_solvency_report().escrowed, 5_000);
+
+    setup.env.ledger().set_timestamp(now + 200);
+    setup.escrow.refund(&1, &None, &None, &RefundMode::Full);
+    let report = setup.escrow.get_solvency_report();
+    assert_eq!(report.escrowed, 3_000);
+    assert_eq!(report.surplus, 0);
+
+    // Simulate a deployment upgraded before the total was tracked
+    setup.env.as_contract(&setup.escrow.address, || {
+        setup
+            .env
+            .storage()
+            .instance()
+            .remove(&DataKey::EscrowedTotal);
+    });
+    assert_eq!(setup.escrow.get_solvency_report().escrowed, 0);
+
+    assert_eq!(
+        setup.escrow.try_rebuild_escrowed_total(&0),
+        Err(Ok(Error::Unauthorized))
+    );
+    setup.escrow.pause();
+    assert_eq!(setup.escrow.rebuild_escrowed_total(&0), 3_000);
+    assert_eq!(setup.escrow.get_solvency_report().escrowed, 3_000);
+}
@@ -0,0 +1,131 @@
+//! # Contributor Index Tests
+//!
+//! Covers recipient tracking on release, `get_contributor_stats` and the
+//! top-earners leaderboard.
+
+#![cfg(test)]
+
+use crate::indexed::indexed_storage::get_release_recipient;
+use crate::test_setup::EscrowFixture;
+use crate::*;
+use soroban_sdk::{testutils::Address as _, vec, Address};
+
+/// Locks and immediately releases a bounty to `contributor`.
+fn pay(setup: &EscrowFixture, bounty_id: u64, amount: i128, contributor: &Address) {
+    setup.lock(bounty_id, amount);
+    setup.escrow.release_funds(&bounty_id, contributor);
+}
+
+#[test]
+fn test_unknown_contributor_has_zero_stats() {
+    let setup = EscrowFixture::at(1_000);
+    let nobody = Address::generate(&setup.env);
+
+    let stats = setup.escrow.get_contributor_stats(&nobody);
+    assert_eq!(stats.contributor, nobody);
+    assert_eq!(stats.payout_count, 0);
+    assert_eq!(stats.total_earned, 0);
+    assert_eq!(stats.first_payout_at, 0);
+    assert_eq!(stats.last_payout_at, 0);
+}
+
+#[test]
+fn test_release_updates_contributor_stats() {
+    let setup = EscrowFixture::at(1_000);
+    let contributor = Address::generate(&setup.env);
+
+    pay(&setup, 1, 1_000, &contributor);
+    let first_at = setup.env.ledger().timestamp();
+    pay(&setup, 2, 500, &contributor);
+    let last_at = setup.env.ledger().timestamp();
+
+    let stats = setup.escrow.get_contributor_stats(&contributor);
+    assert_eq!(stats.payout_count, 2);
+    assert_eq!(stats.total_earned, 1_500);
+    assert_eq!(stats.first_payout_at, first_at);
+    assert_eq!(stats.last_payout_at, last_at);
+
+    setup.env.as_contract(&setup.escrow.address, || {
+        assert_eq!(
+            get_release_recipient(&setup.env, 1),
+            Some(contributor.clone())
+        );
+    });
+
+    let bounties = setup.escrow.get_contributor_bounties(&contributor, &0);
+    assert_eq!(bounties.len(), 2);
+    assert_eq!(bounties.get(0).unwrap().bounty_id, 1);
+    assert_eq!(bounties.get(1).unwrap().bounty_id, 2);
+    assert_eq!(
+        setup
+            .escrow
+            .get_contributor_bounties(&contributor, &1)
+            .len(),
+        0
+    );
+}
+
+#[test]
+fn test_batch_release_records_recipients() {
+    let setup = EscrowFixture::at(1_000);
+    let alice = Address::generate(&setup.env);
+    let bob = Address::generate(&setup.env);
+
+    let deadline = setup.env.ledger().timestamp() + 10_000;
+    setup.escrow.batch_lock_funds(&vec![
+        &setup.env,
+        LockFundsItem {
+            bounty_id: 1,
+            depositor: setup.depositor.clone(),
+            amount: 300,
+            deadline,
+        },
+        LockFundsItem {
+            bounty_id: 2,
+            depositor: setup.depositor.clone(),
+            amount: 700,
+            deadline,
+        },
+    ]);
+    setup.escrow.batch_release_funds(&vec![
+        &setup.env,
+        ReleaseFundsItem {
+            bounty_id: 1,
+            contributor: alice.clone(),
+        },
+        ReleaseFundsItem {
+            bounty_id: 2,
+            contributor: bob.clone(),
+        },
+    ]);
+
+    assert_eq!(setup.escrow.get_contributor_stats(&alice).total_earned, 300);
+    assert_eq!(setup.escrow.get_contributor_stats(&bob).total_earned, 700);
+}
+
+#[test]
+fn test_top_earners_sorted_and_paginated() {
+    let setup = EscrowFixture::at(1_000);
+    let alice = Address::generate(&setup.env);
+    let bob = Address::generate(&setup.env);
+    let carol = Address::generate(&setup.env);
+
+    pay(&setup, 1, 500, &alice);
+    pay(&setup, 2, 900, &bob);
+    pay(&setup, 3, 100, &carol);
+    // Alice overtakes Bob
+    pay(&setup, 4, 600, &alice);
+
+    let board = setup.escrow.get_top_earners(&0, &10);
+    assert_eq!(board.len(), 3);
+    assert_eq!(board.get(0).unwrap().contributor, alice);
+    assert_eq!(board.get(0).unwrap().total_earned, 1_100);
+    assert_eq!(board.get(1).unwrap().contributor, bob);
+    assert_eq!(board.get(2).unwrap().contributor, carol);
+
+    let second_page = setup.escrow.get_top_earners(&1, &2);
+    assert_eq!(second_page.len(), 1);
+    assert_eq!(second_page.get(0).unwrap().contributor, carol);
+
+    assert_eq!(setup.escrow.get_top_earners(&5, &2).len(), 0);
+}
@@ -0,0 +1,238 @@
+//! # Canonical Event Schema Tests
+//!
+//! Covers the v2 event stream: one event per transition, gap-free sequence
+//! numbers, consistent topics, compliance and keeper operations, and the
+//! opt-in legacy events.
+
+#![cfg(test)]
+
+use crate::events::{EscrowEventV2, EventKind, EVENT_SCHEMA_VERSION};
+use crate::test_setup::EscrowFixture;
+use crate::*;
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    Env, Symbol, TryFromVal,
+};
+
+/// Canonical events published so far, checking their topics on the way
+fn v2_events(env: &Env) -> Vec<EscrowEventV2> {
+    let mut events = Vec::new(env);
+    for (_, topics, data) in env.events().all().iter() {
+        let first = match topics.get(0) {
+            Some(first) => first,
+            None => continue,
+        };
+        if Symbol::try_from_val(env, &first) != Ok(symbol_short!("escrow_v2")) {
+            continue;
+        }
+        let event = EscrowEventV2::try_from_val(env, &data).unwrap();
+        assert_eq!(topics.len(), 3);
+        assert_eq!(
+            EventKind::try_from_val(env, &topics.get(1).unwrap()),
+            Ok(event.kind)
+        );
+        assert_eq!(
+            u64::try_from_val(env, &topics.get(2).unwrap()),
+            Ok(event.bounty_id)
+        );
+        events.push_back(event);
+    }
+    events
+}
+
+fn kinds(env: &Env) -> Vec<EventKind> {
+    let mut kinds = Vec::new(env);
+    for event in v2_events(env).iter() {
+        kinds.push_back(event.kind);
+    }
+    kinds
+}
+
+#[test]
+fn test_lock_and_release_emit_one_event_each() {
+    let setup = EscrowFixture::at(1_000);
+    setup
+        .escrow
+        .update_fee_config(&Some(100), &Some(200), &None, &Some(true));
+    setup.lock(1, 10_000);
+    setup.escrow.release_funds(&1, &setup.contributor);
+
+    let events = v2_events(&setup.env);
+    assert_eq!(events.len(), 4);
+
+    let init = events.get(0).unwrap();
+    assert_eq!(init.kind, EventKind::Initialized);
+    assert_eq!(init.version, EVENT_SCHEMA_VERSION);
+    assert_eq!(init.bounty_id, 0);
+    assert_eq!(init.actor, setup.admin);
+
+    let locked = events.get(2).unwrap();
+    assert_eq!(locked.kind, EventKind::Locked);
+    assert_eq!(locked.actor, setup.depositor);
+    assert_eq!((locked.amount, locked.fee), (9_900, 100));
+
+    let released = events.get(3).unwrap();
+    assert_eq!(released.kind, EventKind::Released);
+    assert_eq!(released.bounty_id, 1);
+    assert_eq!(released.recipient, Some(setup.contributor.clone()));
+    assert_eq!((released.amount, released.fee), (9_702, 198));
+}
+
+#[test]
+fn test_sequence_numbers_have_no_gaps() {
+    let setup = EscrowFixture::at(1_000);
+    setup.lock(1, 1_000);
+    setup.lock(2, 1_000);
+    setup.escrow.pause();
+    setup.escrow.unpause();
+    setup.escrow.release_funds(&1, &setup.contributor);
+
+    let events = v2_events(&setup.env);
+    assert_eq!(events.len(), 6);
+    for (index, event) in events.iter().enumerate() {
+        assert_eq!(event.sequence, index as u64 + 1);
+    }
+    assert_eq!(setup.escrow.get_event_sequence(), 6);
+
+    // Idempotent pause calls change nothing and emit nothing
+    setup.escrow.unpause();
+    assert_eq!(setup.escrow.get_event_sequence(), 6);
+}
+
+#[test]
+fn test_refund_paths_emit_their_own_kind() {
+    let setup = EscrowFixture::at(1_000);
+    setup.lock(1, 1_000);
+    setup.lock(2, 1_000);
+
+    setup
+        .escrow
+        .approve_refund(&1, &400, &setup.depositor, &RefundMode::Custom);
+    setup.escrow.refund(
+        &1,
+        &Some(400),
+        &Some(setup.depositor.clone()),
+        &RefundMode::Custom,
+    );
+
+    setup.env.ledger().set_timestamp(100_000);
+    setup.escrow.expire(&2);
+
+    let kinds = kinds(&setup.env);
+    assert_eq!(kinds.len(), 6);
+    assert_eq!(kinds.get(3), Some(EventKind::RefundApproved));
+    assert_eq!(kinds.get(4), Some(EventKind::PartiallyRefunded));
+    assert_eq!(kinds.get(5), Some(EventKind::Expired));
+
+    let expired = v2_events(&setup.env).get(5).unwrap();
+    assert_eq!(expired.actor, setup.escrow.address);
+    assert_eq!(expired.recipient, Some(setup.depositor.clone()));
+    assert_eq!(expired.amount, 1_000);
+}
+
+#[test]
+fn test_batch_operations_emit_per_escrow() {
+    let setup = EscrowFixture::at(1_000);
+    let deadline = setup.env.ledger().timestamp() + 10_000;
+    let mut items = Vec::new(&setup.env);
+    for bounty_id in 1..=3u64 {
+        items.push_back(LockFundsItem {
+            bounty_id,
+            depositor: setup.depositor.clone(),
+            amount: 500,
+            deadline,
+        });
+    }
+    setup.escrow.batch_lock_funds(&items);
+
+    let mut releases = Vec::new(&setup.env);
+    for bounty_id in 1..=2u64 {
+        releases.push_back(ReleaseFundsItem {
+            bounty_id,
+            contributor: setup.contributor.clone(),
+        });
+    }
+    setup.escrow.batch_release_funds(&releases);
+
+    let events = v2_events(&setup.env);
+    assert_eq!(events.len(), 6);
+    for index in 1..=3u32 {
+        let event = events.get(index).unwrap();
+        assert_eq!(event.kind, EventKind::Locked);
+        assert_eq!(event.bounty_id, index as u64);
+    }
+    assert_eq!(events.get(4).unwrap().kind, EventKind::Released);
+    assert_eq!(events.get(5).unwrap().bounty_id, 2);
+}
+
+#[test]
+fn test_legacy_events_are_opt_in() {
+    let setup = EscrowFixture::at(1_000);
+    assert!(!setup.escrow.get_legacy_events());
+
+    setup.lock(1, 1_000);
+    assert_eq!(setup.count_topic(symbol_short!("f_lock")), 0);
+    assert_eq!(setup.count_topic(symbol_short!("escrow_v2")), 2);
+
+    setup.escrow.set_legacy_events(&true);
+    assert!(setup.escrow.get_legacy_events());
+    setup.lock(2, 1_000);
+    assert_eq!(setup.count_topic(symbol_short!("f_lock")), 1);
+    assert_eq!(setup.count_topic(symbol_short!("escrow_v2")), 3);
+}
+
+#[test]
+fn test_compliance_changes_emit_v2_events() {
+    let setup = EscrowFixture::at(1_000);
+    let participant = Address::generate(&setup.env);
+
+    setup.escrow.set_blacklist(&participant, &true, &None);
+    setup.escrow.set_blacklist(&participant, &false, &None);
+    setup.escrow.set_whitelist(&participant, &true);
+    setup.escrow.set_whitelist_mode(&true);
+    setup.escrow.set_whitelist_mode(&false);
+    setup.escrow.set_whitelist(&participant, &false);
+
+    let events = v2_events(&setup.env);
+    assert_eq!(events.len(), 7);
+    assert_eq!(
+        kinds(&setup.env),
+        soroban_sdk::vec![
+            &setup.env,
+            EventKind::Initialized,
+            EventKind::Blacklisted,
+            EventKind::Unblacklisted,
+            EventKind::Whitelisted,
+            EventKind::WhitelistModeEnabled,
+            EventKind::WhitelistModeDisabled,
+            EventKind::Unwhitelisted,
+        ]
+    );
+    for event in events.iter().skip(1) {
+        assert_eq!(event.bounty_id, 0);
+        assert_eq!(event.actor, setup.admin);
+    }
+    assert_eq!(events.get(1).unwrap().recipient, Some(participant.clone()));
+    assert_eq!(events.get(5).unwrap().recipient, None);
+
+    assert_eq!(setup.count_topic(symbol_short!("blklist")), 0);
+    assert_eq!(setup.count_topic(symbol_short!("whtlist")), 0);
+
+    setup.escrow.set_legacy_events(&true);
+    setup.escrow.set_blacklist(&participant, &true, &None);
+    assert_eq!(setup.count_topic(symbol_short!("blklist")), 1);
+    assert_eq!(setup.count_topic(symbol_short!("escrow_v2")), 8);
+}
+
+#[test]
+fn test_keeper_pool_funding_emits_v2_event() {
+    let setup = EscrowFixture::at(1_000);
+    setup.escrow.fund_keeper_pool(&setup.depositor, &500);
+
+    let event = v2_events(&setup.env).last().unwrap();
+    assert_eq!(event.kind, EventKind::KeeperPoolFunded);
+    assert_eq!(event.actor, setup.depositor);
+    assert_eq!(event.amount, 500);
+    assert_eq!(setup.count_topic(symbol_short!("keeper")), 0);
+}
@@ -0,0 +1,177 @@
+//! # Keeper Expiry Sweep Tests
+//!
+//! Covers batched `expire_due` sweeps, cursor wrap-around, keeper rewards
+//! drawn from the pool and the solvency report.
+
+#![cfg(test)]
+
+use crate::indexed::count_bounties_by_status;
+use crate::indexed::indexed_storage::BountyStatus;
+use crate::test_setup::EscrowFixture;
+use crate::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address,
+};
+
+/// Locks `amount` with a deadline `ttl` seconds from now, stepping past the
+/// rate-limit cooldown first.
+fn lock(setup: &EscrowFixture, bounty_id: u64, amount: i128, ttl: u64) {
+    let deadline = setup.env.ledger().timestamp() + 61 + ttl;
+    setup.lock_until(bounty_id, amount, deadline);
+}
+
+fn refunded_count(setup: &EscrowFixture) -> u32 {
+    setup.env.as_contract(&setup.escrow.address, || {
+        count_bounties_by_status(&setup.env, BountyStatus::Refunded)
+    })
+}
+
+#[test]
+fn test_sweep_refunds_only_overdue_escrows() {
+    let setup = EscrowFixture::at(1_000);
+    let keeper = Address::generate(&setup.env);
+    lock(&setup, 1, 1_000, 500);
+    lock(&setup, 2, 2_000, 100_000);
+    lock(&setup, 3, 3_000, 500);
+
+    setup.env.ledger().set_timestamp(5_000);
+    let sweep = setup.escrow.expire_due(&0, &10, &keeper);
+    assert_eq!(sweep.scanned, 3);
+    assert_eq!(sweep.expired, 2);
+    assert_eq!(sweep.refunded_amount, 4_000);
+    assert_eq!(sweep.keeper_reward, 0);
+    assert_eq!(sweep.next_cursor, 0);
+
+    assert_eq!(setup.token.balance(&setup.depositor), 1_000_000 - 2_000);
+    assert_eq!(
+        setup.escrow.get_escrow_info(&1).status,
+        EscrowStatus::Refunded
+    );
+    assert_eq!(
+        setup.escrow.get_escrow_info(&2).status,
+        EscrowStatus::Locked
+    );
+    assert_eq!(refunded_count(&setup), 2);
+
+    // A second pass has nothing left to do
+    let sweep = setup.escrow.expire_due(&0, &10, &keeper);
+    assert_eq!(sweep.expired, 0);
+    assert_eq!(sweep.refunded_amount, 0);
+}
+
+#[test]
+fn test_cursor_walks_index_and_wraps() {
+    let setup = EscrowFixture::at(1_000);
+    let keeper = Address::generate(&setup.env);
+    for bounty_id in 1..=5 {
+        lock(&setup, bounty_id, 100, 500);
+    }
+    setup.env.ledger().set_timestamp(10_000);
+
+    let sweep = setup.escrow.expire_due(&0, &2, &keeper);
+    assert_eq!((sweep.cursor, sweep.next_cursor), (0, 2));
+    assert_eq!(sweep.expired, 2);
+
+    let sweep = setup.escrow.expire_due(&sweep.next_cursor, &2, &keeper);
+    assert_eq!((sweep.cursor, sweep.next_cursor), (2, 4));
+
+    let sweep = setup.escrow.expire_due(&sweep.next_cursor, &2, &keeper);
+    assert_eq!((sweep.cursor, sweep.next_cursor), (4, 0));
+    assert_eq!(sweep.scanned, 1);
+    assert_eq!(refunded_count(&setup), 5);
+
+    // A stale cursor past the end scans nothing and restarts
+    let sweep = setup.escrow.expire_due(&40, &2, &keeper);
+    assert_eq!(sweep.scanned, 0);
+    assert_eq!(sweep.next_cursor, 0);
+}
+
+#[test]
+fn test_keeper_reward_is_capped_by_pool() {
+    let setup = EscrowFixture::at(1_000);
+    let keeper = Address::generate(&setup.env);
+    setup.escrow.set_keeper_reward(&10, &3_600, &1_000);
+    assert_eq!(setup.escrow.fund_keeper_pool(&setup.depositor, &25), 25);
+    for bounty_id in 1..=3 {
+        lock(&setup, bounty_id, 1_000, 500);
+    }
+
+    let report = setup.escrow.get_solvency_report();
+    assert_eq!(report.keeper_pool, 25);
+    assert_eq!(report.surplus, 0);
+    assert!(report.is_solvent);
+
+    setup.env.ledger().set_timestamp(10_000);
+    let sweep = setup.escrow.expire_due(&0, &2, &keeper);
+    assert_eq!(sweep.keeper_reward, 20);
+    assert_eq!(setup.escrow.get_keeper_pool(), 5);
+
+    let sweep = setup.escrow.expire_due(&sweep.next_cursor, &2, &keeper);
+    assert_eq!(sweep.keeper_reward, 5);
+    assert_eq!(setup.escrow.get_keeper_pool(), 0);
+    assert_eq!(setup.token.balance(&keeper), 25);
+
+    // Depositors get their full amounts back; rewards never touch escrows
+    assert_eq!(setup.token.balance(&setup.depositor), 1_000_000 - 25);
+    assert_eq!(setup.token.balance(&setup.escrow.address), 0);
+}
+
+#[test]
+fn test_sweep_validation() {
+    let setup = EscrowFixture::at(1_000);
+    let keeper = Address::generate(&setup.env);
+    assert_eq!(
+        setup.escrow.try_expire_due(&0, &0, &keeper),
+        Err(Ok(Error::InvalidBatchSize))
+    );
+    assert_eq!(
+        setup.escrow.try_expire_due(&0, &101, &keeper),
+        Err(Ok(Error::InvalidBatchSize))
+    );
+    assert_eq!(
+        setup.escrow.try_set_keeper_reward(&-1, &0, &0),
+        Err(Ok(Error::InvalidAmount))
+    );
+    assert_eq!(
+        setup.escrow.try_set_keeper_reward(&10, &0, &-1),
+        Err(Ok(Error::InvalidAmount))
+    );
+    assert_eq!(
+        setup.escrow.try_fund_keeper_pool(&setup.depositor, &0),
+        Err(Ok(Error::InvalidAmount))
+    );
+    assert_eq!(setup.escrow.get_keeper_reward().reward_per_escrow, 0);
+}
+
+#[test]
+fn test_rewards_require_grace_amount_and_third_party_keeper() {
+    let setup = EscrowFixture::at(1_000);
+    let keeper = Address::generate(&setup.env);
+    setup.escrow.set_keeper_reward(&10, &3_600, &1_000);
+    setup.escrow.fund_keeper_pool(&setup.depositor, &100);
+
+    // Too small to earn a reward
+    lock(&setup, 1, 999, 500);
+    // Large enough, but swept before the grace period ends
+    lock(&setup, 2, 1_000, 500);
+    let deadline = setup.env.ledger().timestamp() + 500;
+
+    setup.env.ledger().set_timestamp(deadline + 3_599);
+    let sweep = setup.escrow.expire_due(&0, &10, &keeper);
+    assert_eq!(sweep.expired, 2);
+    assert_eq!(sweep.keeper_reward, 0);
+
+    // Depositors sweeping their own escrows are not rewarded
+    lock(&setup, 3, 1_000, 500);
+    setup.env.ledger().set_timestamp(100_000);
+    let sweep = setup.escrow.expire_due(&0, &10, &setup.depositor);
+    assert_eq!(sweep.expired, 1);
+    assert_eq!(sweep.keeper_reward, 0);
+
+    lock(&setup, 4, 1_000, 500);
+    setup.env.ledger().set_timestamp(200_000);
+    let sweep = setup.escrow.expire_due(&0, &10, &keeper);
+    assert_eq!(sweep.keeper_reward, 10);
+    assert_eq!(setup.escrow.get_keeper_pool(), 90);
+}
@@ -0,0 +1,316 @@
+//! # External Identity Credit Tests
+//!
+//! Covers releasing to a hashed identity, claiming with an oracle-signed
+//! link, and returning expired credits to the depositor.
+
+#![cfg(test)]
+
+use crate::indexed::indexed_storage::{self, BountyStatus};
+use crate::test_setup::EscrowFixture;
+use crate::*;
+use ed25519_dalek::SigningKey;
+use soroban_sdk::{
+    testutils::{ed25519::Sign, Address as _, Ledger},
+    vec, Address, Bytes, BytesN, Env, Vec,
+};
+
+fn oracle() -> SigningKey {
+    SigningKey::from_bytes(&[5u8; 32])
+}
+
+fn identity(env: &Env) -> BytesN<32> {
+    env.crypto()
+        .sha256(&Bytes::from_slice(env, b"github:12345"))
+        .to_bytes()
+}
+
+/// Fixture with a single oracle trusted to sign identity links
+fn identity_setup<'a>() -> EscrowFixture<'a> {
+    let setup = EscrowFixture::at(1_000);
+    let key = BytesN::from_array(&setup.env, &oracle().verifying_key().to_bytes());
+    setup.escrow.set_oracle_config(&Some(OracleConfig {
+        keys: vec![&setup.env, key],
+        threshold: 1,
+    }));
+    setup
+}
+
+fn lock_and_credit(setup: &EscrowFixture, bounty_id: u64, amount: i128, claim_window: u64) {
+    let deadline = setup.env.ledger().timestamp() + 10_000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+    setup
+        .escrow
+        .release_to_identity(&bounty_id, &identity(&setup.env), &claim_window);
+}
+
+fn signed_link(setup: &EscrowFixture, address: &Address) -> (IdentityLink, Vec<OracleSignature>) {
+    let link = IdentityLink {
+        network_id: setup.env.ledger().network_id(),
+        contract: setup.escrow.address.clone(),
+        identity_hash: identity(&setup.env),
+        address: address.clone(),
+        expires_at: setup.env.ledger().timestamp() + 600,
+    };
+    sign(&setup.env, link)
+}
+
+fn sign(env: &Env, link: IdentityLink) -> (IdentityLink, Vec<OracleSignature>) {
+    let oracle = oracle();
+    let signature = oracle.sign(link.clone()).unwrap();
+    let signatures = vec![
+        env,
+        OracleSignature {
+            key: BytesN::from_array(env, &oracle.verifying_key().to_bytes()),
+            signature: BytesN::from_array(env, &signature),
+        },
+    ];
+    (link, signatures)
+}
+
+#[test]
+fn test_credit_and_claim() {
+    let setup = identity_setup();
+    lock_and_credit(&setup, 1, 1_000, 86_400);
+
+    let credit = setup.escrow.get_identity_credit(&1).unwrap();
+    assert_eq!(credit.amount, 1_000);
+    assert_eq!(credit.expires_at, 1_000 + 86_400);
+    assert_eq!(
+        setup
+            .escrow
+            .get_identity_credits(&identity(&setup.env))
+            .len(),
+        1
+    );
+    assert_eq!(
+        setup.escrow.get_escrow_info(&1).status,
+        EscrowStatus::Released
+    );
+    assert_eq!(setup.token.balance(&setup.escrow.address), 1_000);
+
+    let (link, signatures) = signed_link(&setup, &setup.contributor);
+    setup
+        .escrow
+        .claim_identity_credit(&1, &setup.contributor, &link, &signatures);
+
+    assert_eq!(setup.token.balance(&setup.contributor), 1_000);
+    assert!(setup.escrow.get_identity_credit(&1).is_none());
+    assert_eq!(
+        setup
+            .escrow
+            .get_identity_credits(&identity(&setup.env))
+            .len(),
+        0
+    );
+    assert_eq!(
+        setup
+            .escrow
+            .get_contributor_stats(&setup.contributor)
+            .total_earned,
+        1_000
+    );
+}
+
+#[test]
+fn test_claim_rejects_mismatched_link() {
+    let setup = identity_setup();
+    lock_and_credit(&setup, 1, 1_000, 86_400);
+
+    // Link for a different address than the claimant
+    let other = Address::generate(&setup.env);
+    let (link, signatures) = signed_link(&setup, &other);
+    assert_eq!(
+        setup
+            .escrow
+            .try_claim_identity_credit(&1, &setup.contributor, &link, &signatures),
+        Err(Ok(Error::IdentityMismatch))
+    );
+
+    // Link for a different identity
+    let (mut link, _) = signed_link(&setup, &setup.contributor);
+    link.identity_hash = BytesN::from_array(&setup.env, &[1u8; 32]);
+    let (link, signatures) = sign(&setup.env, link);
+    assert_eq!(
+        setup
+            .escrow
+            .try_claim_identity_credit(&1, &setup.contributor, &link, &signatures),
+        Err(Ok(Error::IdentityMismatch))
+    );
+
+    // Link signed for another escrow contract
+    let (mut link, _) = signed_link(&setup, &setup.contributor);
+    link.contract = Address::generate(&setup.env);
+    let (link, signatures) = sign(&setup.env, link);
+    assert_eq!(
+        setup
+            .escrow
+            .try_claim_identity_credit(&1, &setup.contributor, &link, &signatures),
+        Err(Ok(Error::WrongContract))
+    );
+
+    // Unsigned link
+    let (link, _) = signed_link(&setup, &setup.contributor);
+    assert_eq!(
+        setup.escrow.try_claim_identity_credit(
+            &1,
+            &setup.contributor,
+            &link,
+            &Vec::new(&setup.env)
+        ),
+        Err(Ok(Error::OracleQuorumNotMet))
+    );
+    assert!(setup.escrow.get_identity_credit(&1).is_some());
+}
+
+#[test]
+fn test_expired_credit_returns_to_depositor() {
+    let setup = identity_setup();
+    lock_and_credit(&setup, 1, 1_000, 3_600);
+    let (link, signatures) = signed_link(&setup, &setup.contributor);
+
+    assert_eq!(
+        setup.escrow.try_reclaim_identity_credit(&1),
+        Err(Ok(Error::DeadlineNotPassed))
+    );
+
+    setup.env.ledger().set_timestamp(1_000 + 3_600);
+    assert_eq!(
+        setup
+            .escrow
+            .try_claim_identity_credit(&1, &setup.contributor, &link, &signatures),
+        Err(Ok(Error::ClaimExpired))
+    );
+
+    setup.escrow.reclaim_identity_credit(&1);
+    assert_eq!(setup.token.balance(&setup.depositor), 1_000_000);
+    assert_eq!(
+        setup.escrow.get_escrow_info(&1).status,
+        EscrowStatus::Refunded
+    );
+    assert!(setup.escrow.get_identity_credit(&1).is_none());
+    assert_eq!(
+        setup.escrow.try_reclaim_identity_credit(&1),
+        Err(Ok(Error::BountyNotFound))
+    );
+}
+
+#[test]
+fn test_reclaim_blocked_while_paused() {
+    let setup = identity_setup();
+    lock_and_credit(&setup, 1, 1_000, 3_600);
+    setup.env.ledger().set_timestamp(1_000 + 3_600);
+
+    setup.escrow.pause();
+    assert_eq!(
+        setup.escrow.try_reclaim_identity_credit(&1),
+        Err(Ok(Error::ContractPaused))
+    );
+    setup.escrow.unpause();
+    setup.escrow.reclaim_identity_credit(&1);
+    assert_eq!(setup.token.balance(&setup.depositor), 1_000_000);
+}
+
+#[test]
+fn test_release_updates_index_and_fee_is_taken_on_claim() {
+    let setup = identity_setup();
+    setup
+        .escrow
+        .update_fee_config(&None, &Some(100), &None, &Some(true));
+    lock_and_credit(&setup, 1, 1_000, 3_600);
+
+    let indexed = setup
+        .env
+        .as_contract(&setup.escrow.address, || {
+            indexed_storage::get_bounty(&setup.env, 1)
+        })
+        .unwrap();
+    assert_eq!(indexed.status, BountyStatus::Released);
+
+    let credit = setup.escrow.get_identity_credit(&1).unwrap();
+    assert_eq!(credit.amount, 990);
+    assert_eq!(credit.fee, 10);
+    assert_eq!(setup.token.balance(&setup.escrow.address), 1_000);
+
+    let (link, signatures) = signed_link(&setup, &setup.contributor);
+    setup
+        .escrow
+        .claim_identity_credit(&1, &setup.contributor, &link, &signatures);
+    assert_eq!(setup.token.balance(&setup.contributor), 990);
+    assert_eq!(setup.token.balance(&setup.escrow.address), 0);
+}
+
+#[test]
+fn test_reclaim_returns_full_amount_with_refund_record() {
+    let setup = identity_setup();
+    setup
+        .escrow
+        .update_fee_config(&None, &Some(100), &None, &Some(true));
+    lock_and_credit(&setup, 1, 1_000, 3_600);
+
+    setup.env.ledger().set_timestamp(1_000 + 3_600);
+    setup.escrow.reclaim_identity_credit(&1);
+
+    // The withheld fee is returned along with the net credit
+    assert_eq!(setup.token.balance(&setup.depositor), 1_000_000);
+    let history = setup.escrow.get_refund_history(&1);
+    assert_eq!(history.len(), 1);
+    let record = history.get(0).unwrap();
+    assert_eq!(record.amount, 1_000);
+    assert_eq!(record.recipient, setup.depositor);
+    assert_eq!(record.mode, RefundMode::Full);
+}
+
+#[test]
+fn test_outstanding_credit_blocks_compaction() {
+    let setup = identity_setup();
+    lock_and_credit(&setup, 1, 1_000, 3_600);
+
+    assert_eq!(
+        setup.escrow.try_finalize_and_compact(&1),
+        Err(Ok(Error::EscrowNotFinalized))
+    );
+
+    setup.env.ledger().set_timestamp(1_000 + 3_600);
+    setup.escrow.reclaim_identity_credit(&1);
+    let receipt = setup.escrow.finalize_and_compact(&1);
+    assert_eq!(receipt.status, EscrowStatus::Refunded);
+}
+
+#[test]
+fn test_release_to_identity_validation() {
+    let setup = identity_setup();
+    let deadline = setup.env.ledger().timestamp() + 10_000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1_000, &deadline);
+
+    assert_eq!(
+        setup
+            .escrow
+            .try_release_to_identity(&1, &identity(&setup.env), &0),
+        Err(Ok(Error::InvalidDeadline))
+    );
+    assert_eq!(
+        setup
+            .escrow
+            .try_release_to_identity(&2, &identity(&setup.env), &3_600),
+        Err(Ok(Error::BountyNotFound))
+    );
+
+    setup
+        .escrow
+        .release_to_identity(&1, &identity(&setup.env), &3_600);
+    setup.env.ledger().set_timestamp(1_061);
+    assert_eq!(
+        setup
+            .escrow
+            .try_release_to_identity(&1, &identity(&setup.env), &3_600),
+        Err(Ok(Error::FundsNotLocked))
+    );
+    assert_eq!(
+        setup.escrow.try_release_funds(&1, &setup.contributor),
+        Err(Ok(Error::FundsNotLocked))
+    );
+}
@@ -0,0 +1,289 @@
+//! # Paged Index Tests
+//!
+//! Covers the fixed-size index pages used for the `All`, `Status` and
+//! `Depositor` bounty lists, and migration from the legacy flag keys.
+
+#![cfg(test)]
+
+use crate::indexed::indexed_storage::{
+    get_index_ids, get_index_page, get_index_range, index_bounty, index_contains, index_len,
+    remove_bounty, update_bounty_status, BountyStatus, IndexList, IndexedBounty, AMOUNT_INDEX,
+    DEPOSITOR_INDEX, INDEX_PAGE_SIZE, STATUS_INDEX,
+};
+use crate::test_setup::EscrowFixture;
+use crate::*;
+use soroban_sdk::{testutils::Address as _, Address, Env, Vec};
+
+fn indexed(env: &Env, bounty_id: u64, depositor: &Address) -> IndexedBounty {
+    IndexedBounty {
+        bounty_id,
+        depositor: depositor.clone(),
+        amount: 100,
+        deadline: 1000,
+        status: BountyStatus::Locked,
+        created_at: env.ledger().timestamp(),
+        updated_at: env.ledger().timestamp(),
+    }
+}
+
+#[test]
+fn test_index_spills_into_new_pages() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let depositor = Address::generate(&env);
+    let total = INDEX_PAGE_SIZE * 2 + 3;
+    // Seeding many bounties in one frame exceeds the default test budget
+    env.budget().reset_unlimited();
+
+    env.as_contract(&contract_id, || {
+        for id in 0..total as u64 {
+            index_bounty(&env, indexed(&env, id, &depositor));
+        }
+
+        assert_eq!(index_len(&env, &IndexList::All), total);
+        assert_eq!(
+            get_index_page(&env, &IndexList::All, 0).len(),
+            INDEX_PAGE_SIZE
+        );
+        assert_eq!(get_index_page(&env, &IndexList::All, 2).len(), 3);
+        assert_eq!(get_index_page(&env, &IndexList::All, 3).len(), 0);
+        assert_eq!(
+            index_len(&env, &IndexList::Depositor(depositor.clone())),
+            total
+        );
+        assert_eq!(
+            index_len(&env, &IndexList::Status(BountyStatus::Locked)),
+            total
+        );
+    });
+}
+
+#[test]
+fn test_index_range_spans_pages() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let depositor = Address::generate(&env);
+    // Seeding many bounties in one frame exceeds the default test budget
+    env.budget().reset_unlimited();
+
+    env.as_contract(&contract_id, || {
+        for id in 0..(INDEX_PAGE_SIZE + 10) as u64 {
+            index_bounty(&env, indexed(&env, id, &depositor));
+        }
+
+        let ids = get_index_range(&env, &IndexList::All, 45, 55);
+        assert_eq!(ids.len(), 10);
+        assert_eq!(ids.get(0), Some(45));
+        assert_eq!(ids.get(9), Some(54));
+
+        // Ranges are clamped to the list length
+        let tail = get_index_range(&env, &IndexList::All, INDEX_PAGE_SIZE + 8, 1_000);
+        assert_eq!(tail.len(), 2);
+        assert_eq!(get_index_range(&env, &IndexList::All, 70, 80).len(), 0);
+    });
+}
+
+#[test]
+fn test_index_remove_swaps_last_entry() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let depositor = Address::generate(&env);
+    let total = INDEX_PAGE_SIZE + 1;
+    // Seeding many bounties in one frame exceeds the default test budget
+    env.budget().reset_unlimited();
+
+    env.as_contract(&contract_id, || {
+        for id in 0..total as u64 {
+            index_bounty(&env, indexed(&env, id, &depositor));
+        }
+
+        // Removing from the first page pulls the single entry off the last page
+        remove_bounty(&env, 7);
+
+        assert_eq!(index_len(&env, &IndexList::All), INDEX_PAGE_SIZE);
+        assert_eq!(get_index_page(&env, &IndexList::All, 1).len(), 0);
+        assert!(!index_contains(&env, &IndexList::All, 7));
+        assert_eq!(
+            get_index_page(&env, &IndexList::All, 0).get(7),
+            Some(INDEX_PAGE_SIZE as u64)
+        );
+
+        // The moved entry can itself be removed cleanly
+        remove_bounty(&env, INDEX_PAGE_SIZE as u64);
+        let ids = get_index_ids(&env, &IndexList::All);
+        assert_eq!(ids.len(), INDEX_PAGE_SIZE - 1);
+        assert!(!ids.contains(7u64));
+        assert!(!ids.contains(INDEX_PAGE_SIZE as u64));
+    });
+}
+
+#[test]
+fn test_status_change_moves_between_lists() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let depositor = Address::generate(&env);
+
+    env.as_contract(&contract_id, || {
+        for id in 1..=3u64 {
+            index_bounty(&env, indexed(&env, id, &depositor));
+        }
+        update_bounty_status(&env, 2, BountyStatus::Released);
+
+        let locked = get_index_ids(&env, &IndexList::Status(BountyStatus::Locked));
+        let released = get_index_ids(&env, &IndexList::Status(BountyStatus::Released));
+        assert_eq!(locked.len(), 2);
+        assert!(!locked.contains(2u64));
+        assert_eq!(released, Vec::from_array(&env, [2u64]));
+    });
+}
+
+#[test]
+fn test_lock_and_release_update_paged_index() {
+    let EscrowFixture {
+        env,
+        depositor,
+        contributor,
+        escrow,
+        ..
+    } = EscrowFixture::at(0);
+    let contract_id = escrow.address.clone();
+
+    let deadline = env.ledger().timestamp() + 1000;
+    escrow.lock_funds(&depositor, &1, &1_000, &deadline);
+    escrow.release_funds(&1, &contributor);
+
+    env.as_contract(&contract_id, || {
+        assert!(index_contains(&env, &IndexList::All, 1));
+        assert!(index_contains(
+            &env,
+            &IndexList::Status(BountyStatus::Released),
+            1
+        ));
+        assert!(!index_contains(
+            &env,
+            &IndexList::Status(BountyStatus::Locked),
+            1
+        ));
+        assert!(index_contains(
+            &env,
+            &IndexList::Depositor(depositor.clone()),
+            1
+        ));
+    });
+}
+
+#[test]
+fn test_migrate_legacy_flag_entries() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let escrow = BountyEscrowContractClient::new(&env, &contract_id);
+    escrow.init(&admin, &token);
+
+    // Seed bounties the way the old index stored them
+    env.as_contract(&contract_id, || {
+        for id in [3u64, 10, 42] {
+            let bounty = indexed(&env, id, &depositor);
+            env.storage().persistent().set(
+                &(crate::indexed::indexed_storage::BOUNTY_INDEX, id),
+                &bounty,
+            );
+            env.storage()
+                .persistent()
+                .set(&(STATUS_INDEX, BountyStatus::Locked, id), &true);
+            env.storage()
+                .persistent()
+                .set(&(DEPOSITOR_INDEX, depositor.clone(), id), &true);
+            env.storage()
+                .persistent()
+                .set(&(AMOUNT_INDEX, 0u64, id), &true);
+        }
+    });
+
+    assert_eq!(escrow.migrate_bounty_index(&0, &20), 2);
+    assert_eq!(escrow.migrate_bounty_index(&20, &50), 1);
+    // Re-running a range is a no-op
+    assert_eq!(escrow.migrate_bounty_index(&0, &50), 0);
+
+    env.as_contract(&contract_id, || {
+        assert_eq!(index_len(&env, &IndexList::All), 3);
+        assert_eq!(index_len(&env, &IndexList::Status(BountyStatus::Locked)), 3);
+        assert_eq!(index_len(&env, &IndexList::Depositor(depositor.clone())), 3);
+        assert!(!env
+            .storage()
+            .persistent()
+            .has(&(STATUS_INDEX, BountyStatus::Locked, 42u64)));
+        assert!(!env
+            .storage()
+            .persistent()
+            .has(&(DEPOSITOR_INDEX, depositor.clone(), 42u64)));
+        assert!(!env.storage().persistent().has(&(AMOUNT_INDEX, 0u64, 42u64)));
+    });
+}
+
+#[test]
+fn test_migrate_skips_lists_updated_after_upgrade() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let escrow = BountyEscrowContractClient::new(&env, &contract_id);
+    escrow.init(&admin, &token);
+
+    env.as_contract(&contract_id, || {
+        let bounty = indexed(&env, 7, &depositor);
+        env.storage().persistent().set(
+            &(crate::indexed::indexed_storage::BOUNTY_INDEX, 7u64),
+            &bounty,
+        );
+        env.storage()
+            .persistent()
+            .set(&(STATUS_INDEX, BountyStatus::Locked, 7u64), &true);
+        // Released after the upgrade, before the migration reached it
+        update_bounty_status(&env, 7, BountyStatus::Released);
+    });
+
+    assert_eq!(escrow.migrate_bounty_index(&0, &20), 1);
+    assert_eq!(escrow.migrate_bounty_index(&0, &20), 0);
+
+    env.as_contract(&contract_id, || {
+        assert_eq!(index_len(&env, &IndexList::All), 1);
+        assert_eq!(
+            index_len(&env, &IndexList::Status(BountyStatus::Released)),
+            1
+        );
+        assert_eq!(index_len(&env, &IndexList::Status(BountyStatus::Locked)), 0);
+        assert!(!env
+            .storage()
+            .persistent()
+            .has(&(STATUS_INDEX, BountyStatus::Locked, 7u64)));
+    });
+}
+
+#[test]
+fn test_migrate_rejects_invalid_limit() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token = Address::generate(&env);
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let escrow = BountyEscrowContractClient::new(&env, &contract_id);
+    escrow.init(&admin, &token);
+
+    assert_eq!(
+        escrow.try_migrate_bounty_index(&0, &0),
+        Err(Ok(Error::InvalidBatchSize))
+    );
+    assert_eq!(
+        escrow.try_migrate_bounty_index(&0, &(MAX_BATCH_SIZE + 1)),
+        Err(Ok(Error::InvalidBatchSize))
+    );
+}
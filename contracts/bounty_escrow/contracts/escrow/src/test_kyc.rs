@@ -0,0 +1,288 @@
+//! # KYC Attestation Tests
+//!
+//! Covers verifier registration, ed25519 attestation verification and
+//! caching, and the payout gate in `release_funds` / `batch_release_funds`.
+
+#![cfg(test)]
+
+use crate::test_setup::EscrowFixture;
+use crate::*;
+use ed25519_dalek::SigningKey;
+use soroban_sdk::{
+    testutils::{ed25519::Sign, Address as _, Ledger},
+    vec, Address, BytesN, Env,
+};
+
+/// Fixture with a registered KYC verifier and a payout threshold of 1_000
+fn kyc_setup<'a>() -> (EscrowFixture<'a>, SigningKey) {
+    let setup = EscrowFixture::at(1_000);
+    let signer = SigningKey::from_bytes(&[7u8; 32]);
+    setup
+        .escrow
+        .set_kyc_verifier(&public_key(&setup.env, &signer), &true);
+    setup.escrow.set_kyc_config(&Some(KycConfig {
+        payout_threshold: 1_000,
+        min_level: 2,
+    }));
+    (setup, signer)
+}
+
+fn public_key(env: &Env, signer: &SigningKey) -> BytesN<32> {
+    BytesN::from_array(env, &signer.verifying_key().to_bytes())
+}
+
+fn attestation(
+    setup: &EscrowFixture,
+    subject: &Address,
+    level: u32,
+    expires_at: u64,
+) -> KycAttestation {
+    KycAttestation {
+        contract: setup.escrow.address.clone(),
+        subject: subject.clone(),
+        level,
+        issued_at: setup.env.ledger().timestamp(),
+        expires_at,
+    }
+}
+
+fn attest(
+    setup: &EscrowFixture,
+    signer: &SigningKey,
+    subject: &Address,
+    level: u32,
+    expires_at: u64,
+) {
+    let attestation = attestation(setup, subject, level, expires_at);
+    let signature = BytesN::from_array(&setup.env, &signer.sign(attestation.clone()).unwrap());
+    setup
+        .escrow
+        .submit_kyc_attestation(&attestation, &public_key(&setup.env, signer), &signature);
+}
+
+#[test]
+fn test_small_payout_needs_no_attestation() {
+    let (setup, _) = kyc_setup();
+    setup.lock(1, 1_000);
+    setup.escrow.release_funds(&1, &setup.contributor);
+}
+
+#[test]
+fn test_large_payout_requires_attestation() {
+    let (setup, signer) = kyc_setup();
+    setup.lock(1, 5_000);
+
+    assert_eq!(
+        setup.escrow.try_release_funds(&1, &setup.contributor),
+        Err(Ok(Error::KycRequired))
+    );
+
+    attest(&setup, &signer, &setup.contributor, 2, 50_000);
+    let cached = setup
+        .escrow
+        .get_kyc_attestation(&setup.contributor)
+        .unwrap();
+    assert_eq!(cached.level, 2);
+    assert_eq!(cached.expires_at, 50_000);
+    assert_eq!(cached.verified_at, setup.env.ledger().timestamp());
+
+    setup.escrow.release_funds(&1, &setup.contributor);
+}
+
+#[test]
+fn test_low_level_and_expired_attestations_rejected() {
+    let (setup, signer) = kyc_setup();
+    setup.lock(1, 5_000);
+
+    attest(&setup, &signer, &setup.contributor, 1, 5_000);
+    assert_eq!(
+        setup.escrow.try_release_funds(&1, &setup.contributor),
+        Err(Ok(Error::KycRequired))
+    );
+
+    attest(&setup, &signer, &setup.contributor, 3, 5_000);
+    setup.env.ledger().set_timestamp(5_000);
+    assert_eq!(
+        setup.escrow.try_release_funds(&1, &setup.contributor),
+        Err(Ok(Error::AttestationExpired))
+    );
+}
+
+#[test]
+fn test_attestation_submission_checks() {
+    let (setup, signer) = kyc_setup();
+    let rogue = SigningKey::from_bytes(&[9u8; 32]);
+    let attestation = attestation(&setup, &setup.contributor, 2, 50_000);
+    let signature = BytesN::from_array(&setup.env, &rogue.sign(attestation.clone()).unwrap());
+
+    assert_eq!(
+        setup.escrow.try_submit_kyc_attestation(
+            &attestation,
+            &public_key(&setup.env, &rogue),
+            &signature
+        ),
+        Err(Ok(Error::UnknownVerifier))
+    );
+
+    let expired = KycAttestation {
+        expires_at: 1_000,
+        ..attestation.clone()
+    };
+    let signature = BytesN::from_array(&setup.env, &signer.sign(expired.clone()).unwrap());
+    assert_eq!(
+        setup.escrow.try_submit_kyc_attestation(
+            &expired,
+            &public_key(&setup.env, &signer),
+            &signature
+        ),
+        Err(Ok(Error::AttestationExpired))
+    );
+
+    // Signature made by the registered key over different contents
+    let tampered = KycAttestation {
+        level: 5,
+        ..attestation.clone()
+    };
+    let signature = BytesN::from_array(&setup.env, &signer.sign(attestation).unwrap());
+    assert!(setup
+        .escrow
+        .try_submit_kyc_attestation(&tampered, &public_key(&setup.env, &signer), &signature)
+        .is_err());
+    assert!(setup
+        .escrow
+        .get_kyc_attestation(&setup.contributor)
+        .is_none());
+}
+
+#[test]
+fn test_attestation_bound_to_contract_and_issue_time() {
+    let (setup, signer) = kyc_setup();
+    let verifier = public_key(&setup.env, &signer);
+
+    let foreign = KycAttestation {
+        contract: Address::generate(&setup.env),
+        ..attestation(&setup, &setup.contributor, 2, 50_000)
+    };
+    let signature = BytesN::from_array(&setup.env, &signer.sign(foreign.clone()).unwrap());
+    assert_eq!(
+        setup
+            .escrow
+            .try_submit_kyc_attestation(&foreign, &verifier, &signature),
+        Err(Ok(Error::WrongContract))
+    );
+
+    let future = KycAttestation {
+        issued_at: 2_000,
+        ..attestation(&setup, &setup.contributor, 2, 50_000)
+    };
+    let signature = BytesN::from_array(&setup.env, &signer.sign(future.clone()).unwrap());
+    assert_eq!(
+        setup
+            .escrow
+            .try_submit_kyc_attestation(&future, &verifier, &signature),
+        Err(Ok(Error::InvalidAttestation))
+    );
+}
+
+#[test]
+fn test_older_weaker_attestation_does_not_replace_cached() {
+    let (setup, signer) = kyc_setup();
+    let verifier = public_key(&setup.env, &signer);
+    let old = attestation(&setup, &setup.contributor, 2, 50_000);
+    let old_signature = BytesN::from_array(&setup.env, &signer.sign(old.clone()).unwrap());
+
+    setup.env.ledger().set_timestamp(2_000);
+    attest(&setup, &signer, &setup.contributor, 3, 60_000);
+
+    // Replaying the earlier, lower-level attestation is refused
+    assert_eq!(
+        setup
+            .escrow
+            .try_submit_kyc_attestation(&old, &verifier, &old_signature),
+        Err(Ok(Error::InvalidAttestation))
+    );
+    let cached = setup
+        .escrow
+        .get_kyc_attestation(&setup.contributor)
+        .unwrap();
+    assert_eq!(cached.level, 3);
+    assert_eq!(cached.issued_at, 2_000);
+
+    // A later attestation replaces it even at a lower level
+    setup.env.ledger().set_timestamp(3_000);
+    attest(&setup, &signer, &setup.contributor, 1, 70_000);
+    let cached = setup
+        .escrow
+        .get_kyc_attestation(&setup.contributor)
+        .unwrap();
+    assert_eq!(cached.level, 1);
+    assert_eq!(cached.issued_at, 3_000);
+}
+
+#[test]
+fn test_removing_verifier_invalidates_cached_attestations() {
+    let (setup, signer) = kyc_setup();
+    setup.lock(1, 5_000);
+    attest(&setup, &signer, &setup.contributor, 2, 50_000);
+
+    setup
+        .escrow
+        .set_kyc_verifier(&public_key(&setup.env, &signer), &false);
+    assert_eq!(setup.escrow.get_kyc_verifiers().len(), 0);
+    assert_eq!(
+        setup.escrow.try_release_funds(&1, &setup.contributor),
+        Err(Ok(Error::KycRequired))
+    );
+
+    // Disabling the gate lets the payout through
+    setup.escrow.set_kyc_config(&None);
+    setup.escrow.release_funds(&1, &setup.contributor);
+}
+
+#[test]
+fn test_batch_release_checks_each_recipient() {
+    let (setup, signer) = kyc_setup();
+    let verified = Address::generate(&setup.env);
+    let unverified = Address::generate(&setup.env);
+    attest(&setup, &signer, &verified, 2, 50_000);
+
+    let deadline = setup.env.ledger().timestamp() + 10_000;
+    setup.escrow.batch_lock_funds(&vec![
+        &setup.env,
+        LockFundsItem {
+            bounty_id: 1,
+            depositor: setup.depositor.clone(),
+            amount: 5_000,
+            deadline,
+        },
+        LockFundsItem {
+            bounty_id: 2,
+            depositor: setup.depositor.clone(),
+            amount: 5_000,
+            deadline,
+        },
+    ]);
+
+    assert_eq!(
+        setup.escrow.try_batch_release_funds(&vec![
+            &setup.env,
+            ReleaseFundsItem {
+                bounty_id: 1,
+                contributor: verified.clone(),
+            },
+            ReleaseFundsItem {
+                bounty_id: 2,
+                contributor: unverified,
+            },
+        ]),
+        Err(Ok(Error::KycRequired))
+    );
+
+    setup.escrow.batch_release_funds(&vec![
+        &setup.env,
+        ReleaseFundsItem {
+            bounty_id: 1,
+            contributor: verified,
+        },
+    ]);
+}
@@ -0,0 +1,111 @@
+//! # Deadline Notification Tests
+//!
+//! Covers the 7-day and 1-day notices, refund-available notices, the scan
+//! window and de-duplication of already sent notices.
+
+#![cfg(test)]
+
+use crate::test_setup::EscrowFixture;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Symbol,
+};
+
+const DAY: u64 = 24 * 60 * 60;
+
+#[test]
+fn test_notices_fire_once_per_threshold() {
+    let setup = EscrowFixture::at(1_000);
+    let deadline = 1_000 + 30 * DAY;
+    setup.lock_until(1, 1_000, deadline);
+
+    // Too early for any notice
+    let sweep = setup.escrow.poke_notifications(&0, &(30 * DAY));
+    assert_eq!(sweep.scanned, 1);
+    assert_eq!(sweep.emitted, 0);
+    assert_eq!(setup.escrow.get_notice_level(&1), 0);
+
+    setup.env.ledger().set_timestamp(deadline - 6 * DAY);
+    assert_eq!(setup.escrow.poke_notifications(&0, &(30 * DAY)).emitted, 1);
+    assert_eq!(setup.count_topic(Symbol::new(&setup.env, "notify")), 1);
+    assert_eq!(setup.escrow.get_notice_level(&1), 1);
+
+    // Poking again before the next threshold sends nothing
+    setup.env.ledger().set_timestamp(deadline - 2 * DAY);
+    assert_eq!(setup.escrow.poke_notifications(&0, &(30 * DAY)).emitted, 0);
+    assert_eq!(setup.count_topic(Symbol::new(&setup.env, "notify")), 1);
+
+    setup.env.ledger().set_timestamp(deadline - DAY / 2);
+    assert_eq!(setup.escrow.poke_notifications(&0, &(30 * DAY)).emitted, 1);
+    assert_eq!(setup.escrow.get_notice_level(&1), 2);
+
+    setup.env.ledger().set_timestamp(deadline);
+    assert_eq!(setup.escrow.poke_notifications(&0, &(30 * DAY)).emitted, 1);
+    assert_eq!(setup.escrow.get_notice_level(&1), 3);
+    assert_eq!(setup.escrow.poke_notifications(&0, &(30 * DAY)).emitted, 0);
+    assert_eq!(setup.count_topic(Symbol::new(&setup.env, "notify")), 3);
+}
+
+#[test]
+fn test_extended_deadline_is_announced_again() {
+    let setup = EscrowFixture::at(1_000);
+    let deadline = 1_000 + 10 * DAY;
+    setup.lock_until(1, 1_000, deadline);
+
+    setup.env.ledger().set_timestamp(deadline - DAY / 2);
+    assert_eq!(setup.escrow.poke_notifications(&0, &(30 * DAY)).emitted, 1);
+    assert_eq!(setup.escrow.get_notice_level(&1), 2);
+
+    let new_deadline = deadline + 20 * DAY;
+    setup.escrow.extend_refund_deadline(&1, &new_deadline);
+    assert_eq!(setup.escrow.get_notice_level(&1), 0);
+
+    // Nothing is due yet for the new deadline
+    assert_eq!(setup.escrow.poke_notifications(&0, &(30 * DAY)).emitted, 0);
+
+    setup.env.ledger().set_timestamp(new_deadline - 6 * DAY);
+    assert_eq!(setup.escrow.poke_notifications(&0, &(30 * DAY)).emitted, 1);
+    assert_eq!(setup.escrow.get_notice_level(&1), 1);
+    assert_eq!(setup.count_topic(Symbol::new(&setup.env, "notify")), 2);
+}
+
+#[test]
+fn test_late_first_sighting_skips_to_most_urgent_notice() {
+    let setup = EscrowFixture::at(1_000);
+    let deadline = 1_000 + 10 * DAY;
+    setup.lock_until(1, 1_000, deadline);
+
+    setup.env.ledger().set_timestamp(deadline - 3_600);
+    assert_eq!(setup.escrow.poke_notifications(&0, &(30 * DAY)).emitted, 1);
+    assert_eq!(setup.escrow.get_notice_level(&1), 2);
+}
+
+#[test]
+fn test_window_limits_deadline_notices() {
+    let setup = EscrowFixture::at(1_000);
+    setup.lock_until(1, 1_000, 1_000 + 5 * DAY);
+    setup.lock_until(2, 1_000, 1_000 + DAY / 2);
+
+    // Only the deadline within the window is announced
+    let sweep = setup.escrow.poke_notifications(&0, &DAY);
+    assert_eq!(sweep.emitted, 1);
+    assert_eq!(setup.escrow.get_notice_level(&1), 0);
+    assert_eq!(setup.escrow.get_notice_level(&2), 2);
+
+    let sweep = setup.escrow.poke_notifications(&0, &(7 * DAY));
+    assert_eq!(sweep.emitted, 1);
+    assert_eq!(setup.escrow.get_notice_level(&1), 1);
+}
+
+#[test]
+fn test_settled_escrows_are_not_announced() {
+    let setup = EscrowFixture::at(1_000);
+    let contributor = Address::generate(&setup.env);
+    setup.lock_until(1, 1_000, 1_000 + DAY);
+    setup.escrow.release_funds(&1, &contributor);
+
+    setup.env.ledger().set_timestamp(1_000 + 2 * DAY);
+    let sweep = setup.escrow.poke_notifications(&0, &(7 * DAY));
+    assert_eq!(sweep.emitted, 0);
+    assert_eq!(setup.escrow.get_notice_level(&1), 0);
+}
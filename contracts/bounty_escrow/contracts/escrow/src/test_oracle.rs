@@ -0,0 +1,282 @@
+//! # Oracle-Attested Release Tests
+//!
+//! Covers oracle key management, M-of-N signature checks, nonce replay
+//! protection and relayer-submitted releases.
+
+#![cfg(test)]
+
+use crate::test_setup::EscrowFixture;
+use crate::*;
+use ed25519_dalek::SigningKey;
+use soroban_sdk::{
+    testutils::{ed25519::Sign, Address as _},
+    vec, Address, BytesN, Env, String, Vec,
+};
+
+/// Fixture with three registered oracles, two of which must sign, and a
+/// relayer address to submit releases
+fn oracle_setup<'a>() -> (EscrowFixture<'a>, [SigningKey; 3], Address) {
+    let setup = EscrowFixture::at(1_000);
+    let relayer = Address::generate(&setup.env);
+    let oracles = [
+        SigningKey::from_bytes(&[1u8; 32]),
+        SigningKey::from_bytes(&[2u8; 32]),
+        SigningKey::from_bytes(&[3u8; 32]),
+    ];
+
+    let mut keys = Vec::new(&setup.env);
+    for oracle in oracles.iter() {
+        keys.push_back(oracle_key(&setup.env, oracle));
+    }
+    setup
+        .escrow
+        .set_oracle_config(&Some(OracleConfig { keys, threshold: 2 }));
+    (setup, oracles, relayer)
+}
+
+fn oracle_key(env: &Env, signer: &SigningKey) -> BytesN<32> {
+    BytesN::from_array(env, &signer.verifying_key().to_bytes())
+}
+
+fn release_attestation(setup: &EscrowFixture, bounty_id: u64, nonce: u64) -> ReleaseAttestation {
+    ReleaseAttestation {
+        network_id: setup.env.ledger().network_id(),
+        contract: setup.escrow.address.clone(),
+        bounty_id,
+        repo: String::from_str(&setup.env, "org/repo"),
+        pr_number: 42,
+        contributor: setup.contributor.clone(),
+        expires_at: setup.env.ledger().timestamp() + 3_600,
+        nonce,
+    }
+}
+
+fn sign(
+    env: &Env,
+    attestation: &ReleaseAttestation,
+    signers: &[&SigningKey],
+) -> Vec<OracleSignature> {
+    let mut signatures = Vec::new(env);
+    for signer in signers {
+        let signature = signer.sign(attestation.clone()).unwrap();
+        signatures.push_back(OracleSignature {
+            key: oracle_key(env, signer),
+            signature: BytesN::from_array(env, &signature),
+        });
+    }
+    signatures
+}
+
+#[test]
+fn test_quorum_release_by_relayer() {
+    let (setup, oracles, relayer) = oracle_setup();
+    setup.lock(1, 1_000);
+
+    let attestation = release_attestation(&setup, 1, 7);
+    let signatures = sign(&setup.env, &attestation, &[&oracles[0], &oracles[2]]);
+    setup
+        .escrow
+        .release_with_oracle_attestation(&attestation, &signatures, &relayer);
+
+    assert_eq!(setup.token.balance(&setup.contributor), 1_000);
+    assert_eq!(
+        setup.escrow.get_escrow_info(&1).status,
+        EscrowStatus::Released
+    );
+    assert!(setup.escrow.is_oracle_nonce_used(&7));
+}
+
+#[test]
+fn test_quorum_not_met() {
+    let (setup, oracles, relayer) = oracle_setup();
+    setup.lock(1, 1_000);
+
+    let attestation = release_attestation(&setup, 1, 1);
+    let single = sign(&setup.env, &attestation, &[&oracles[0]]);
+    assert_eq!(
+        setup
+            .escrow
+            .try_release_with_oracle_attestation(&attestation, &single, &relayer),
+        Err(Ok(Error::OracleQuorumNotMet))
+    );
+
+    // The same oracle signing twice still counts once
+    let duplicated = sign(&setup.env, &attestation, &[&oracles[0], &oracles[0]]);
+    assert_eq!(
+        setup
+            .escrow
+            .try_release_with_oracle_attestation(&attestation, &duplicated, &relayer),
+        Err(Ok(Error::OracleQuorumNotMet))
+    );
+    assert!(!setup.escrow.is_oracle_nonce_used(&1));
+}
+
+#[test]
+fn test_nonce_cannot_be_replayed() {
+    let (setup, oracles, relayer) = oracle_setup();
+    setup.lock(1, 1_000);
+    setup.lock(2, 1_000);
+
+    let attestation = release_attestation(&setup, 1, 9);
+    let signatures = sign(&setup.env, &attestation, &[&oracles[0], &oracles[1]]);
+    setup
+        .escrow
+        .release_with_oracle_attestation(&attestation, &signatures, &relayer);
+
+    // A fresh statement for another bounty reusing the nonce is rejected
+    setup.advance(61);
+    let replay = release_attestation(&setup, 2, 9);
+    let signatures = sign(&setup.env, &replay, &[&oracles[0], &oracles[1]]);
+    assert_eq!(
+        setup
+            .escrow
+            .try_release_with_oracle_attestation(&replay, &signatures, &relayer),
+        Err(Ok(Error::NonceAlreadyUsed))
+    );
+}
+
+#[test]
+fn test_expired_and_unknown_signers_rejected() {
+    let (setup, oracles, relayer) = oracle_setup();
+    setup.lock(1, 1_000);
+
+    let mut expired = release_attestation(&setup, 1, 1);
+    expired.expires_at = setup.env.ledger().timestamp();
+    let signatures = sign(&setup.env, &expired, &[&oracles[0], &oracles[1]]);
+    assert_eq!(
+        setup
+            .escrow
+            .try_release_with_oracle_attestation(&expired, &signatures, &relayer),
+        Err(Ok(Error::AttestationExpired))
+    );
+
+    let outsider = SigningKey::from_bytes(&[9u8; 32]);
+    let attestation = release_attestation(&setup, 1, 2);
+    let signatures = sign(&setup.env, &attestation, &[&oracles[0], &outsider]);
+    assert_eq!(
+        setup
+            .escrow
+            .try_release_with_oracle_attestation(&attestation, &signatures, &relayer),
+        Err(Ok(Error::UnknownVerifier))
+    );
+}
+
+#[test]
+fn test_signature_must_match_statement() {
+    let (setup, oracles, relayer) = oracle_setup();
+    setup.lock(1, 1_000);
+
+    let attestation = release_attestation(&setup, 1, 1);
+    let signatures = sign(&setup.env, &attestation, &[&oracles[0], &oracles[1]]);
+    let mut redirected = attestation.clone();
+    redirected.contributor = Address::generate(&setup.env);
+
+    assert!(setup
+        .escrow
+        .try_release_with_oracle_attestation(&redirected, &signatures, &relayer)
+        .is_err());
+    assert_eq!(
+        setup.escrow.get_escrow_info(&1).status,
+        EscrowStatus::Locked
+    );
+}
+
+#[test]
+fn test_statement_for_another_contract_rejected() {
+    let (setup, oracles, relayer) = oracle_setup();
+    setup.lock(1, 1_000);
+
+    let mut foreign = release_attestation(&setup, 1, 1);
+    foreign.contract = Address::generate(&setup.env);
+    let signatures = sign(&setup.env, &foreign, &[&oracles[0], &oracles[1]]);
+    assert_eq!(
+        setup
+            .escrow
+            .try_release_with_oracle_attestation(&foreign, &signatures, &relayer),
+        Err(Ok(Error::WrongContract))
+    );
+
+    let mut other_network = release_attestation(&setup, 1, 2);
+    other_network.network_id = BytesN::from_array(&setup.env, &[1u8; 32]);
+    let signatures = sign(&setup.env, &other_network, &[&oracles[0], &oracles[1]]);
+    assert_eq!(
+        setup
+            .escrow
+            .try_release_with_oracle_attestation(&other_network, &signatures, &relayer),
+        Err(Ok(Error::WrongContract))
+    );
+    assert!(!setup.escrow.is_oracle_nonce_used(&1));
+    assert_eq!(
+        setup.escrow.get_escrow_info(&1).status,
+        EscrowStatus::Locked
+    );
+}
+
+#[test]
+fn test_key_rotation() {
+    let (setup, oracles, relayer) = oracle_setup();
+    setup.lock(1, 1_000);
+    let replacement = SigningKey::from_bytes(&[4u8; 32]);
+
+    setup.escrow.rotate_oracle_key(
+        &oracle_key(&setup.env, &oracles[0]),
+        &oracle_key(&setup.env, &replacement),
+    );
+    let config = setup.escrow.get_oracle_config().unwrap();
+    assert_eq!(config.threshold, 2);
+    assert!(config.keys.contains(&oracle_key(&setup.env, &replacement)));
+    assert!(!config.keys.contains(&oracle_key(&setup.env, &oracles[0])));
+
+    let attestation = release_attestation(&setup, 1, 1);
+    let stale = sign(&setup.env, &attestation, &[&oracles[0], &oracles[1]]);
+    assert_eq!(
+        setup
+            .escrow
+            .try_release_with_oracle_attestation(&attestation, &stale, &relayer),
+        Err(Ok(Error::UnknownVerifier))
+    );
+
+    let fresh = sign(&setup.env, &attestation, &[&replacement, &oracles[1]]);
+    setup
+        .escrow
+        .release_with_oracle_attestation(&attestation, &fresh, &relayer);
+    assert_eq!(setup.token.balance(&setup.contributor), 1_000);
+}
+
+#[test]
+fn test_invalid_oracle_config_rejected() {
+    let (setup, oracles, relayer) = oracle_setup();
+    let key = oracle_key(&setup.env, &oracles[0]);
+
+    assert_eq!(
+        setup.escrow.try_set_oracle_config(&Some(OracleConfig {
+            keys: vec![&setup.env, key.clone()],
+            threshold: 2,
+        })),
+        Err(Ok(Error::InvalidOracleConfig))
+    );
+    assert_eq!(
+        setup.escrow.try_set_oracle_config(&Some(OracleConfig {
+            keys: vec![&setup.env, key.clone(), key.clone()],
+            threshold: 1,
+        })),
+        Err(Ok(Error::InvalidOracleConfig))
+    );
+    assert_eq!(
+        setup
+            .escrow
+            .try_rotate_oracle_key(&key, &oracle_key(&setup.env, &oracles[1])),
+        Err(Ok(Error::InvalidOracleConfig))
+    );
+
+    setup.escrow.set_oracle_config(&None);
+    let attestation = release_attestation(&setup, 1, 1);
+    assert_eq!(
+        setup.escrow.try_release_with_oracle_attestation(
+            &attestation,
+            &Vec::new(&setup.env),
+            &relayer
+        ),
+        Err(Ok(Error::OracleNotConfigured))
+    );
+}
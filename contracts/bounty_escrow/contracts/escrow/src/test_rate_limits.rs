@@ -0,0 +1,280 @@
+//! # Anti-Abuse Configuration Tests
+//!
+//! Covers admin-configured default, per-operation and per-address rate limits,
+//! exemptions, and the typed errors returned when a limit is hit.
+
+#![cfg(test)]
+
+use crate::test_setup::EscrowFixture;
+use crate::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address,
+};
+
+fn try_lock(setup: &EscrowFixture, depositor: &Address, bounty_id: u64) -> Result<(), Error> {
+    let deadline = setup.env.ledger().timestamp() + 10_000;
+    match setup
+        .escrow
+        .try_lock_funds(depositor, &bounty_id, &100, &deadline)
+    {
+        Ok(_) => Ok(()),
+        Err(Ok(err)) => Err(err),
+        Err(Err(_)) => panic!("unexpected host error"),
+    }
+}
+
+fn unlimited() -> AntiAbuseConfig {
+    AntiAbuseConfig {
+        window_size: 3600,
+        max_operations: 1_000,
+        cooldown_period: 0,
+    }
+}
+
+#[test]
+fn test_cooldown_returns_typed_error() {
+    let setup = EscrowFixture::at(1_000);
+
+    assert_eq!(try_lock(&setup, &setup.depositor, 1), Ok(()));
+    assert_eq!(
+        try_lock(&setup, &setup.depositor, 2),
+        Err(Error::OperationInCooldown)
+    );
+
+    setup.advance(60);
+    assert_eq!(try_lock(&setup, &setup.depositor, 2), Ok(()));
+}
+
+#[test]
+fn test_default_config_limits_operations_per_window() {
+    let setup = EscrowFixture::at(1_000);
+    setup.escrow.set_rate_limit_config(&AntiAbuseConfig {
+        window_size: 3600,
+        max_operations: 2,
+        cooldown_period: 0,
+    });
+
+    assert_eq!(try_lock(&setup, &setup.depositor, 1), Ok(()));
+    assert_eq!(try_lock(&setup, &setup.depositor, 2), Ok(()));
+    assert_eq!(
+        try_lock(&setup, &setup.depositor, 3),
+        Err(Error::RateLimitExceeded)
+    );
+
+    // A new window resets the count
+    setup.advance(3600);
+    assert_eq!(try_lock(&setup, &setup.depositor, 3), Ok(()));
+}
+
+#[test]
+fn test_operations_are_limited_independently() {
+    let setup = EscrowFixture::at(1_000);
+    setup.escrow.set_op_rate_limit(
+        &RateLimitOp::Release,
+        &Some(AntiAbuseConfig {
+            window_size: 3600,
+            max_operations: 1,
+            cooldown_period: 0,
+        }),
+    );
+    setup
+        .escrow
+        .set_op_rate_limit(&RateLimitOp::Lock, &Some(unlimited()));
+
+    for id in 1..=3 {
+        assert_eq!(try_lock(&setup, &setup.depositor, id), Ok(()));
+    }
+
+    setup.escrow.release_funds(&1, &setup.contributor);
+    assert_eq!(
+        setup.escrow.try_release_funds(&2, &setup.contributor),
+        Err(Ok(Error::RateLimitExceeded))
+    );
+
+    // Clearing the op config falls back to the default (60s cooldown)
+    setup.escrow.set_op_rate_limit(&RateLimitOp::Lock, &None);
+    assert_eq!(setup.escrow.get_op_rate_limit(&RateLimitOp::Lock), None);
+    setup.advance(60);
+    assert_eq!(try_lock(&setup, &setup.depositor, 4), Ok(()));
+    assert_eq!(
+        try_lock(&setup, &setup.depositor, 5),
+        Err(Error::OperationInCooldown)
+    );
+}
+
+#[test]
+fn test_address_override_takes_precedence() {
+    let setup = EscrowFixture::at(1_000);
+    let backend = Address::generate(&setup.env);
+    setup
+        .escrow
+        .set_rate_limit_override(&setup.depositor, &Some(unlimited()));
+
+    assert_eq!(
+        setup
+            .escrow
+            .get_effective_rate_limit(&setup.depositor, &RateLimitOp::Lock),
+        Some(unlimited())
+    );
+    assert_eq!(
+        setup
+            .escrow
+            .get_effective_rate_limit(&backend, &RateLimitOp::Lock),
+        Some(setup.escrow.get_rate_limit_config())
+    );
+    assert_eq!(
+        setup
+            .escrow
+            .get_effective_rate_limit(&backend, &RateLimitOp::Refund),
+        None
+    );
+
+    for id in 1..=15 {
+        assert_eq!(try_lock(&setup, &setup.depositor, id), Ok(()));
+    }
+
+    setup
+        .escrow
+        .set_rate_limit_override(&setup.depositor, &None);
+    assert_eq!(
+        try_lock(&setup, &setup.depositor, 16),
+        Err(Error::OperationInCooldown)
+    );
+}
+
+#[test]
+fn test_exempt_address_skips_limits() {
+    let setup = EscrowFixture::at(1_000);
+    setup.escrow.set_rate_limit_exempt(&setup.depositor, &true);
+    assert!(setup.escrow.is_rate_limit_exempt(&setup.depositor));
+
+    for id in 1..=15 {
+        assert_eq!(try_lock(&setup, &setup.depositor, id), Ok(()));
+    }
+
+    setup.escrow.set_rate_limit_exempt(&setup.depositor, &false);
+    assert!(!setup.escrow.is_rate_limit_exempt(&setup.depositor));
+    assert_eq!(try_lock(&setup, &setup.depositor, 16), Ok(()));
+    assert_eq!(
+        try_lock(&setup, &setup.depositor, 17),
+        Err(Error::OperationInCooldown)
+    );
+}
+
+#[test]
+fn test_invalid_config_rejected() {
+    let setup = EscrowFixture::at(1_000);
+    let zero_window = AntiAbuseConfig {
+        window_size: 0,
+        max_operations: 10,
+        cooldown_period: 0,
+    };
+    let zero_ops = AntiAbuseConfig {
+        window_size: 3600,
+        max_operations: 0,
+        cooldown_period: 0,
+    };
+
+    assert_eq!(
+        setup.escrow.try_set_rate_limit_config(&zero_window),
+        Err(Ok(Error::InvalidRateLimitConfig))
+    );
+    assert_eq!(
+        setup
+            .escrow
+            .try_set_op_rate_limit(&RateLimitOp::Refund, &Some(zero_ops.clone())),
+        Err(Ok(Error::InvalidRateLimitConfig))
+    );
+    assert_eq!(
+        setup
+            .escrow
+            .try_set_rate_limit_override(&setup.depositor, &Some(zero_ops)),
+        Err(Ok(Error::InvalidRateLimitConfig))
+    );
+}
+
+#[test]
+fn test_refunds_limited_only_when_configured() {
+    let setup = EscrowFixture::at(1_000);
+    let deadline = setup.env.ledger().timestamp() + 10_000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1_000, &deadline);
+    setup.env.ledger().set_timestamp(deadline + 1);
+
+    // No refund limit by default
+    setup
+        .escrow
+        .refund(&1, &Some(100), &None, &RefundMode::Partial);
+    setup
+        .escrow
+        .refund(&1, &Some(100), &None, &RefundMode::Partial);
+
+    setup.escrow.set_op_rate_limit(
+        &RateLimitOp::Refund,
+        &Some(AntiAbuseConfig {
+            window_size: 3600,
+            max_operations: 10,
+            cooldown_period: 300,
+        }),
+    );
+    setup
+        .escrow
+        .refund(&1, &Some(100), &None, &RefundMode::Partial);
+    assert_eq!(
+        setup
+            .escrow
+            .try_refund(&1, &Some(100), &None, &RefundMode::Partial),
+        Err(Ok(Error::OperationInCooldown))
+    );
+}
+
+#[test]
+fn test_batch_paths_are_rate_limited() {
+    let setup = EscrowFixture::at(1_000);
+    let deadline = setup.env.ledger().timestamp() + 10_000;
+    let items = vec![
+        &setup.env,
+        LockFundsItem {
+            bounty_id: 1,
+            depositor: setup.depositor.clone(),
+            amount: 100,
+            deadline,
+        },
+        LockFundsItem {
+            bounty_id: 2,
+            depositor: setup.depositor.clone(),
+            amount: 100,
+            deadline,
+        },
+    ];
+
+    // A batch counts as a single lock for each depositor in it
+    assert_eq!(setup.escrow.batch_lock_funds(&items), 2);
+    assert_eq!(
+        try_lock(&setup, &setup.depositor, 3),
+        Err(Error::OperationInCooldown)
+    );
+
+    setup.advance(61);
+    let releases = vec![
+        &setup.env,
+        ReleaseFundsItem {
+            bounty_id: 1,
+            contributor: setup.contributor.clone(),
+        },
+    ];
+    setup.escrow.batch_release_funds(&releases);
+    let releases = vec![
+        &setup.env,
+        ReleaseFundsItem {
+            bounty_id: 2,
+            contributor: setup.contributor.clone(),
+        },
+    ];
+    assert_eq!(
+        setup.escrow.try_batch_release_funds(&releases),
+        Err(Ok(Error::OperationInCooldown))
+    );
+}
@@ -0,0 +1,434 @@
+//! # Merkle Sanctions List Tests
+//!
+//! Builds small sorted sanctions trees off-chain and checks membership and
+//! non-membership proofs, versioning, clearance mode and admin overrides.
+
+#![cfg(test)]
+
+use crate::sanctions::{leaf_hash, node_hash, AdjacentLeaves, NonMembershipProof, SortedLeafProof};
+use crate::test_setup::EscrowFixture;
+use crate::*;
+use soroban_sdk::{testutils::Address as _, vec, Address, BytesN, Env, Vec};
+
+fn addresses(env: &Env, count: u32) -> Vec<Address> {
+    let mut addresses = Vec::new(env);
+    for _ in 0..count {
+        addresses.push_back(Address::generate(env));
+    }
+    addresses
+}
+
+/// Sorted Merkle tree built the same way compliance tooling would
+struct SanctionsTree {
+    leaves: Vec<BytesN<32>>,
+    levels: Vec<Vec<BytesN<32>>>,
+}
+
+impl SanctionsTree {
+    fn new(env: &Env, addresses: &Vec<Address>) -> Self {
+        // Insertion sort keeps the leaves in ascending order
+        let mut leaves: Vec<BytesN<32>> = Vec::new(env);
+        for address in addresses.iter() {
+            let leaf = leaf_hash(env, &address);
+            let mut at = leaves.len();
+            for i in 0..leaves.len() {
+                if leaf < leaves.get(i).unwrap() {
+                    at = i;
+                    break;
+                }
+            }
+            leaves.insert(at, leaf);
+        }
+
+        let mut width = 1u32;
+        while width < leaves.len() {
+            width *= 2;
+        }
+        let mut level = leaves.clone();
+        while level.len() < width {
+            level.push_back(BytesN::from_array(env, &[0u8; 32]));
+        }
+
+        let mut levels = Vec::new(env);
+        levels.push_back(level.clone());
+        while level.len() > 1 {
+            let mut next = Vec::new(env);
+            let mut i = 0;
+            while i < level.len() {
+                next.push_back(node_hash(
+                    env,
+                    &level.get(i).unwrap(),
+                    &level.get(i + 1).unwrap(),
+                ));
+                i += 2;
+            }
+            levels.push_back(next.clone());
+            level = next;
+        }
+
+        Self { leaves, levels }
+    }
+
+    fn root(&self, env: &Env) -> BytesN<32> {
+        if self.leaves.is_empty() {
+            return BytesN::from_array(env, &[0u8; 32]);
+        }
+        self.levels.last().unwrap().get(0).unwrap()
+    }
+
+    fn proof(&self, env: &Env, index: u32) -> SortedLeafProof {
+        let mut siblings = Vec::new(env);
+        let mut i = index;
+        for depth in 0..self.levels.len() - 1 {
+            siblings.push_back(self.levels.get(depth).unwrap().get(i ^ 1).unwrap());
+            i /= 2;
+        }
+        SortedLeafProof {
+            leaf: self.leaves.get(index).unwrap(),
+            index,
+            siblings,
+        }
+    }
+
+    fn position(&self, env: &Env, address: &Address) -> Result<u32, u32> {
+        let target = leaf_hash(env, address);
+        for i in 0..self.leaves.len() {
+            let leaf = self.leaves.get(i).unwrap();
+            if leaf == target {
+                return Ok(i);
+            }
+            if target < leaf {
+                return Err(i);
+            }
+        }
+        Err(self.leaves.len())
+    }
+
+    /// Finds the address whose hash sits at `index` of the sorted leaves
+    fn leaf_address(&self, env: &Env, addresses: &Vec<Address>, index: u32) -> Address {
+        let leaf = self.leaves.get(index).unwrap();
+        for address in addresses.iter() {
+            if leaf_hash(env, &address) == leaf {
+                return address;
+            }
+        }
+        panic!("leaf not found");
+    }
+
+    fn member_proof(&self, env: &Env, address: &Address) -> SanctionsProof {
+        let index = self.position(env, address).unwrap();
+        SanctionsProof::Member(self.proof(env, index))
+    }
+
+    fn non_member_proof(&self, env: &Env, address: &Address) -> SanctionsProof {
+        let insert_at = self.position(env, address).unwrap_err();
+        let proof = if self.leaves.is_empty() {
+            NonMembershipProof::Empty
+        } else if insert_at == 0 {
+            NonMembershipProof::BeforeFirst(self.proof(env, 0))
+        } else if insert_at == self.leaves.len() {
+            NonMembershipProof::AfterLast(self.proof(env, insert_at - 1))
+        } else {
+            NonMembershipProof::Between(AdjacentLeaves {
+                low: self.proof(env, insert_at - 1),
+                high: self.proof(env, insert_at),
+            })
+        };
+        SanctionsProof::NonMember(proof)
+    }
+}
+
+#[test]
+fn test_no_root_allows_everyone() {
+    let setup = EscrowFixture::at(1_000);
+    assert_eq!(setup.escrow.get_sanctions_root(), None);
+    assert!(setup.escrow.check_participant(&setup.depositor));
+}
+
+#[test]
+fn test_publishing_bumps_version() {
+    let setup = EscrowFixture::at(1_000);
+    let listed = addresses(&setup.env, 3);
+    let tree = SanctionsTree::new(&setup.env, &listed);
+
+    let root = tree.root(&setup.env);
+    assert_eq!(setup.escrow.publish_sanctions_root(&root, &3, &false), 1);
+    assert_eq!(setup.escrow.publish_sanctions_root(&root, &3, &true), 2);
+
+    let published = setup.escrow.get_sanctions_root().unwrap();
+    assert_eq!(published.version, 2);
+    assert_eq!(published.root, root);
+    assert!(published.require_clearance);
+}
+
+#[test]
+fn test_membership_proof_blocks_participant() {
+    let setup = EscrowFixture::at(1_000);
+    let listed = addresses(&setup.env, 5);
+    let tree = SanctionsTree::new(&setup.env, &listed);
+    setup
+        .escrow
+        .publish_sanctions_root(&tree.root(&setup.env), &5, &false);
+
+    let sanctioned = listed.get(2).unwrap();
+    setup.token_admin.mint(&sanctioned, &10_000);
+    // Not yet proven: accepted while clearance is optional
+    assert!(setup.escrow.check_participant(&sanctioned));
+
+    let cleared = setup
+        .escrow
+        .submit_sanctions_proof(&sanctioned, &tree.member_proof(&setup.env, &sanctioned));
+    assert!(!cleared);
+    assert!(!setup.escrow.check_participant(&sanctioned));
+
+    let deadline = setup.env.ledger().timestamp() + 1_000;
+    assert_eq!(
+        setup
+            .escrow
+            .try_lock_funds(&sanctioned, &1, &1_000, &deadline),
+        Err(Ok(Error::ParticipantNotAllowed))
+    );
+}
+
+#[test]
+fn test_membership_hit_survives_new_root_until_disproven() {
+    let setup = EscrowFixture::at(1_000);
+    let listed = addresses(&setup.env, 4);
+    let tree = SanctionsTree::new(&setup.env, &listed);
+    setup
+        .escrow
+        .publish_sanctions_root(&tree.root(&setup.env), &4, &false);
+
+    let sanctioned = listed.get(1).unwrap();
+    setup
+        .escrow
+        .submit_sanctions_proof(&sanctioned, &tree.member_proof(&setup.env, &sanctioned));
+
+    // Republishing does not un-sanction a proven member
+    setup
+        .escrow
+        .publish_sanctions_root(&tree.root(&setup.env), &4, &false);
+    assert!(!setup.escrow.check_participant(&sanctioned));
+
+    // Once delisted, a non-membership proof against the new root clears it
+    let mut remaining = Vec::new(&setup.env);
+    for address in listed.iter() {
+        if address != sanctioned {
+            remaining.push_back(address);
+        }
+    }
+    let delisted = SanctionsTree::new(&setup.env, &remaining);
+    setup
+        .escrow
+        .publish_sanctions_root(&delisted.root(&setup.env), &3, &false);
+    assert!(!setup.escrow.check_participant(&sanctioned));
+    assert!(setup.escrow.submit_sanctions_proof(
+        &sanctioned,
+        &delisted.non_member_proof(&setup.env, &sanctioned)
+    ));
+    assert!(setup.escrow.check_participant(&sanctioned));
+}
+
+#[test]
+fn test_batch_paths_check_sanctions() {
+    let setup = EscrowFixture::at(1_000);
+    let listed = addresses(&setup.env, 3);
+    let tree = SanctionsTree::new(&setup.env, &listed);
+    setup
+        .escrow
+        .publish_sanctions_root(&tree.root(&setup.env), &3, &false);
+
+    let sanctioned = listed.get(0).unwrap();
+    setup
+        .escrow
+        .submit_sanctions_proof(&sanctioned, &tree.member_proof(&setup.env, &sanctioned));
+    setup.token_admin.mint(&sanctioned, &10_000);
+
+    let deadline = setup.env.ledger().timestamp() + 1_000;
+    let lock_item = |bounty_id: u64, depositor: &Address| LockFundsItem {
+        bounty_id,
+        depositor: depositor.clone(),
+        amount: 1_000,
+        deadline,
+    };
+    assert_eq!(
+        setup
+            .escrow
+            .try_batch_lock_funds(&vec![&setup.env, lock_item(1, &sanctioned)]),
+        Err(Ok(Error::ParticipantNotAllowed))
+    );
+
+    setup
+        .escrow
+        .batch_lock_funds(&vec![&setup.env, lock_item(1, &setup.depositor)]);
+    assert_eq!(
+        setup.escrow.try_batch_release_funds(&vec![
+            &setup.env,
+            ReleaseFundsItem {
+                bounty_id: 1,
+                contributor: sanctioned.clone(),
+            },
+        ]),
+        Err(Ok(Error::ParticipantNotAllowed))
+    );
+}
+
+#[test]
+fn test_clearance_required_for_participants() {
+    let setup = EscrowFixture::at(1_000);
+    let listed = addresses(&setup.env, 6);
+    let tree = SanctionsTree::new(&setup.env, &listed);
+    setup
+        .escrow
+        .publish_sanctions_root(&tree.root(&setup.env), &6, &true);
+
+    let deadline = setup.env.ledger().timestamp() + 1_000;
+    assert_eq!(
+        setup
+            .escrow
+            .try_lock_funds(&setup.depositor, &1, &1_000, &deadline),
+        Err(Ok(Error::ParticipantNotAllowed))
+    );
+
+    let proof = tree.non_member_proof(&setup.env, &setup.depositor);
+    assert!(setup
+        .escrow
+        .submit_sanctions_proof(&setup.depositor, &proof));
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1_000, &deadline);
+
+    // A new root version requires a fresh proof
+    setup
+        .escrow
+        .publish_sanctions_root(&tree.root(&setup.env), &6, &true);
+    assert!(!setup.escrow.check_participant(&setup.depositor));
+    assert!(setup
+        .escrow
+        .submit_sanctions_proof(&setup.depositor, &proof));
+    assert!(setup.escrow.check_participant(&setup.depositor));
+}
+
+#[test]
+fn test_non_membership_at_edges_and_empty_list() {
+    let setup = EscrowFixture::at(1_000);
+    let listed = addresses(&setup.env, 4);
+    let tree = SanctionsTree::new(&setup.env, &listed);
+    setup
+        .escrow
+        .publish_sanctions_root(&tree.root(&setup.env), &4, &true);
+
+    // Every generated address lands somewhere among the leaves; check a batch
+    // so the first-leaf and last-leaf cases are likely exercised
+    for candidate in addresses(&setup.env, 8).iter() {
+        let proof = tree.non_member_proof(&setup.env, &candidate);
+        assert!(setup.escrow.submit_sanctions_proof(&candidate, &proof));
+        assert!(setup.escrow.check_participant(&candidate));
+    }
+
+    let empty = SanctionsTree::new(&setup.env, &Vec::new(&setup.env));
+    setup
+        .escrow
+        .publish_sanctions_root(&empty.root(&setup.env), &0, &true);
+    assert!(setup.escrow.submit_sanctions_proof(
+        &setup.depositor,
+        &SanctionsProof::NonMember(NonMembershipProof::Empty)
+    ));
+}
+
+#[test]
+fn test_invalid_proofs_rejected() {
+    let setup = EscrowFixture::at(1_000);
+    let listed = addresses(&setup.env, 4);
+    let tree = SanctionsTree::new(&setup.env, &listed);
+
+    assert_eq!(
+        setup.escrow.try_submit_sanctions_proof(
+            &setup.depositor,
+            &SanctionsProof::NonMember(NonMembershipProof::Empty)
+        ),
+        Err(Ok(Error::SanctionsRootNotSet))
+    );
+
+    setup
+        .escrow
+        .publish_sanctions_root(&tree.root(&setup.env), &4, &true);
+
+    // A listed address cannot produce a non-membership proof: its own leaf
+    // is one of the bounds
+    let listed_addr = tree.leaf_address(&setup.env, &listed, 1);
+    let own_leaf = SanctionsProof::NonMember(NonMembershipProof::Between(AdjacentLeaves {
+        low: tree.proof(&setup.env, 1),
+        high: tree.proof(&setup.env, 2),
+    }));
+    assert_eq!(
+        setup
+            .escrow
+            .try_submit_sanctions_proof(&listed_addr, &own_leaf),
+        Err(Ok(Error::InvalidSanctionsProof))
+    );
+
+    // Non-adjacent leaves do not prove absence, even though the hash of the
+    // listed address lies between them
+    let gap = SanctionsProof::NonMember(NonMembershipProof::Between(AdjacentLeaves {
+        low: tree.proof(&setup.env, 0),
+        high: tree.proof(&setup.env, 3),
+    }));
+    assert_eq!(
+        setup.escrow.try_submit_sanctions_proof(&listed_addr, &gap),
+        Err(Ok(Error::InvalidSanctionsProof))
+    );
+
+    // A membership proof for someone else's leaf does not apply
+    assert_eq!(
+        setup.escrow.try_submit_sanctions_proof(
+            &setup.depositor,
+            &tree.member_proof(&setup.env, &listed_addr)
+        ),
+        Err(Ok(Error::InvalidSanctionsProof))
+    );
+
+    // Tampered siblings do not hash to the published root
+    let mut leaf_proof = tree.proof(&setup.env, 1);
+    leaf_proof
+        .siblings
+        .set(0, BytesN::from_array(&setup.env, &[7u8; 32]));
+    assert_eq!(
+        setup
+            .escrow
+            .try_submit_sanctions_proof(&listed_addr, &SanctionsProof::Member(leaf_proof)),
+        Err(Ok(Error::InvalidSanctionsProof))
+    );
+}
+
+#[test]
+fn test_overrides_take_precedence() {
+    let setup = EscrowFixture::at(1_000);
+    let listed = addresses(&setup.env, 3);
+    let tree = SanctionsTree::new(&setup.env, &listed);
+    setup
+        .escrow
+        .publish_sanctions_root(&tree.root(&setup.env), &3, &true);
+
+    // False positive: allow without a proof
+    setup
+        .escrow
+        .set_sanctions_override(&setup.depositor, &Some(SanctionsOverride::Allow));
+    assert!(setup.escrow.check_participant(&setup.depositor));
+    assert_eq!(
+        setup.escrow.get_sanctions_override(&setup.depositor),
+        Some(SanctionsOverride::Allow)
+    );
+
+    // Block despite a valid clearance
+    let other = Address::generate(&setup.env);
+    let proof = tree.non_member_proof(&setup.env, &other);
+    setup.escrow.submit_sanctions_proof(&other, &proof);
+    setup
+        .escrow
+        .set_sanctions_override(&other, &Some(SanctionsOverride::Block));
+    assert!(!setup.escrow.check_participant(&other));
+
+    setup.escrow.set_sanctions_override(&other, &None);
+    assert!(setup.escrow.check_participant(&other));
+}
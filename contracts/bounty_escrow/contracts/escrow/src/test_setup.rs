@@ -0,0 +1,101 @@
+//! # Shared Test Fixture
+//!
+//! Deploys a token and an initialized escrow contract with a funded
+//! depositor. Feature test modules use it directly and keep only their
+//! feature-specific helpers.
+
+#![cfg(test)]
+
+use crate::{BountyEscrowContract, BountyEscrowContractClient};
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    token, Address, Env, Symbol, TryFromVal,
+};
+
+pub fn create_token_contract<'a>(
+    e: &Env,
+    admin: &Address,
+) -> (token::Client<'a>, token::StellarAssetClient<'a>) {
+    let contract_address = e.register_stellar_asset_contract(admin.clone());
+    (
+        token::Client::new(e, &contract_address),
+        token::StellarAssetClient::new(e, &contract_address),
+    )
+}
+
+/// Initialized escrow with all auths mocked and 1_000_000 minted to the
+/// depositor
+pub struct EscrowFixture<'a> {
+    pub env: Env,
+    pub admin: Address,
+    pub depositor: Address,
+    pub contributor: Address,
+    pub token: token::Client<'a>,
+    pub token_admin: token::StellarAssetClient<'a>,
+    pub escrow: BountyEscrowContractClient<'a>,
+}
+
+impl<'a> EscrowFixture<'a> {
+    /// Deploys the fixture with the ledger clock at `timestamp`
+    pub fn at(timestamp: u64) -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().set_timestamp(timestamp);
+
+        let admin = Address::generate(&env);
+        let depositor = Address::generate(&env);
+        let contributor = Address::generate(&env);
+
+        let (token, token_admin) = create_token_contract(&env, &admin);
+        let contract_id = env.register_contract(None, BountyEscrowContract);
+        let escrow = BountyEscrowContractClient::new(&env, &contract_id);
+
+        escrow.init(&admin, &token.address);
+        token_admin.mint(&depositor, &1_000_000);
+
+        Self {
+            env,
+            admin,
+            depositor,
+            contributor,
+            token,
+            token_admin,
+            escrow,
+        }
+    }
+
+    /// Moves the ledger clock forward by `seconds`
+    pub fn advance(&self, seconds: u64) {
+        let now = self.env.ledger().timestamp();
+        self.env.ledger().set_timestamp(now + seconds);
+    }
+
+    /// Locks `amount` from the depositor until `deadline`, stepping past the
+    /// rate-limit cooldown first
+    pub fn lock_until(&self, bounty_id: u64, amount: i128, deadline: u64) {
+        self.advance(61);
+        self.escrow
+            .lock_funds(&self.depositor, &bounty_id, &amount, &deadline);
+    }
+
+    /// Locks `amount` from the depositor for 10_000 seconds, stepping past
+    /// the rate-limit cooldown first. Returns the deadline.
+    pub fn lock(&self, bounty_id: u64, amount: i128) -> u64 {
+        let deadline = self.env.ledger().timestamp() + 61 + 10_000;
+        self.lock_until(bounty_id, amount, deadline);
+        deadline
+    }
+
+    /// Number of events published so far whose first topic is `topic`
+    pub fn count_topic(&self, topic: Symbol) -> u32 {
+        let mut count = 0;
+        for (_, topics, _) in self.env.events().all().iter() {
+            if let Some(first) = topics.get(0) {
+                if Symbol::try_from_val(&self.env, &first) == Ok(topic.clone()) {
+                    count += 1;
+                }
+            }
+        }
+        count
+    }
+}
@@ -0,0 +1,164 @@
+//! # Daily Volume Bucket Tests
+//!
+//! Covers the per-day aggregates updated on lock, release, refund and fee
+//! collection, and the time-series queries built on top of them.
+
+#![cfg(test)]
+
+use crate::indexed::time_series::{DAILY_BUCKET, MAX_TIME_SERIES_DAYS, SECONDS_PER_DAY};
+use crate::test_setup::EscrowFixture;
+use crate::ttl::DEFAULT_ACTIVE_EXTEND_TO;
+use crate::*;
+use soroban_sdk::{
+    testutils::{storage::Persistent, Events, Ledger},
+    vec, IntoVal, Symbol, TryIntoVal, Vec,
+};
+
+const START_DAY: u64 = 10;
+
+#[test]
+fn test_untouched_day_is_empty() {
+    let setup = EscrowFixture::at(START_DAY * SECONDS_PER_DAY + 1_000);
+
+    let bucket = setup.escrow.get_daily_volume(&3);
+    assert_eq!(bucket.day_start, 3 * SECONDS_PER_DAY);
+    assert_eq!(bucket.locked_count, 0);
+    assert_eq!(bucket.locked_amount, 0);
+    assert_eq!(bucket.fee_amount, 0);
+}
+
+#[test]
+fn test_lock_release_refund_update_bucket() {
+    let setup = EscrowFixture::at(START_DAY * SECONDS_PER_DAY + 1_000);
+
+    setup.lock(1, 1_000);
+    let deadline = setup.lock(2, 400);
+    setup.advance(61);
+    setup.escrow.release_funds(&1, &setup.contributor);
+    setup.env.ledger().set_timestamp(deadline + 1);
+    setup.escrow.expire(&2);
+
+    let bucket = setup.escrow.get_daily_volume(&START_DAY);
+    assert_eq!(bucket.locked_count, 2);
+    assert_eq!(bucket.locked_amount, 1_400);
+    assert_eq!(bucket.released_count, 1);
+    assert_eq!(bucket.released_amount, 1_000);
+    assert_eq!(bucket.refunded_count, 1);
+    assert_eq!(bucket.refunded_amount, 400);
+    assert_eq!(bucket.fee_count, 0);
+}
+
+#[test]
+fn test_fees_are_recorded_separately() {
+    let setup = EscrowFixture::at(START_DAY * SECONDS_PER_DAY + 1_000);
+    // 1% lock fee
+    setup
+        .escrow
+        .update_fee_config(&Some(100), &None, &Some(setup.admin.clone()), &Some(true));
+
+    setup.lock(1, 10_000);
+
+    let bucket = setup.escrow.get_daily_volume(&START_DAY);
+    assert_eq!(bucket.locked_amount, 9_900);
+    assert_eq!(bucket.fee_count, 1);
+    assert_eq!(bucket.fee_amount, 100);
+}
+
+#[test]
+fn test_batch_lock_records_one_aggregate() {
+    let setup = EscrowFixture::at(START_DAY * SECONDS_PER_DAY + 1_000);
+    let deadline = setup.env.ledger().timestamp() + 10_000;
+
+    setup.escrow.batch_lock_funds(&vec![
+        &setup.env,
+        LockFundsItem {
+            bounty_id: 1,
+            depositor: setup.depositor.clone(),
+            amount: 300,
+            deadline,
+        },
+        LockFundsItem {
+            bounty_id: 2,
+            depositor: setup.depositor.clone(),
+            amount: 700,
+            deadline,
+        },
+    ]);
+
+    let bucket = setup.escrow.get_daily_volume(&START_DAY);
+    assert_eq!(bucket.locked_count, 2);
+    assert_eq!(bucket.locked_amount, 1_000);
+
+    // Only one DailyVolume event is emitted for the whole batch
+    let analytics = symbol_short!("analytic");
+    let mut daily_events = Vec::new(&setup.env);
+    for (_, topics, data) in setup.env.events().all().iter() {
+        let topic: Result<Symbol, _> = topics.get(0).unwrap().try_into_val(&setup.env);
+        if topic == Ok(analytics.clone()) {
+            daily_events.push_back(data);
+        }
+    }
+    assert_eq!(daily_events.len(), 1);
+
+    let event: indexed::AnalyticsEvent = daily_events.get(0).unwrap().into_val(&setup.env);
+    assert_eq!(event.count, 2);
+    assert_eq!(event.total_amount, 1_000);
+    assert_eq!(event.timestamp, START_DAY * SECONDS_PER_DAY);
+}
+
+#[test]
+fn test_time_series_groups_days_by_interval() {
+    let setup = EscrowFixture::at(START_DAY * SECONDS_PER_DAY + 1_000);
+
+    setup.lock(1, 100);
+    setup.advance(SECONDS_PER_DAY);
+    setup.lock(2, 200);
+    setup.advance(SECONDS_PER_DAY);
+    setup.lock(3, 300);
+
+    let from = START_DAY * SECONDS_PER_DAY;
+    let to = (START_DAY + 3) * SECONDS_PER_DAY;
+
+    let daily = setup.escrow.get_time_series(&from, &to, &SECONDS_PER_DAY);
+    assert_eq!(daily.len(), 3);
+    assert_eq!(daily.get(1).unwrap().timestamp, from + SECONDS_PER_DAY);
+    assert_eq!(daily.get(1).unwrap().locked_amount, 200);
+    assert_eq!(daily.get(2).unwrap().count, 1);
+
+    // Two-day interval: the final point is truncated at `to`
+    let paired = setup
+        .escrow
+        .get_time_series(&from, &to, &(2 * SECONDS_PER_DAY));
+    assert_eq!(paired.len(), 2);
+    assert_eq!(paired.get(0).unwrap().count, 2);
+    assert_eq!(paired.get(0).unwrap().locked_amount, 300);
+    assert_eq!(paired.get(1).unwrap().locked_amount, 300);
+
+    // Sub-day intervals are rounded up to one day
+    assert_eq!(setup.escrow.get_time_series(&from, &to, &60).len(), 3);
+}
+
+#[test]
+fn test_time_series_range_is_capped() {
+    let setup = EscrowFixture::at(START_DAY * SECONDS_PER_DAY + 1_000);
+    let from = START_DAY * SECONDS_PER_DAY;
+    let to = from + 10 * MAX_TIME_SERIES_DAYS * SECONDS_PER_DAY;
+
+    let points = setup.escrow.get_time_series(&from, &to, &SECONDS_PER_DAY);
+    assert_eq!(points.len() as u64, MAX_TIME_SERIES_DAYS);
+}
+
+#[test]
+fn test_bucket_ttl_is_extended() {
+    let setup = EscrowFixture::at(START_DAY * SECONDS_PER_DAY + 1_000);
+    setup.lock(1, 100);
+
+    let ttl = setup.env.as_contract(&setup.escrow.address, || {
+        setup
+            .env
+            .storage()
+            .persistent()
+            .get_ttl(&(DAILY_BUCKET, START_DAY))
+    });
+    assert_eq!(ttl, DEFAULT_ACTIVE_EXTEND_TO);
+}
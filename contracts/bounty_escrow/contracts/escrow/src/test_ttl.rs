@@ -0,0 +1,177 @@
+//! # Storage TTL and Compaction Tests
+
+#![cfg(test)]
+
+use crate::test_setup::EscrowFixture;
+use crate::ttl::{DEFAULT_ACTIVE_EXTEND_TO, DEFAULT_RECEIPT_EXTEND_TO};
+use crate::*;
+use soroban_sdk::{
+    map,
+    testutils::{storage::Persistent as _, Ledger},
+    vec, String,
+};
+
+fn persistent_ttl(setup: &EscrowFixture, key: &DataKey) -> u32 {
+    setup.env.as_contract(&setup.escrow.address, || {
+        setup.env.storage().persistent().get_ttl(key)
+    })
+}
+
+fn has(setup: &EscrowFixture, key: &DataKey) -> bool {
+    setup.env.as_contract(&setup.escrow.address, || {
+        setup.env.storage().persistent().has(key)
+    })
+}
+
+#[test]
+fn test_lock_extends_escrow_ttl() {
+    let setup = EscrowFixture::at(0);
+    setup.lock(1, 1_000);
+
+    assert_eq!(
+        persistent_ttl(&setup, &DataKey::Escrow(1)),
+        DEFAULT_ACTIVE_EXTEND_TO
+    );
+}
+
+#[test]
+fn test_bump_escrow_restores_ttl() {
+    let setup = EscrowFixture::at(0);
+    setup.lock(1, 1_000);
+
+    // Let most of the TTL run out, then bump
+    let seq = setup.env.ledger().sequence();
+    setup
+        .env
+        .ledger()
+        .set_sequence_number(seq + DEFAULT_ACTIVE_EXTEND_TO - 100);
+    assert_eq!(persistent_ttl(&setup, &DataKey::Escrow(1)), 100);
+
+    setup.escrow.bump_escrow(&1);
+    assert_eq!(
+        persistent_ttl(&setup, &DataKey::Escrow(1)),
+        DEFAULT_ACTIVE_EXTEND_TO
+    );
+}
+
+#[test]
+fn test_bump_unknown_escrow_fails() {
+    let setup = EscrowFixture::at(0);
+    assert_eq!(
+        setup.escrow.try_bump_escrow(&99),
+        Err(Ok(Error::BountyNotFound))
+    );
+}
+
+#[test]
+fn test_compact_requires_final_status() {
+    let setup = EscrowFixture::at(0);
+    setup.lock(1, 1_000);
+
+    assert_eq!(
+        setup.escrow.try_finalize_and_compact(&1),
+        Err(Ok(Error::EscrowNotFinalized))
+    );
+}
+
+#[test]
+fn test_compact_released_escrow() {
+    let setup = EscrowFixture::at(0);
+    setup.lock(1, 1_000);
+    setup.escrow.set_escrow_metadata(
+        &1,
+        &EscrowMetadata {
+            repo_id: Some(String::from_str(&setup.env, "owner/repo")),
+            issue_id: Some(String::from_str(&setup.env, "7")),
+            bounty_type: None,
+            tags: vec![&setup.env],
+            custom_fields: map![&setup.env],
+        },
+    );
+    setup.escrow.release_funds(&1, &setup.contributor);
+
+    let receipt = setup.escrow.finalize_and_compact(&1);
+    assert_eq!(receipt.status, EscrowStatus::Released);
+    assert_eq!(receipt.amount, 1_000);
+    assert_eq!(receipt.released_amount, 1_000);
+    assert_eq!(receipt.refunded_amount, 0);
+    assert_eq!(receipt.release_recipient, Some(setup.contributor.clone()));
+    assert_eq!(
+        receipt.recipients,
+        vec![&setup.env, setup.contributor.clone()]
+    );
+    assert_eq!(setup.escrow.get_escrow_receipt(&1), receipt);
+
+    assert!(!has(&setup, &DataKey::Escrow(1)));
+    assert!(!has(&setup, &DataKey::EscrowMetadata(1)));
+    assert_eq!(
+        persistent_ttl(&setup, &DataKey::EscrowReceipt(1)),
+        DEFAULT_RECEIPT_EXTEND_TO
+    );
+    assert_eq!(
+        setup.escrow.try_get_escrow_info(&1),
+        Err(Ok(Error::BountyNotFound))
+    );
+
+    // The bounty ID stays reserved after compaction
+    setup.advance(61);
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    assert_eq!(
+        setup
+            .escrow
+            .try_lock_funds(&setup.depositor, &1, &1_000, &deadline),
+        Err(Ok(Error::BountyExists))
+    );
+
+    // Receipts can be bumped too, but not compacted twice
+    setup.escrow.bump_escrow(&1);
+    assert_eq!(
+        setup.escrow.try_finalize_and_compact(&1),
+        Err(Ok(Error::BountyNotFound))
+    );
+}
+
+#[test]
+fn test_compact_refunded_escrow_records_recipients() {
+    let setup = EscrowFixture::at(0);
+    let deadline = setup.lock(1, 1_000);
+
+    setup.env.ledger().set_timestamp(deadline + 1);
+    setup.escrow.expire(&1);
+
+    let receipt = setup.escrow.finalize_and_compact(&1);
+    assert_eq!(receipt.status, EscrowStatus::Refunded);
+    assert_eq!(receipt.refunded_amount, 1_000);
+    assert_eq!(receipt.released_amount, 0);
+    assert_eq!(receipt.release_recipient, None);
+    assert_eq!(
+        receipt.recipients,
+        vec![&setup.env, setup.depositor.clone()]
+    );
+}
+
+#[test]
+fn test_set_ttl_policy_validates_values() {
+    let setup = EscrowFixture::at(0);
+
+    let policy = TtlPolicy {
+        active_threshold: 2_000,
+        active_extend_to: 1_000,
+        receipt_extend_to: 500,
+    };
+    assert_eq!(
+        setup.escrow.try_set_ttl_policy(&policy),
+        Err(Ok(Error::InvalidTtlPolicy))
+    );
+
+    let policy = TtlPolicy {
+        active_threshold: 10_000,
+        active_extend_to: 50_000,
+        receipt_extend_to: 500,
+    };
+    setup.escrow.set_ttl_policy(&policy);
+    assert_eq!(setup.escrow.get_ttl_policy(), policy);
+
+    setup.lock(1, 1_000);
+    assert_eq!(persistent_ttl(&setup, &DataKey::Escrow(1)), 50_000);
+}
@@ -0,0 +1,237 @@
+//! # Vested Release Tests
+//!
+//! Covers converting an escrow into a linear stream, cliff handling,
+//! withdrawals, cancellation by the depositor or admin, and the index status.
+
+#![cfg(test)]
+
+use crate::indexed::indexed_storage::BountyStatus;
+use crate::indexed::{count_bounties_by_status, get_bounty_stats};
+use crate::test_setup::EscrowFixture;
+use crate::*;
+use soroban_sdk::testutils::Ledger;
+
+/// Locks 10_000 and streams it over 1_000 seconds with a 100 second cliff,
+/// starting at t = 1_000.
+fn start_stream(setup: &EscrowFixture, bounty_id: u64) -> VestingStream {
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &bounty_id, &10_000, &100_000);
+    setup
+        .escrow
+        .start_vested_release(&bounty_id, &setup.contributor, &100, &1_000)
+}
+
+fn streaming_count(setup: &EscrowFixture) -> u32 {
+    setup.env.as_contract(&setup.escrow.address, || {
+        assert_eq!(
+            get_bounty_stats(&setup.env).streaming_count,
+            count_bounties_by_status(&setup.env, BountyStatus::Streaming)
+        );
+        count_bounties_by_status(&setup.env, BountyStatus::Streaming)
+    })
+}
+
+#[test]
+fn test_stream_vests_linearly_after_cliff() {
+    let setup = EscrowFixture::at(1_000);
+    let stream = start_stream(&setup, 1);
+    assert_eq!(stream.total, 10_000);
+    assert_eq!(stream.cliff, 1_100);
+    assert_eq!(stream.end, 2_000);
+    assert_eq!(
+        setup.escrow.get_escrow_info(&1).status,
+        EscrowStatus::Streaming
+    );
+    assert_eq!(streaming_count(&setup), 1);
+
+    // Before the cliff nothing is withdrawable
+    setup.env.ledger().set_timestamp(1_099);
+    assert_eq!(setup.escrow.get_withdrawable(&1), 0);
+    assert_eq!(setup.escrow.withdraw_vested(&1), 0);
+
+    // At the cliff the elapsed share vests at once
+    setup.env.ledger().set_timestamp(1_100);
+    assert_eq!(setup.escrow.get_withdrawable(&1), 1_000);
+
+    setup.env.ledger().set_timestamp(1_500);
+    assert_eq!(setup.escrow.withdraw_vested(&1), 5_000);
+    assert_eq!(setup.token.balance(&setup.contributor), 5_000);
+    assert_eq!(setup.escrow.get_escrow_info(&1).remaining_amount, 5_000);
+    assert_eq!(setup.escrow.get_withdrawable(&1), 0);
+
+    setup.env.ledger().set_timestamp(5_000);
+    assert_eq!(setup.escrow.withdraw_vested(&1), 5_000);
+    assert_eq!(setup.token.balance(&setup.contributor), 10_000);
+
+    let info = setup.escrow.get_escrow_info(&1);
+    assert_eq!(info.status, EscrowStatus::Released);
+    assert_eq!(info.remaining_amount, 0);
+    assert_eq!(streaming_count(&setup), 0);
+    let stats = setup.escrow.get_contributor_stats(&setup.contributor);
+    assert_eq!(stats.total_earned, 10_000);
+    // Two withdrawals, one completed payout
+    assert_eq!(stats.payout_count, 1);
+}
+
+#[test]
+fn test_release_recorded_once_when_stream_completes() {
+    let setup = EscrowFixture::at(1_000);
+    start_stream(&setup, 1);
+
+    setup.env.ledger().set_timestamp(1_500);
+    setup.escrow.withdraw_vested(&1);
+    let stats = setup.escrow.get_contributor_stats(&setup.contributor);
+    assert_eq!(stats.payout_count, 0);
+    assert_eq!(stats.total_earned, 0);
+
+    setup.env.ledger().set_timestamp(1_750);
+    setup.escrow.withdraw_vested(&1);
+    setup.env.ledger().set_timestamp(2_000);
+    setup.escrow.withdraw_vested(&1);
+    let stats = setup.escrow.get_contributor_stats(&setup.contributor);
+    assert_eq!(stats.payout_count, 1);
+    assert_eq!(stats.total_earned, 10_000);
+}
+
+#[test]
+fn test_cancel_after_full_withdrawal_rejected() {
+    let setup = EscrowFixture::at(1_000);
+    start_stream(&setup, 1);
+
+    setup.env.ledger().set_timestamp(2_000);
+    setup.escrow.withdraw_vested(&1);
+    assert_eq!(
+        setup.escrow.try_cancel_vested_release(&1, &setup.depositor),
+        Err(Ok(Error::FundsNotLocked))
+    );
+
+    // The completed stream is still counted as a single release
+    let stats = setup.escrow.get_contributor_stats(&setup.contributor);
+    assert_eq!(stats.payout_count, 1);
+    assert_eq!(stats.total_earned, 10_000);
+    let earners = setup.escrow.get_top_earners(&0, &10);
+    assert_eq!(earners.len(), 1);
+    assert_eq!(earners.get(0).unwrap().total_earned, 10_000);
+}
+
+#[test]
+fn test_cancel_blocked_while_paused() {
+    let setup = EscrowFixture::at(1_000);
+    start_stream(&setup, 1);
+
+    setup.escrow.pause();
+    assert_eq!(
+        setup.escrow.try_cancel_vested_release(&1, &setup.depositor),
+        Err(Ok(Error::ContractPaused))
+    );
+    setup.escrow.unpause();
+    assert_eq!(
+        setup.escrow.cancel_vested_release(&1, &setup.depositor),
+        10_000
+    );
+}
+
+#[test]
+fn test_depositor_cancel_refunds_unvested_part() {
+    let setup = EscrowFixture::at(1_000);
+    start_stream(&setup, 1);
+
+    setup.env.ledger().set_timestamp(1_250);
+    assert_eq!(
+        setup.escrow.cancel_vested_release(&1, &setup.depositor),
+        7_500
+    );
+    assert_eq!(setup.token.balance(&setup.depositor), 1_000_000 - 2_500);
+
+    // The vested part stays withdrawable after cancellation
+    let stream = setup.escrow.get_vesting_stream(&1).unwrap();
+    assert!(stream.cancelled);
+    assert_eq!(stream.total, 2_500);
+    assert_eq!(
+        setup.escrow.get_escrow_info(&1).status,
+        EscrowStatus::Streaming
+    );
+
+    setup.env.ledger().set_timestamp(9_000);
+    assert_eq!(setup.escrow.withdraw_vested(&1), 2_500);
+    assert_eq!(
+        setup.escrow.get_escrow_info(&1).status,
+        EscrowStatus::Released
+    );
+    assert_eq!(
+        setup.escrow.try_cancel_vested_release(&1, &setup.admin),
+        Err(Ok(Error::FundsNotLocked))
+    );
+}
+
+#[test]
+fn test_cancel_before_cliff_refunds_everything() {
+    let setup = EscrowFixture::at(1_000);
+    start_stream(&setup, 1);
+
+    setup.env.ledger().set_timestamp(1_050);
+    assert_eq!(setup.escrow.cancel_vested_release(&1, &setup.admin), 10_000);
+    assert_eq!(setup.token.balance(&setup.depositor), 1_000_000);
+
+    let info = setup.escrow.get_escrow_info(&1);
+    assert_eq!(info.status, EscrowStatus::Refunded);
+    assert_eq!(info.refund_history.len(), 1);
+    assert_eq!(setup.escrow.get_withdrawable(&1), 0);
+}
+
+#[test]
+fn test_only_depositor_or_admin_can_cancel() {
+    let setup = EscrowFixture::at(1_000);
+    start_stream(&setup, 1);
+
+    assert_eq!(
+        setup
+            .escrow
+            .try_cancel_vested_release(&1, &setup.contributor),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        setup.escrow.try_cancel_vested_release(&2, &setup.depositor),
+        Err(Ok(Error::BountyNotFound))
+    );
+}
+
+#[test]
+fn test_stream_validation_and_queries() {
+    let setup = EscrowFixture::at(1_000);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &10_000, &100_000);
+
+    assert_eq!(
+        setup
+            .escrow
+            .try_start_vested_release(&1, &setup.contributor, &0, &0),
+        Err(Ok(Error::InvalidDeadline))
+    );
+    assert_eq!(
+        setup
+            .escrow
+            .try_start_vested_release(&1, &setup.contributor, &2_000, &1_000),
+        Err(Ok(Error::InvalidDeadline))
+    );
+
+    setup
+        .escrow
+        .start_vested_release(&1, &setup.contributor, &0, &1_000);
+    setup.env.ledger().set_timestamp(1_061);
+    assert_eq!(
+        setup.escrow.try_release_funds(&1, &setup.contributor),
+        Err(Ok(Error::FundsNotLocked))
+    );
+
+    let streams = setup.escrow.get_vesting_streams(&setup.contributor);
+    assert_eq!(streams.len(), 1);
+    assert_eq!(streams.get(0).unwrap().bounty_id, 1);
+    assert_eq!(streaming_count(&setup), 1);
+
+    let report = setup.escrow.get_solvency_report();
+    assert_eq!(report.escrowed, 10_000);
+    assert!(report.is_solvent);
+}
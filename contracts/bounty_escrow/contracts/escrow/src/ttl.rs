@@ -0,0 +1,191 @@
+//! # Storage TTL Policy Module
+//!
+//! Keeps escrow records alive while they are in use and lets finished ones
+//! be compacted so they stop paying rent for data nobody needs on-chain.
+//!
+//! ## Lifecycle
+//!
+//! ```text
+//! lock / release / refund / metadata update
+//!        │
+//!        ▼
+//! extend_escrow_ttl() / extend_active_key_ttl() ── Escrow, EscrowMetadata, RefundApproval
+//! extend_instance_ttl() ── contract instance (once per call)
+//!        │
+//!        ▼ (Released / Refunded)
+//! finalize_and_compact() ── EscrowReceipt (amounts, recipients, history hash)
+//! ```
+//!
+//! TTL values are expressed in ledgers (~5 seconds each).
+
+use crate::indexed::indexed_storage::get_release_recipient;
+use crate::{DataKey, Escrow, EscrowMetadata, EscrowStatus};
+use soroban_sdk::{contracttype, xdr::ToXdr, Address, Bytes, BytesN, Env, Vec};
+
+/// Approximate number of ledgers per day.
+pub const DAY_IN_LEDGERS: u32 = 17_280;
+
+/// Default threshold below which an active escrow's TTL is extended (30 days).
+pub const DEFAULT_ACTIVE_THRESHOLD: u32 = 30 * DAY_IN_LEDGERS;
+
+/// Default TTL an active escrow is extended to (120 days).
+pub const DEFAULT_ACTIVE_EXTEND_TO: u32 = 120 * DAY_IN_LEDGERS;
+
+/// Default TTL a compacted receipt is extended to (30 days).
+pub const DEFAULT_RECEIPT_EXTEND_TO: u32 = 30 * DAY_IN_LEDGERS;
+
+/// TTL policy applied to escrow storage entries.
+///
+/// # Fields
+/// * `active_threshold` - Extend once remaining TTL drops below this many ledgers
+/// * `active_extend_to` - TTL (in ledgers) given to active escrow entries
+/// * `receipt_extend_to` - TTL (in ledgers) given to compacted receipts
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TtlPolicy {
+    pub active_threshold: u32,
+    pub active_extend_to: u32,
+    pub receipt_extend_to: u32,
+}
+
+/// Compact record kept in place of a finalized escrow.
+///
+/// # Fields
+/// * `bounty_id` - The bounty this receipt belongs to
+/// * `depositor` - Address that originally locked the funds
+/// * `status` - Final status (`Released` or `Refunded`)
+/// * `amount` - Amount originally held in escrow
+/// * `released_amount` - Total paid out to contributors
+/// * `refunded_amount` - Total returned through refunds
+/// * `release_recipient` - Contributor the escrow was released to, if any
+/// * `recipients` - Every address that received funds from this escrow
+/// * `history_hash` - SHA-256 of the XDR of the escrow record and its metadata
+/// * `finalized_at` - Timestamp of compaction
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowReceipt {
+    pub bounty_id: u64,
+    pub depositor: Address,
+    pub status: EscrowStatus,
+    pub amount: i128,
+    pub released_amount: i128,
+    pub refunded_amount: i128,
+    pub release_recipient: Option<Address>,
+    pub recipients: Vec<Address>,
+    pub history_hash: BytesN<32>,
+    pub finalized_at: u64,
+}
+
+pub fn get_policy(env: &Env) -> TtlPolicy {
+    env.storage()
+        .instance()
+        .get(&DataKey::TtlPolicy)
+        .unwrap_or(TtlPolicy {
+            active_threshold: DEFAULT_ACTIVE_THRESHOLD,
+            active_extend_to: DEFAULT_ACTIVE_EXTEND_TO,
+            receipt_extend_to: DEFAULT_RECEIPT_EXTEND_TO,
+        })
+}
+
+pub fn set_policy(env: &Env, policy: &TtlPolicy) {
+    env.storage().instance().set(&DataKey::TtlPolicy, policy);
+}
+
+/// Extends the TTL of an active escrow's main record.
+///
+/// Called right after the record is written, so no existence check is needed.
+pub fn extend_escrow_ttl(env: &Env, bounty_id: u64) {
+    extend_active_key_ttl(env, &DataKey::Escrow(bounty_id));
+}
+
+/// Extends the TTL of a single escrow-related persistent entry.
+pub fn extend_active_key_ttl(env: &Env, key: &DataKey) {
+    let policy = get_policy(env);
+    env.storage()
+        .persistent()
+        .extend_ttl(key, policy.active_threshold, policy.active_extend_to);
+}
+
+/// Extends the TTL of the contract instance (admin, token and configuration).
+pub fn extend_instance_ttl(env: &Env) {
+    let policy = get_policy(env);
+    env.storage()
+        .instance()
+        .extend_ttl(policy.active_threshold, policy.active_extend_to);
+}
+
+/// Extends every storage entry belonging to an active escrow, along with the
+/// contract instance it depends on.
+pub fn extend_all_escrow_ttl(env: &Env, bounty_id: u64) {
+    let storage = env.storage().persistent();
+    let keys = [
+        DataKey::Escrow(bounty_id),
+        DataKey::EscrowMetadata(bounty_id),
+        DataKey::RefundApproval(bounty_id),
+    ];
+    for key in keys.iter() {
+        if storage.has(key) {
+            extend_active_key_ttl(env, key);
+        }
+    }
+    extend_instance_ttl(env);
+}
+
+/// Extends the TTL of a compacted receipt.
+pub fn extend_receipt_ttl(env: &Env, bounty_id: u64) {
+    let policy = get_policy(env);
+    env.storage().persistent().extend_ttl(
+        &DataKey::EscrowReceipt(bounty_id),
+        policy.receipt_extend_to,
+        policy.receipt_extend_to,
+    );
+}
+
+/// Builds the receipt that replaces a finalized escrow.
+pub fn build_receipt(
+    env: &Env,
+    bounty_id: u64,
+    escrow: &Escrow,
+    metadata: &Option<EscrowMetadata>,
+) -> EscrowReceipt {
+    let mut refunded_amount = 0i128;
+    let mut recipients: Vec<Address> = Vec::new(env);
+    for record in escrow.refund_history.iter() {
+        refunded_amount += record.amount;
+        if !recipients.contains(&record.recipient) {
+            recipients.push_back(record.recipient);
+        }
+    }
+
+    let release_recipient = get_release_recipient(env, bounty_id);
+    if let Some(recipient) = release_recipient.clone() {
+        if !recipients.contains(&recipient) {
+            recipients.push_back(recipient);
+        }
+    }
+
+    let released_amount = if escrow.status == EscrowStatus::Released {
+        escrow.amount - refunded_amount
+    } else {
+        0
+    };
+
+    let mut history = Bytes::new(env);
+    history.append(&escrow.clone().to_xdr(env));
+    if let Some(metadata) = metadata {
+        history.append(&metadata.clone().to_xdr(env));
+    }
+
+    EscrowReceipt {
+        bounty_id,
+        depositor: escrow.depositor.clone(),
+        status: escrow.status.clone(),
+        amount: escrow.amount,
+        released_amount,
+        refunded_amount,
+        release_recipient,
+        recipients,
+        history_hash: env.crypto().sha256(&history).to_bytes(),
+        finalized_at: env.ledger().timestamp(),
+    }
+}
@@ -0,0 +1,206 @@
+//! # Vested Release Module
+//!
+//! Pays a bounty out over time instead of in one transfer. Starting a stream
+//! turns the escrow's `remaining_amount` into a linear schedule with an
+//! optional cliff; the contributor withdraws whatever has accrued.
+//!
+//! ## Schedule
+//!
+//! ```text
+//! amount
+//!   │                        ┌──────── total
+//!   │                     ╱
+//!   │                  ╱
+//!   │               ╱
+//!   │            │
+//!   │            │  nothing vests before the cliff
+//!   └────────────┴──────────┴───────► time
+//!   start       cliff       end
+//! ```
+//!
+//! Cancelling a stream (depositor or admin) freezes it at the amount vested
+//! so far: the unvested part is refunded and the contributor can still
+//! withdraw what had accrued.
+
+use crate::events::legacy_events_enabled;
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Vec};
+
+// ============================================================================
+// Data Structures
+// ============================================================================
+
+/// Linear payout schedule for a bounty
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingStream {
+    pub bounty_id: u64,
+    pub recipient: Address,
+    pub depositor: Address,
+    /// Gross amount the stream pays out in total (reduced on cancel)
+    pub total: i128,
+    /// Gross amount already withdrawn
+    pub withdrawn: i128,
+    /// Net amount paid to the recipient after release fees
+    pub paid: i128,
+    pub start: u64,
+    /// Nothing is withdrawable before this time
+    pub cliff: u64,
+    /// Time at which `total` is fully vested
+    pub end: u64,
+    pub cancelled: bool,
+}
+
+impl VestingStream {
+    /// Gross amount vested at `now`
+    pub fn vested_at(&self, now: u64) -> i128 {
+        if now >= self.end {
+            return self.total;
+        }
+        if now < self.cliff {
+            return 0;
+        }
+        let elapsed = (now - self.start) as i128;
+        let duration = (self.end - self.start) as i128;
+        self.total * elapsed / duration
+    }
+
+    /// Vested amount not yet withdrawn
+    pub fn withdrawable_at(&self, now: u64) -> i128 {
+        self.vested_at(now) - self.withdrawn
+    }
+
+    /// Amount the contract still owes the recipient, vested or not
+    pub fn outstanding(&self) -> i128 {
+        self.total - self.withdrawn
+    }
+}
+
+/// Event emitted when an escrow is converted into a stream
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct StreamStarted {
+    pub bounty_id: u64,
+    pub recipient: Address,
+    pub total: i128,
+    pub start: u64,
+    pub cliff: u64,
+    pub end: u64,
+}
+
+/// Event emitted when the recipient withdraws accrued funds
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct StreamWithdrawn {
+    pub bounty_id: u64,
+    pub recipient: Address,
+    pub amount: i128,
+    pub fee: i128,
+    pub withdrawn: i128,
+    pub timestamp: u64,
+}
+
+/// Event emitted when a stream is cancelled and its unvested part refunded
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct StreamCancelled {
+    pub bounty_id: u64,
+    pub cancelled_by: Address,
+    pub vested: i128,
+    pub refunded: i128,
+    pub timestamp: u64,
+}
+
+// ============================================================================
+// Storage
+// ============================================================================
+
+pub fn get_stream(env: &Env, bounty_id: u64) -> Option<VestingStream> {
+    env.storage()
+        .persistent()
+        .get(&(symbol_short!("stream"), bounty_id))
+}
+
+pub fn set_stream(env: &Env, stream: &VestingStream) {
+    env.storage()
+        .persistent()
+        .set(&(symbol_short!("stream"), stream.bounty_id), stream);
+}
+
+/// Bounty ids streamed to the address
+pub fn get_recipient_stream_ids(env: &Env, recipient: &Address) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&(symbol_short!("strm_addr"), recipient.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Streams paying out to the address, including finished ones
+pub fn get_recipient_streams(env: &Env, recipient: &Address) -> Vec<VestingStream> {
+    let mut streams = Vec::new(env);
+    for bounty_id in get_recipient_stream_ids(env, recipient).iter() {
+        if let Some(stream) = get_stream(env, bounty_id) {
+            streams.push_back(stream);
+        }
+    }
+    streams
+}
+
+/// Stores a new stream, indexes it under its recipient and emits the legacy
+/// `StreamStarted` when enabled
+pub fn start_stream(env: &Env, stream: &VestingStream) {
+    set_stream(env, stream);
+
+    let mut ids = get_recipient_stream_ids(env, &stream.recipient);
+    ids.push_back(stream.bounty_id);
+    env.storage().persistent().set(
+        &(symbol_short!("strm_addr"), stream.recipient.clone()),
+        &ids,
+    );
+
+    if legacy_events_enabled(env) {
+        env.events().publish(
+            (symbol_short!("stream"), symbol_short!("start")),
+            StreamStarted {
+                bounty_id: stream.bounty_id,
+                recipient: stream.recipient.clone(),
+                total: stream.total,
+                start: stream.start,
+                cliff: stream.cliff,
+                end: stream.end,
+            },
+        );
+    }
+}
+
+pub fn emit_withdrawn(env: &Env, stream: &VestingStream, amount: i128, fee: i128) {
+    if !legacy_events_enabled(env) {
+        return;
+    }
+    env.events().publish(
+        (symbol_short!("stream"), symbol_short!("withdraw")),
+        StreamWithdrawn {
+            bounty_id: stream.bounty_id,
+            recipient: stream.recipient.clone(),
+            amount,
+            fee,
+            withdrawn: stream.withdrawn,
+            timestamp: env.ledger().timestamp(),
+        },
+    );
+}
+
+pub fn emit_cancelled(env: &Env, stream: &VestingStream, cancelled_by: &Address, refunded: i128) {
+    if !legacy_events_enabled(env) {
+        return;
+    }
+    env.events().publish(
+        (symbol_short!("stream"), symbol_short!("cancel")),
+        StreamCancelled {
+            bounty_id: stream.bounty_id,
+            cancelled_by: cancelled_by.clone(),
+            vested: stream.total,
+            refunded,
+            timestamp: env.ledger().timestamp(),
+        },
+    );
+}
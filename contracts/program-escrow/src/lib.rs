@@ -61,7 +61,7 @@
 //! │  │  - total_funds                           │                  │
 //! │  │  - remaining_balance                     │                  │
 //! │  │  - authorized_payout_key                 │                  │
-//! │  │  - payout_history: [PayoutRecord]        │                  │
+//! │  │  - payout_count (records stored apart)   │                  │
 //! │  │  - token_address                         │                  │
 //! │  └──────────────────────────────────────────┘                  │
 //! └─────────────────────────────────────────────────────────────────┘
@@ -118,7 +118,7 @@
 //!     2_000_0000000,  // 3rd place: 2,000 USDC
 //! ];
 //!
-//! escrow_client.batch_payout(&winners, &prizes);
+//! escrow_client.batch_payout(&program_id, &None, &winners, &prizes);
 //! ```
 //!
 //! ## Event System
@@ -150,8 +150,8 @@ mod pause_tests;
 use security::reentrancy_guard::{ReentrancyGuard, ReentrancyGuardRAII};
 
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, symbol_short, token, vec, Address, Env,
-    String, Symbol, Vec,
+    contract, contracterror, contractimpl, contracttype, symbol_short, token, vec, Address, BytesN,
+    Env, IntoVal, String, Symbol, TryFromVal, Val, Vec,
 };
 
 // Event types
@@ -162,7 +162,6 @@ const BATCH_PAYOUT: Symbol = symbol_short!("BatchPay");
 const PAYOUT: Symbol = symbol_short!("Payout");
 
 // Storage keys
-const PROGRAM_DATA: Symbol = symbol_short!("ProgData");
 const FEE_CONFIG: Symbol = symbol_short!("FeeCfg");
 
 // Fee rate is stored in basis points (1 basis point = 0.01%)
@@ -536,6 +535,41 @@ mod anti_abuse {
     }
 }
 
+// ==================== MERKLE PROOF MODULE ====================
+mod merkle {
+    use soroban_sdk::{xdr::ToXdr, Address, Bytes, BytesN, Env, Vec};
+
+    /// Leaf of a distribution tree: `sha256(index || xdr(recipient) || amount)`
+    /// with `index` and `amount` big-endian.
+    pub fn leaf_hash(env: &Env, index: u32, recipient: &Address, amount: i128) -> BytesN<32> {
+        let mut data = Bytes::from_array(env, &index.to_be_bytes());
+        data.append(&recipient.clone().to_xdr(env));
+        data.extend_from_array(&amount.to_be_bytes());
+        env.crypto().sha256(&data).to_bytes()
+    }
+
+    /// Parent of two nodes; pairs are sorted so proofs need no direction bits.
+    pub fn node_hash(env: &Env, a: &BytesN<32>, b: &BytesN<32>) -> BytesN<32> {
+        let (first, second) = if a <= b { (a, b) } else { (b, a) };
+        let mut data = Bytes::from_array(env, &first.to_array());
+        data.extend_from_array(&second.to_array());
+        env.crypto().sha256(&data).to_bytes()
+    }
+
+    pub fn verify(
+        env: &Env,
+        root: &BytesN<32>,
+        leaf: BytesN<32>,
+        proof: &Vec<BytesN<32>>,
+    ) -> bool {
+        let mut computed = leaf;
+        for sibling in proof.iter() {
+            computed = node_hash(env, &computed, &sibling);
+        }
+        computed == *root
+    }
+}
+
 // ============================================================================
 // Event Types
 // ============================================================================
@@ -547,9 +581,19 @@ const PROGRAM_REGISTERED: Symbol = symbol_short!("ProgReg");
 // Storage Keys
 // ============================================================================
 
-/// Storage key for the program registry (list of all program IDs)
+/// Instance storage key of the legacy program registry (list of all program
+/// IDs). Registered programs now live under `DataKey::ProgramIdAt`; this list
+/// only holds programs still awaiting `migrate_legacy_programs`.
 const PROGRAM_REGISTRY: Symbol = symbol_short!("ProgReg");
 
+/// Maximum page size of payout history queries
+const MAX_PAYOUT_PAGE: u32 = 100;
+
+/// Remaining TTL (in ledgers) below which program entries are extended
+const PROGRAM_TTL_THRESHOLD: u32 = 17_280; // ~1 day
+/// TTL (in ledgers) program entries are extended to
+const PROGRAM_TTL_EXTEND_TO: u32 = 518_400; // ~30 days
+
 // ============================================================================
 // Data Structures
 // ============================================================================
@@ -628,6 +672,55 @@ pub struct ProgramReleaseSchedule {
     pub released_by: Option<Address>,
 }
 
+/// Vesting release schedule for grant-style programs.
+///
+/// Nothing vests before `cliff_timestamp`; from then on the amount vests
+/// linearly from `start_timestamp` to `end_timestamp`, or in whole
+/// `tranche_period` steps when that is non-zero. The recipient withdraws
+/// whatever has vested so far with `withdraw_vested_release`.
+///
+/// # Fields
+/// * `schedule_id` - Shares the program's schedule ID sequence
+/// * `total_amount` - Amount vesting; lowered to the vested amount on revocation
+/// * `withdrawn` - Amount already withdrawn by the recipient
+/// * `revoked_at` - Timestamp the organizer revoked the unvested remainder
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProgramVestingSchedule {
+    pub schedule_id: u64,
+    pub recipient: Address,
+    pub total_amount: i128,
+    pub start_timestamp: u64,
+    pub cliff_timestamp: u64,
+    pub end_timestamp: u64,
+    pub tranche_period: u64,
+    pub withdrawn: i128,
+    pub revoked_at: Option<u64>,
+}
+
+impl ProgramVestingSchedule {
+    /// Amount vested at `now`, regardless of what was withdrawn
+    pub fn vested_at(&self, now: u64) -> i128 {
+        if self.revoked_at.is_some() || now >= self.end_timestamp {
+            return self.total_amount;
+        }
+        if now < self.cliff_timestamp {
+            return 0;
+        }
+        let mut elapsed = now - self.start_timestamp;
+        if self.tranche_period > 0 {
+            elapsed -= elapsed % self.tranche_period;
+        }
+        let duration = (self.end_timestamp - self.start_timestamp) as i128;
+        self.total_amount * elapsed as i128 / duration
+    }
+
+    /// Amount still reserved from the program balance for this schedule
+    pub fn outstanding(&self) -> i128 {
+        self.total_amount - self.withdrawn
+    }
+}
+
 /// History record for executed program release schedules.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -647,6 +740,7 @@ pub struct ProgramReleaseHistory {
 pub enum ReleaseType {
     Automatic, // Released automatically after timestamp
     Manual,    // Released manually by authorized party
+    Vesting,   // Vested amount withdrawn by the recipient
 }
 
 /// Event emitted when a program release schedule is created.
@@ -674,6 +768,85 @@ pub struct ProgramScheduleReleased {
     pub release_type: ReleaseType,
 }
 
+/// Event emitted when an unreleased program release schedule is changed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProgramScheduleUpdated {
+    pub program_id: String,
+    pub schedule_id: u64,
+    pub old_amount: i128,
+    pub amount: i128,
+    pub release_timestamp: u64,
+    pub recipient: Address,
+    pub updated_by: Address,
+}
+
+/// Event emitted when an unreleased program release schedule is cancelled.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProgramScheduleCancelled {
+    pub program_id: String,
+    pub schedule_id: u64,
+    pub amount: i128,
+    pub recipient: Address,
+    pub cancelled_by: Address,
+}
+
+/// Lifecycle state of a program.
+///
+/// ```text
+/// Draft ──► Open ──► Judging ──► Payout ──► Closed
+///   │        │          │
+///   └────────┴──────────┴──► Cancelled
+/// ```
+///
+/// * `Draft` - Registered and fundable, no payouts yet
+/// * `Open` - Running and accepting funds
+/// * `Judging` - Submissions closed, winners being decided
+/// * `Payout` - Prizes being distributed
+/// * `Closed` - Finished; unspent funds were refunded
+/// * `Cancelled` - Abandoned; unspent funds were refunded
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ProgramStatus {
+    Draft,
+    Open,
+    Judging,
+    Payout,
+    Closed,
+    Cancelled,
+}
+
+impl ProgramStatus {
+    /// Whether funds can still be locked into the program
+    pub fn accepts_funds(&self) -> bool {
+        !matches!(self, ProgramStatus::Closed | ProgramStatus::Cancelled)
+    }
+
+    /// Whether payouts, and deadline expiry, are allowed
+    pub fn allows_payouts(&self) -> bool {
+        matches!(
+            self,
+            ProgramStatus::Open | ProgramStatus::Judging | ProgramStatus::Payout
+        )
+    }
+
+    /// Whether the organizer or admin may move the program to `next`
+    pub fn can_transition_to(&self, next: ProgramStatus) -> bool {
+        matches!(
+            (self, next),
+            (ProgramStatus::Draft, ProgramStatus::Open)
+                | (ProgramStatus::Open, ProgramStatus::Judging)
+                | (ProgramStatus::Judging, ProgramStatus::Open)
+                | (ProgramStatus::Judging, ProgramStatus::Payout)
+                | (ProgramStatus::Payout, ProgramStatus::Closed)
+                | (ProgramStatus::Draft, ProgramStatus::Cancelled)
+                | (ProgramStatus::Open, ProgramStatus::Cancelled)
+                | (ProgramStatus::Judging, ProgramStatus::Cancelled)
+        )
+    }
+}
+
 /// Complete program state and configuration.
 ///
 /// # Fields
@@ -681,17 +854,23 @@ pub struct ProgramScheduleReleased {
 /// * `total_funds` - Total amount of funds locked (cumulative)
 /// * `remaining_balance` - Current available balance for payouts
 /// * `authorized_payout_key` - Address authorized to trigger payouts
-/// * `payout_history` - Complete record of all payouts
+/// * `payout_count` - Number of payouts recorded (see `get_payout_history`)
 /// * `token_address` - Token contract used for transfers
+/// * `status` - Current lifecycle state
+/// * `reserved_for_schedules` - Part of `remaining_balance` owed to unreleased
+///   release schedules and unwithdrawn vesting schedules
 ///
 /// # Storage
-/// Stored in instance storage with key `PROGRAM_DATA`.
+/// Stored in persistent storage with key `DataKey::Program(program_id)`.
+/// Payout records are stored separately under `DataKey::Payout(program_id, index)`
+/// so the program entry stays small no matter how many payouts it makes.
 ///
 /// # Invariants
 /// - `remaining_balance <= total_funds` (always)
-/// - `remaining_balance = total_funds - sum(payout_history.amounts)`
-/// - `payout_history` is append-only
-/// - `program_id` and `authorized_payout_key` are immutable after init
+/// - `remaining_balance = total_funds - sum(payout amounts)`
+/// - `reserved_for_schedules <= remaining_balance`
+/// - Payout records are append-only, indexed `0..payout_count`
+/// - `program_id` and `authorized_payout_key` are immutable after registration
 ///
 /// # Example
 /// ```rust
@@ -700,23 +879,74 @@ pub struct ProgramScheduleReleased {
 ///     total_funds: 10_000_0000000,
 ///     remaining_balance: 7_000_0000000,
 ///     authorized_payout_key: backend_address,
-///     payout_history: vec![&env],
+///     payout_count: 0,
 ///     token_address: usdc_token_address,
 /// };
 /// ```
-/// Complete program state and configuration.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProgramData {
+    pub program_id: String,
+    pub total_funds: i128,
+    pub remaining_balance: i128,
+    pub authorized_payout_key: Address,
+    pub payout_count: u32,
+    pub token_address: Address,
+    pub deadline: Option<u64>,
+    pub organizer: Address,
+    pub status: ProgramStatus,
+    pub reserved_for_schedules: i128,
+}
+
+/// A prize track with its own budget inside a program.
+///
+/// # Fields
+/// * `track_id` - Sequential identifier within the program
+/// * `name` - Display name, e.g. "DeFi"
+/// * `funded` - Budget moved into the track, net of amounts moved out
+/// * `paid` - Amount paid out of the track (including payout fees)
+/// * `remaining` - `funded - paid`
 ///
 /// # Storage Key
-/// Stored with key: `("Program", program_id)`
+/// Stored per track with key: `DataKey::Track(program_id, track_id)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProgramTrack {
+    pub track_id: u32,
+    pub name: String,
+    pub funded: i128,
+    pub paid: i128,
+    pub remaining: i128,
+}
+
+/// A Merkle distribution of prizes that winners claim themselves.
 ///
-/// # Invariants
-/// - `remaining_balance <= total_funds` (always)
-/// - `remaining_balance = total_funds - sum(payout_history.amounts)`
-/// - `payout_history` is append-only
-/// - `program_id` and `authorized_payout_key` are immutable after registration
+/// # Fields
+/// * `distribution_id` - Sequential identifier within the program
+/// * `merkle_root` - Root of the tree of `(index, recipient, amount)` leaves
+/// * `total` - Sum of all leaf amounts, reserved from `remaining_balance`
+/// * `claimed` - Amount claimed so far (including payout fees)
+/// * `claim_deadline` - Timestamp after which claims are rejected
+/// * `finalized` - Whether unclaimed funds were returned to the program
+///
+/// # Storage Key
+/// Stored with key: `DataKey::Distribution(program_id, distribution_id)`
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct ProgramData {
+pub struct Distribution {
+    pub distribution_id: u32,
+    pub merkle_root: BytesN<32>,
+    pub total: i128,
+    pub claimed: i128,
+    pub claim_deadline: u64,
+    pub finalized: bool,
+}
+
+/// Program layout used before programs moved to persistent storage, with the
+/// full payout history inline. Only read by `migrate_legacy_programs`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacyProgramData {
     pub program_id: String,
     pub total_funds: i128,
     pub remaining_balance: i128,
@@ -727,6 +957,40 @@ pub struct ProgramData {
     pub organizer: Address,
 }
 
+/// A sponsor's funding of a program.
+///
+/// # Fields
+/// * `sponsor` - Address the funds were pulled from
+/// * `contributed` - Net amount locked by this sponsor (after lock fees)
+/// * `refunded` - Amount returned to this sponsor on expiry or closure
+///
+/// # Storage Key
+/// Stored per program with key: `DataKey::Sponsors(program_id)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SponsorContribution {
+    pub sponsor: Address,
+    pub contributed: i128,
+    pub refunded: i128,
+}
+
+/// Token balance held by the contract compared with what programs account for.
+///
+/// # Fields
+/// * `token_address` - Token contract being reconciled
+/// * `contract_balance` - Tokens actually held by the escrow contract
+/// * `total_remaining` - Sum of `remaining_balance` over programs using this
+///   token, plus what their unfinalized distributions still owe to claimants
+/// * `is_covered` - Whether `contract_balance >= total_remaining`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenReconciliation {
+    pub token_address: Address,
+    pub contract_balance: i128,
+    pub total_remaining: i128,
+    pub is_covered: bool,
+}
+
 /// Storage key type for individual programs
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -739,6 +1003,23 @@ pub enum DataKey {
     ReleaseHistory(String),       // program_id -> Vec<ProgramReleaseHistory>
     NextScheduleId(String),       // program_id -> next schedule_id
     IsPaused,                     // Global contract pause state
+    Sponsors(String),             // program_id -> Vec<SponsorContribution>
+    OrganizerFunds(String),       // program_id -> net amount locked via lock_program_funds
+    ProgramFeeConfig(String),     // program_id -> approved FeeConfig override
+    PendingFeeConfig(String),     // program_id -> FeeConfig awaiting admin approval
+    ProgramCount,                 // Number of registered programs
+    ProgramIdAt(u32),             // registry position -> program_id
+    Payout(String, u32),          // program_id, index -> PayoutRecord
+    RecipientPayoutCount(String, Address), // program_id, recipient -> number of payouts
+    RecipientPayout(String, Address, u32), // program_id, recipient, n -> payout index
+    TrackCount(String),           // program_id -> number of tracks
+    Track(String, u32),           // program_id, track_id -> ProgramTrack
+    VestingSchedule(String, u64), // program_id, schedule_id -> ProgramVestingSchedule
+    DistributionCount(String),    // program_id -> number of distributions
+    Distribution(String, u32),    // program_id, distribution_id -> Distribution
+    DistributionClaimed(String, u32, u32), // program_id, distribution_id, index -> claimed
+    DustRecipient,                // Receives rounding dust of pro-rata refunds
+    LegacyPayoutsMigrated(String),         // program_id -> legacy payout records already copied
 }
 
 // ============================================================================
@@ -760,6 +1041,10 @@ pub struct ProgramEscrowContract;
 // Event symbols for program release schedules
 const PROG_SCHEDULE_CREATED: soroban_sdk::Symbol = soroban_sdk::symbol_short!("prg_sch_c");
 const PROG_SCHEDULE_RELEASED: soroban_sdk::Symbol = soroban_sdk::symbol_short!("prg_sch_r");
+const PROG_SCHEDULE_UPDATED: soroban_sdk::Symbol = soroban_sdk::symbol_short!("prg_sch_u");
+const PROG_SCHEDULE_CANCELLED: soroban_sdk::Symbol = soroban_sdk::symbol_short!("prg_sch_x");
+const PROG_VESTING_CREATED: soroban_sdk::Symbol = soroban_sdk::symbol_short!("prg_vst_c");
+const PROG_VESTING_REVOKED: soroban_sdk::Symbol = soroban_sdk::symbol_short!("prg_vst_x");
 
 #[contractimpl]
 impl ProgramEscrowContract {
@@ -892,12 +1177,9 @@ impl ProgramEscrowContract {
         // Get program data to access token address
         let program_key = DataKey::Program(program_id.clone());
         let program_data: ProgramData =
-            env.storage()
-                .instance()
-                .get(&program_key)
-                .unwrap_or_else(|| {
-                    panic!("Program not found");
-                });
+            Self::load_persistent(&env, &program_key).unwrap_or_else(|| {
+                panic!("Program not found");
+            });
 
         let client = token::Client::new(&env, &program_data.token_address);
         let balance = client.balance(&env.current_contract_address());
@@ -936,7 +1218,9 @@ impl ProgramEscrowContract {
         }
 
         let program_key = DataKey::Program(program_id.clone());
-        if env.storage().instance().has(&program_key) {
+        if env.storage().persistent().has(&program_key)
+            || env.storage().instance().has(&program_key)
+        {
             monitoring::track_operation(&env, symbol_short!("init_prg"), caller, false);
             panic!("Program already exists");
         }
@@ -953,29 +1237,17 @@ impl ProgramEscrowContract {
             total_funds: 0,
             remaining_balance: 0,
             authorized_payout_key: authorized_payout_key.clone(),
-            payout_history: vec![&env],
+            payout_count: 0,
             token_address: token_address.clone(),
             deadline,
             organizer: organizer.clone(),
+            status: ProgramStatus::Draft,
+            reserved_for_schedules: 0,
         };
 
-        let fee_config = FeeConfig {
-            lock_fee_rate: 0,
-            payout_fee_rate: 0,
-            fee_recipient: authorized_payout_key.clone(),
-            fee_enabled: false,
-        };
-        env.storage().instance().set(&FEE_CONFIG, &fee_config);
-
-        env.storage().instance().set(&program_key, &program_data);
+        Self::store_persistent(&env, &program_key, &program_data);
 
-        let mut registry: Vec<String> = env
-            .storage()
-            .instance()
-            .get(&PROGRAM_REGISTRY)
-            .unwrap_or(vec![&env]);
-        registry.push_back(program_id.clone());
-        env.storage().instance().set(&PROGRAM_REGISTRY, &registry);
+        Self::register_program_id(&env, program_id.clone());
 
         env.events().publish(
             (PROGRAM_REGISTERED,),
@@ -1016,6 +1288,82 @@ impl ProgramEscrowContract {
             })
     }
 
+    /// Fee configuration in effect for a program: its approved override if
+    /// any, otherwise the admin defaults.
+    fn get_program_fee_config_internal(env: &Env, program_id: &String) -> FeeConfig {
+        Self::load_persistent(env, &DataKey::ProgramFeeConfig(program_id.clone()))
+            .unwrap_or_else(|| Self::get_fee_config_internal(env))
+    }
+
+    fn validate_fee_rates(lock_fee_rate: i128, payout_fee_rate: i128) {
+        if !(0..=MAX_FEE_RATE).contains(&lock_fee_rate) {
+            panic!(
+                "Invalid lock fee rate: must be between 0 and {}",
+                MAX_FEE_RATE
+            );
+        }
+        if !(0..=MAX_FEE_RATE).contains(&payout_fee_rate) {
+            panic!(
+                "Invalid payout fee rate: must be between 0 and {}",
+                MAX_FEE_RATE
+            );
+        }
+    }
+
+    /// Appends a program ID to the persistent registry.
+    fn register_program_id(env: &Env, program_id: String) {
+        let count: u32 = Self::load_persistent(env, &DataKey::ProgramCount).unwrap_or(0);
+        Self::store_persistent(env, &DataKey::ProgramIdAt(count), &program_id);
+        Self::store_persistent(env, &DataKey::ProgramCount, &(count + 1));
+    }
+
+    /// Extends the TTL of a persistent program entry.
+    fn extend_program_ttl(env: &Env, key: &DataKey) {
+        env.storage()
+            .persistent()
+            .extend_ttl(key, PROGRAM_TTL_THRESHOLD, PROGRAM_TTL_EXTEND_TO);
+    }
+
+    /// Reads a persistent program entry, extending its TTL if it exists.
+    /// Every persistent `DataKey` is read through here so that entries in
+    /// use never expire.
+    fn load_persistent<V: TryFromVal<Env, Val>>(env: &Env, key: &DataKey) -> Option<V> {
+        let value = env.storage().persistent().get(key);
+        if value.is_some() {
+            Self::extend_program_ttl(env, key);
+        }
+        value
+    }
+
+    /// Writes a persistent program entry and extends its TTL.
+    fn store_persistent<V: IntoVal<Env, Val>>(env: &Env, key: &DataKey, value: &V) {
+        env.storage().persistent().set(key, value);
+        Self::extend_program_ttl(env, key);
+    }
+
+    /// Stores a payout record under the next index of the program and
+    /// indexes it by recipient. The caller persists `program_data`.
+    fn record_payout(env: &Env, program_data: &mut ProgramData, record: PayoutRecord) {
+        let program_id = program_data.program_id.clone();
+        let index = program_data.payout_count;
+        let payout_key = DataKey::Payout(program_id.clone(), index);
+        Self::store_persistent(env, &payout_key, &record);
+
+        let count_key = DataKey::RecipientPayoutCount(program_id.clone(), record.recipient.clone());
+        let count: u32 = Self::load_persistent(env, &count_key).unwrap_or(0);
+        let recipient_key = DataKey::RecipientPayout(program_id, record.recipient, count);
+        Self::store_persistent(env, &recipient_key, &index);
+        Self::store_persistent(env, &count_key, &(count + 1));
+
+        program_data.payout_count = index + 1;
+    }
+
+    fn validate_page_limit(limit: u32) {
+        if limit == 0 || limit > MAX_PAYOUT_PAGE {
+            panic!("Invalid page limit: must be between 1 and {}", MAX_PAYOUT_PAGE);
+        }
+    }
+
     /// Lock initial funds into the program escrow
     ///
     /// Lists all registered program IDs in the contract.
@@ -1031,10 +1379,13 @@ impl ProgramEscrowContract {
     /// }
     /// ```
     pub fn list_programs(env: Env) -> Vec<String> {
-        env.storage()
-            .instance()
-            .get(&PROGRAM_REGISTRY)
-            .unwrap_or(vec![&env])
+        let count = Self::get_program_count(env.clone());
+        let mut programs = vec![&env];
+        for i in 0..count {
+            let program_id: String = Self::load_persistent(&env, &DataKey::ProgramIdAt(i)).unwrap();
+            programs.push_back(program_id);
+        }
+        programs
     }
 
     /// Checks if a program exists.
@@ -1046,7 +1397,7 @@ impl ProgramEscrowContract {
     /// * `bool` - True if program exists, false otherwise
     pub fn program_exists(env: Env, program_id: String) -> bool {
         let program_key = DataKey::Program(program_id);
-        env.storage().instance().has(&program_key)
+        env.storage().persistent().has(&program_key)
     }
 
     // ========================================================================
@@ -1083,6 +1434,8 @@ impl ProgramEscrowContract {
     /// - This function doesn't perform the actual token transfer
     /// - Caller is responsible for transferring tokens to contract
     /// - Consider verifying contract balance matches recorded amount
+    ///   (see `reconcile_program_balances`), or use `lock_program_funds_from`
+    ///   which pulls the tokens itself
     /// - Multiple lock operations are additive (cumulative)
     ///
     /// # Events
@@ -1134,44 +1487,119 @@ impl ProgramEscrowContract {
     /// -  Not verifying contract received the tokens
     pub fn lock_program_funds(env: Env, program_id: String, amount: i128) -> ProgramData {
         let _guard = ReentrancyGuardRAII::new(&env).expect("Reentrancy detected");
-        // Apply rate limiting
-        anti_abuse::check_rate_limit(&env, env.current_contract_address());
+        Self::lock_funds_internal(&env, program_id, amount, None)
+    }
 
-        let _start = env.ledger().timestamp();
-        let caller = env.current_contract_address();
+    /// Pulls `amount` from `funder` into the contract and locks it for the program.
+    ///
+    /// Unlike [`lock_program_funds`](Self::lock_program_funds), balances are only
+    /// credited after the token transfer succeeds, so accounting can never run
+    /// ahead of the tokens actually held.
+    ///
+    /// # Arguments
+    /// * `program_id` - The program to fund
+    /// * `funder` - Address the tokens are transferred from
+    /// * `amount` - Gross amount to transfer (lock fee is deducted from it)
+    ///
+    /// # Authorization
+    /// - `funder` must authorize the call
+    ///
+    /// # State Changes
+    /// - Transfers `amount` of the program's token from `funder` to the contract
+    /// - Forwards the lock fee, if any, to the fee recipient
+    /// - Increases `total_funds` and `remaining_balance` by the net amount
+    ///
+    /// # Events
+    /// Emits: `FundsLocked(program_id, net_amount, new_remaining_balance)`
+    ///
+    /// # Panics
+    /// * If the contract is paused, the amount is invalid or the program doesn't exist
+    /// * If `funder` has insufficient token balance
+    pub fn lock_program_funds_from(
+        env: Env,
+        program_id: String,
+        funder: Address,
+        amount: i128,
+    ) -> ProgramData {
+        let _guard = ReentrancyGuardRAII::new(&env).expect("Reentrancy detected");
+        funder.require_auth();
+        Self::lock_funds_internal(&env, program_id, amount, Some(funder))
+    }
+
+    /// Adds `amount` to the sponsor's running contribution for the program.
+    fn record_contribution(env: &Env, program_id: &String, sponsor: Address, amount: i128) {
+        let key = DataKey::Sponsors(program_id.clone());
+        let mut sponsors: Vec<SponsorContribution> =
+            Self::load_persistent(env, &key).unwrap_or(vec![env]);
+
+        let mut found = false;
+        for i in 0..sponsors.len() {
+            let mut entry = sponsors.get(i).unwrap();
+            if entry.sponsor == sponsor {
+                entry.contributed += amount;
+                sponsors.set(i, entry);
+                found = true;
+                break;
+            }
+        }
+        if !found {
+            sponsors.push_back(SponsorContribution {
+                sponsor,
+                contributed: amount,
+                refunded: 0,
+            });
+        }
+        Self::store_persistent(env, &key, &sponsors);
+    }
+
+    /// Shared lock logic. When `funder` is set, tokens are pulled from it
+    /// before any balance is credited.
+    fn lock_funds_internal(
+        env: &Env,
+        program_id: String,
+        amount: i128,
+        funder: Option<Address>,
+    ) -> ProgramData {
+        let caller = funder
+            .clone()
+            .unwrap_or_else(|| env.current_contract_address());
+        // Apply rate limiting
+        anti_abuse::check_rate_limit(env, caller.clone());
 
         // Check if contract is paused
-        if Self::is_paused_internal(&env) {
-            monitoring::track_operation(&env, symbol_short!("lock"), caller.clone(), false);
+        if Self::is_paused_internal(env) {
+            monitoring::track_operation(env, symbol_short!("lock"), caller.clone(), false);
             panic!("Contract is paused");
         }
 
         // Validate amount
         if amount <= 0 {
-            monitoring::track_operation(&env, symbol_short!("lock"), caller.clone(), false);
+            monitoring::track_operation(env, symbol_short!("lock"), caller.clone(), false);
             panic!("Amount must be greater than zero");
         }
 
         // Check amount limits
         let limits = Self::get_amount_limits(env.clone());
         if amount < limits.min_lock_amount || amount > limits.max_lock_amount {
-            monitoring::track_operation(&env, symbol_short!("lock"), caller.clone(), false);
+            monitoring::track_operation(env, symbol_short!("lock"), caller.clone(), false);
             panic!("Amount violates configured limits");
         }
 
         // Get program data
         let program_key = DataKey::Program(program_id.clone());
-        let mut program_data: ProgramData = env
-            .storage()
-            .instance()
-            .get(&program_key)
+        let mut program_data: ProgramData = Self::load_persistent(env, &program_key)
             .unwrap_or_else(|| {
-                monitoring::track_operation(&env, symbol_short!("lock"), caller.clone(), false);
+                monitoring::track_operation(env, symbol_short!("lock"), caller.clone(), false);
                 panic!("Program not found")
             });
 
+        if !program_data.status.accepts_funds() {
+            monitoring::track_operation(env, symbol_short!("lock"), caller.clone(), false);
+            panic!("Program is not accepting funds");
+        }
+
         // Calculate and collect fee if enabled
-        let fee_config = Self::get_fee_config_internal(&env);
+        let fee_config = Self::get_program_fee_config_internal(env, &program_id);
         let fee_amount = if fee_config.fee_enabled && fee_config.lock_fee_rate > 0 {
             Self::calculate_fee(amount, fee_config.lock_fee_rate)
         } else {
@@ -1179,9 +1607,26 @@ impl ProgramEscrowContract {
         };
         let net_amount = amount - fee_amount;
 
+        // Pull the tokens in before crediting anything
+        if let Some(funder) = &funder {
+            let contract_address = env.current_contract_address();
+            let token_client = token::Client::new(env, &program_data.token_address);
+            token_client.transfer(funder, &contract_address, &amount);
+            if fee_amount > 0 {
+                token_client.transfer(&contract_address, &fee_config.fee_recipient, &fee_amount);
+            }
+        }
+
         // Update balances with net amount
         program_data.total_funds += net_amount;
         program_data.remaining_balance += net_amount;
+        if let Some(funder) = funder {
+            Self::record_contribution(env, &program_id, funder, net_amount);
+        } else {
+            let organizer_key = DataKey::OrganizerFunds(program_id.clone());
+            let organizer_funds: i128 = Self::load_persistent(env, &organizer_key).unwrap_or(0);
+            Self::store_persistent(env, &organizer_key, &(organizer_funds + net_amount));
+        }
 
         // Emit fee collected event if applicable
         if fee_amount > 0 {
@@ -1197,7 +1642,7 @@ impl ProgramEscrowContract {
         }
 
         // Store updated data
-        env.storage().instance().set(&program_key, &program_data);
+        Self::store_persistent(env, &program_key, &program_data);
 
         // Emit FundsLocked event (with net amount after fee)
         env.events().publish(
@@ -1213,72 +1658,521 @@ impl ProgramEscrowContract {
     }
 
     // ========================================================================
-    // Payout Functions
+    // Prize Tracks
     // ========================================================================
 
-    /// Executes batch payouts to multiple recipients simultaneously.
+    /// Creates a prize track with an empty budget.
     ///
     /// # Arguments
-    /// * `env` - The contract environment
-    /// * `recipients` - Vector of recipient addresses
-    /// * `amounts` - Vector of amounts (must match recipients length)
+    /// * `program_id` - The program to add the track to
+    /// * `name` - Display name of the track
     ///
     /// # Returns
-    /// * `Ok(ProgramData)` - Updated program data after payouts
-    /// * `Err(Error::Unauthorized)` - Caller is not the authorized payout key
-    /// * `Err(Error::NotInitialized)` - Program not initialized
-    /// * `Err(Error::BatchMismatch)` - Recipients and amounts vectors length mismatch
-    /// * `Err(Error::InvalidAmount)` - Amount is zero or negative
-    /// * `Err(Error::InsufficientBalance)` - Total payout exceeds remaining balance
+    /// * `u32` - The new track's ID
     ///
     /// # Authorization
-    /// - **CRITICAL**: Only authorized payout key can call
-    /// - Caller must be exact match to `authorized_payout_key`
-    ///
-    /// # State Changes
-    /// - Transfers tokens from contract to each recipient
-    /// - Adds PayoutRecord for each transfer to history
-    /// - Decreases `remaining_balance` by total payout amount
-    /// - Emits BatchPayout event
-    ///
-    /// # Atomicity
-    /// This operation is atomic - either all transfers succeed or all fail.
-    /// If any transfer fails, the entire batch is reverted.
-    ///
-    /// # Security Considerations
-    /// - Verify recipient addresses off-chain before calling
-    /// - Ensure amounts match winner rankings/criteria
-    /// - Total payout is calculated with overflow protection
-    /// - Balance check prevents overdraft
-    /// - All transfers are logged for audit trail
-    /// - Consider implementing payout limits for additional safety
+    /// - Requires auth from the program's organizer
     ///
     /// # Events
-    /// Emits: `BatchPayout(program_id, recipient_count, total_amount, new_balance)`
+    /// Emits: `trk_new(program_id, track_id, name)`
+    pub fn create_track(env: Env, program_id: String, name: String) -> u32 {
+        if Self::is_paused_internal(&env) {
+            panic!("Contract is paused");
+        }
+
+        let program_data = Self::get_program_info(env.clone(), program_id.clone());
+        program_data.organizer.require_auth();
+
+        if !program_data.status.accepts_funds() {
+            panic!("Program is not accepting funds");
+        }
+
+        let count_key = DataKey::TrackCount(program_id.clone());
+        let track_id: u32 = Self::load_persistent(&env, &count_key).unwrap_or(0);
+        let track = ProgramTrack {
+            track_id,
+            name: name.clone(),
+            funded: 0,
+            paid: 0,
+            remaining: 0,
+        };
+        Self::store_persistent(&env, &DataKey::Track(program_id.clone(), track_id), &track);
+        Self::store_persistent(&env, &count_key, &(track_id + 1));
+
+        env.events()
+            .publish((symbol_short!("trk_new"),), (program_id, track_id, name));
+
+        track_id
+    }
+
+    /// Moves budget between tracks or between a track and the program's
+    /// unallocated pool (`None`).
     ///
-    /// # Example
-    /// ```rust
-    /// use soroban_sdk::{vec, Address};
+    /// # Arguments
+    /// * `program_id` - The program owning the tracks
+    /// * `from_track` - Source track, or `None` for the unallocated pool
+    /// * `to_track` - Destination track, or `None` for the unallocated pool
+    /// * `amount` - Amount to move
     ///
-    /// // Define winners and prizes
-    /// let winners = vec![
-    ///     &env,
-    ///     Address::from_string("GWINNER1..."), // 1st place
-    ///     Address::from_string("GWINNER2..."), // 2nd place
-    ///     Address::from_string("GWINNER3..."), // 3rd place
-    /// ];
+    /// # Authorization
+    /// - Requires auth from the program's organizer
     ///
-    /// let prizes = vec![
-    ///     &env,
-    ///     5_000_0000000,  // $5,000 USDC
-    ///     3_000_0000000,  // $3,000 USDC
-    ///     2_000_0000000,  // $2,000 USDC
-    /// ];
+    /// # Events
+    /// Emits: `trk_move(program_id, from_track, to_track, amount)`
     ///
-    /// // Execute batch payout (only authorized backend can call)
-    /// let result = escrow_client.batch_payout(&winners, &prizes);
-    /// println!("Paid {} winners", winners.len());
-    /// println!("Remaining: {}", result.remaining_balance);
+    /// # Panics
+    /// * If the contract is paused
+    /// * If the program is closed or cancelled
+    /// * If the source has less than `amount` left
+    pub fn move_track_funds(
+        env: Env,
+        program_id: String,
+        from_track: Option<u32>,
+        to_track: Option<u32>,
+        amount: i128,
+    ) {
+        if Self::is_paused_internal(&env) {
+            panic!("Contract is paused");
+        }
+
+        let program_data = Self::get_program_info(env.clone(), program_id.clone());
+        program_data.organizer.require_auth();
+
+        if !program_data.status.accepts_funds() {
+            panic!("Program is not accepting funds");
+        }
+        if amount <= 0 {
+            panic!("Amount must be greater than zero");
+        }
+        if from_track == to_track {
+            panic!("Source and destination must differ");
+        }
+
+        match from_track {
+            Some(track_id) => {
+                let mut track = Self::get_track(env.clone(), program_id.clone(), track_id);
+                if amount > track.remaining {
+                    panic!("Insufficient track budget");
+                }
+                track.funded -= amount;
+                track.remaining -= amount;
+                Self::store_track(&env, &program_id, &track);
+            }
+            None => {
+                if amount > Self::unallocated_balance(&env, &program_data) {
+                    panic!("Insufficient unallocated balance");
+                }
+            }
+        }
+
+        if let Some(track_id) = to_track {
+            let mut track = Self::get_track(env.clone(), program_id.clone(), track_id);
+            track.funded += amount;
+            track.remaining += amount;
+            Self::store_track(&env, &program_id, &track);
+        }
+
+        env.events().publish(
+            (symbol_short!("trk_move"),),
+            (program_id, from_track, to_track, amount),
+        );
+    }
+
+    /// Returns a single track of a program.
+    pub fn get_track(env: Env, program_id: String, track_id: u32) -> ProgramTrack {
+        Self::load_persistent(&env, &DataKey::Track(program_id, track_id))
+            .unwrap_or_else(|| panic!("Track not found"))
+    }
+
+    /// Returns all tracks of a program with their funded, paid and remaining
+    /// amounts.
+    pub fn get_program_tracks(env: Env, program_id: String) -> Vec<ProgramTrack> {
+        let count: u32 =
+            Self::load_persistent(&env, &DataKey::TrackCount(program_id.clone())).unwrap_or(0);
+
+        let mut tracks = vec![&env];
+        for track_id in 0..count {
+            tracks.push_back(Self::get_track(env.clone(), program_id.clone(), track_id));
+        }
+        tracks
+    }
+
+    /// Returns the part of a program's remaining balance not assigned to any
+    /// track or reserved for release schedules.
+    pub fn get_unallocated_balance(env: Env, program_id: String) -> i128 {
+        let program_data = Self::get_program_info(env.clone(), program_id);
+        Self::unallocated_balance(&env, &program_data)
+    }
+
+    fn unallocated_balance(env: &Env, program_data: &ProgramData) -> i128 {
+        let tracks = Self::get_program_tracks(env.clone(), program_data.program_id.clone());
+        let mut allocated: i128 = 0;
+        for track in tracks.iter() {
+            allocated += track.remaining;
+        }
+        program_data.remaining_balance - program_data.reserved_for_schedules - allocated
+    }
+
+    fn store_track(env: &Env, program_id: &String, track: &ProgramTrack) {
+        Self::store_persistent(
+            env,
+            &DataKey::Track(program_id.clone(), track.track_id),
+            track,
+        );
+    }
+
+    /// Checks that the track (or the unallocated pool) can cover `amount`
+    /// and charges it to the track.
+    fn draw_from_budget(
+        env: &Env,
+        program_data: &ProgramData,
+        track_id: Option<u32>,
+        amount: i128,
+    ) {
+        match track_id {
+            Some(track_id) => {
+                let mut track =
+                    Self::get_track(env.clone(), program_data.program_id.clone(), track_id);
+                if amount > track.remaining {
+                    panic!("Insufficient track budget");
+                }
+                track.paid += amount;
+                track.remaining -= amount;
+                Self::store_track(env, &program_data.program_id, &track);
+            }
+            None => {
+                if amount > Self::unallocated_balance(env, program_data) {
+                    panic!("Insufficient unallocated balance");
+                }
+            }
+        }
+    }
+
+    // ========================================================================
+    // Merkle Distributions
+    // ========================================================================
+
+    /// Publishes a Merkle distribution and reserves its total from the
+    /// program's unallocated balance.
+    ///
+    /// Instead of the payout key paying every winner, each winner claims
+    /// their own leaf with `claim`. Leaves are
+    /// `sha256(index_be || xdr(recipient) || amount_be)` and parents hash
+    /// their two children in sorted order.
+    ///
+    /// # Arguments
+    /// * `program_id` - The program paying the prizes
+    /// * `merkle_root` - Root of the distribution tree
+    /// * `total` - Sum of all leaf amounts
+    /// * `claim_deadline` - Timestamp after which claims close
+    ///
+    /// # Returns
+    /// * `u32` - The new distribution's ID
+    ///
+    /// # Authorization
+    /// - Requires auth from the program's authorized payout key
+    ///
+    /// # Events
+    /// Emits: `dist_pub(program_id, distribution_id, merkle_root, total, claim_deadline)`
+    pub fn publish_distribution(
+        env: Env,
+        program_id: String,
+        merkle_root: BytesN<32>,
+        total: i128,
+        claim_deadline: u64,
+    ) -> u32 {
+        if Self::is_paused_internal(&env) {
+            panic!("Contract is paused");
+        }
+
+        let program_key = DataKey::Program(program_id.clone());
+        let mut program_data = Self::get_program_info(env.clone(), program_id.clone());
+        program_data.authorized_payout_key.require_auth();
+
+        if !program_data.status.allows_payouts() {
+            panic!("Payouts not allowed in current program state");
+        }
+        if total <= 0 {
+            panic!("Amount must be greater than zero");
+        }
+        if claim_deadline <= env.ledger().timestamp() {
+            panic!("Claim deadline must be in the future");
+        }
+        Self::draw_from_budget(&env, &program_data, None, total);
+
+        program_data.remaining_balance -= total;
+        Self::store_persistent(&env, &program_key, &program_data);
+
+        let count_key = DataKey::DistributionCount(program_id.clone());
+        let distribution_id: u32 = Self::load_persistent(&env, &count_key).unwrap_or(0);
+        let distribution = Distribution {
+            distribution_id,
+            merkle_root: merkle_root.clone(),
+            total,
+            claimed: 0,
+            claim_deadline,
+            finalized: false,
+        };
+        Self::store_persistent(
+            &env,
+            &DataKey::Distribution(program_id.clone(), distribution_id),
+            &distribution,
+        );
+        Self::store_persistent(&env, &count_key, &(distribution_id + 1));
+
+        env.events().publish(
+            (symbol_short!("dist_pub"),),
+            (program_id, distribution_id, merkle_root, total, claim_deadline),
+        );
+
+        distribution_id
+    }
+
+    /// Claims a winner's prize from a Merkle distribution.
+    ///
+    /// # Arguments
+    /// * `program_id` - The program paying the prize
+    /// * `distribution_id` - The distribution to claim from
+    /// * `index` - Leaf index of the claim
+    /// * `recipient` - Winner named in the leaf
+    /// * `amount` - Amount named in the leaf
+    /// * `proof` - Sibling hashes from the leaf up to the root
+    ///
+    /// # Authorization
+    /// - Requires auth from `recipient`
+    ///
+    /// # State Changes
+    /// - Transfers `amount` (less the program's payout fee) to `recipient`
+    /// - Adds a PayoutRecord to the program's history
+    ///
+    /// # Events
+    /// Emits: `dist_clm(program_id, distribution_id, index, recipient, net_amount)`
+    ///
+    /// # Panics
+    /// * If the contract is paused or the program is not `Open`, `Judging`
+    ///   or `Payout`
+    /// * If the claim deadline has passed or the leaf was already claimed
+    /// * If the proof does not match the distribution's root
+    pub fn claim(
+        env: Env,
+        program_id: String,
+        distribution_id: u32,
+        index: u32,
+        recipient: Address,
+        amount: i128,
+        proof: Vec<BytesN<32>>,
+    ) -> i128 {
+        let _guard = ReentrancyGuardRAII::new(&env).expect("Reentrancy detected");
+        if Self::is_paused_internal(&env) {
+            panic!("Contract is paused");
+        }
+        recipient.require_auth();
+
+        let program_key = DataKey::Program(program_id.clone());
+        let mut program_data = Self::get_program_info(env.clone(), program_id.clone());
+        if !program_data.status.allows_payouts() {
+            panic!("Payouts not allowed in current program state");
+        }
+
+        let mut distribution =
+            Self::get_distribution(env.clone(), program_id.clone(), distribution_id);
+        if distribution.finalized || env.ledger().timestamp() > distribution.claim_deadline {
+            panic!("Claim period has ended");
+        }
+
+        let claimed_key = DataKey::DistributionClaimed(program_id.clone(), distribution_id, index);
+        if env.storage().persistent().has(&claimed_key) {
+            panic!("Already claimed");
+        }
+
+        let leaf = merkle::leaf_hash(&env, index, &recipient, amount);
+        if !merkle::verify(&env, &distribution.merkle_root, leaf, &proof) {
+            panic!("Invalid Merkle proof");
+        }
+        if amount <= 0 || distribution.claimed + amount > distribution.total {
+            panic!("Claim exceeds distribution total");
+        }
+
+        Self::store_persistent(&env, &claimed_key, &true);
+        distribution.claimed += amount;
+        Self::store_persistent(
+            &env,
+            &DataKey::Distribution(program_id.clone(), distribution_id),
+            &distribution,
+        );
+
+        let fee_config = Self::get_program_fee_config_internal(&env, &program_id);
+        let fee_amount = if fee_config.fee_enabled && fee_config.payout_fee_rate > 0 {
+            Self::calculate_fee(amount, fee_config.payout_fee_rate)
+        } else {
+            0
+        };
+        let net_amount = amount - fee_amount;
+
+        let contract_address = env.current_contract_address();
+        let token_client = token::Client::new(&env, &program_data.token_address);
+        token_client.transfer(&contract_address, &recipient, &net_amount);
+        if fee_amount > 0 {
+            token_client.transfer(&contract_address, &fee_config.fee_recipient, &fee_amount);
+        }
+
+        Self::record_payout(
+            &env,
+            &mut program_data,
+            PayoutRecord {
+                recipient: recipient.clone(),
+                amount: net_amount,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Self::store_persistent(&env, &program_key, &program_data);
+
+        env.events().publish(
+            (symbol_short!("dist_clm"),),
+            (program_id, distribution_id, index, recipient, net_amount),
+        );
+
+        net_amount
+    }
+
+    /// Returns the unclaimed part of a distribution to the program's
+    /// balance once its claim deadline has passed. Callable by anyone.
+    ///
+    /// Once the program is closed, cancelled or expired no more claims are
+    /// possible, so the distribution can be finalized right away and the
+    /// unclaimed part is refunded to the funders like the rest of the
+    /// program's balance.
+    ///
+    /// # Returns
+    /// * `i128` - Unclaimed amount returned to the program or its funders
+    ///
+    /// # Events
+    /// Emits: `dist_end(program_id, distribution_id, returned_amount)`
+    ///
+    /// # Panics
+    /// * If the contract is paused or the distribution is already finalized
+    /// * If the program is active and the claim period has not ended
+    pub fn finalize_distribution(env: Env, program_id: String, distribution_id: u32) -> i128 {
+        if Self::is_paused_internal(&env) {
+            panic!("Contract is paused");
+        }
+
+        let program_key = DataKey::Program(program_id.clone());
+        let mut program_data = Self::get_program_info(env.clone(), program_id.clone());
+        let active = program_data.status.allows_payouts();
+
+        let mut distribution =
+            Self::get_distribution(env.clone(), program_id.clone(), distribution_id);
+        if distribution.finalized {
+            panic!("Distribution already finalized");
+        }
+        if active && env.ledger().timestamp() <= distribution.claim_deadline {
+            panic!("Claim period has not ended");
+        }
+
+        let returned = distribution.total - distribution.claimed;
+        distribution.finalized = true;
+        Self::store_persistent(
+            &env,
+            &DataKey::Distribution(program_id.clone(), distribution_id),
+            &distribution,
+        );
+
+        program_data.remaining_balance += returned;
+        if !active && program_data.remaining_balance > 0 {
+            Self::refund_remaining(&env, &mut program_data);
+        }
+        Self::store_persistent(&env, &program_key, &program_data);
+
+        env.events().publish(
+            (symbol_short!("dist_end"),),
+            (program_id, distribution_id, returned),
+        );
+
+        returned
+    }
+
+    /// Returns a distribution of a program.
+    pub fn get_distribution(env: Env, program_id: String, distribution_id: u32) -> Distribution {
+        Self::load_persistent(&env, &DataKey::Distribution(program_id, distribution_id))
+            .unwrap_or_else(|| panic!("Distribution not found"))
+    }
+
+    /// Whether the leaf at `index` of a distribution has been claimed.
+    pub fn is_claimed(env: Env, program_id: String, distribution_id: u32, index: u32) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::DistributionClaimed(program_id, distribution_id, index))
+    }
+
+    // ========================================================================
+    // Payout Functions
+    // ========================================================================
+
+    /// Executes batch payouts to multiple recipients simultaneously.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `track_id` - Prize track to draw from (`None` for the unallocated pool)
+    /// * `recipients` - Vector of recipient addresses
+    /// * `amounts` - Vector of amounts (must match recipients length)
+    ///
+    /// # Returns
+    /// * `Ok(ProgramData)` - Updated program data after payouts
+    /// * `Err(Error::Unauthorized)` - Caller is not the authorized payout key
+    /// * `Err(Error::NotInitialized)` - Program not initialized
+    /// * `Err(Error::BatchMismatch)` - Recipients and amounts vectors length mismatch
+    /// * `Err(Error::InvalidAmount)` - Amount is zero or negative
+    /// * `Err(Error::InsufficientBalance)` - Total payout exceeds remaining balance
+    ///
+    /// # Authorization
+    /// - **CRITICAL**: Only authorized payout key can call
+    /// - Caller must be exact match to `authorized_payout_key`
+    ///
+    /// # State Changes
+    /// - Transfers tokens from contract to each recipient
+    /// - Adds PayoutRecord for each transfer to history
+    /// - Decreases `remaining_balance` by total payout amount
+    /// - Emits BatchPayout event
+    ///
+    /// # Atomicity
+    /// This operation is atomic - either all transfers succeed or all fail.
+    /// If any transfer fails, the entire batch is reverted.
+    ///
+    /// # Security Considerations
+    /// - Verify recipient addresses off-chain before calling
+    /// - Ensure amounts match winner rankings/criteria
+    /// - Total payout is calculated with overflow protection
+    /// - Balance check prevents overdraft
+    /// - All transfers are logged for audit trail
+    /// - Consider implementing payout limits for additional safety
+    ///
+    /// # Events
+    /// Emits: `BatchPayout(program_id, recipient_count, total_amount, new_balance)`
+    ///
+    /// # Example
+    /// ```rust
+    /// use soroban_sdk::{vec, Address};
+    ///
+    /// // Define winners and prizes
+    /// let winners = vec![
+    ///     &env,
+    ///     Address::from_string("GWINNER1..."), // 1st place
+    ///     Address::from_string("GWINNER2..."), // 2nd place
+    ///     Address::from_string("GWINNER3..."), // 3rd place
+    /// ];
+    ///
+    /// let prizes = vec![
+    ///     &env,
+    ///     5_000_0000000,  // $5,000 USDC
+    ///     3_000_0000000,  // $3,000 USDC
+    ///     2_000_0000000,  // $2,000 USDC
+    /// ];
+    ///
+    /// // Execute batch payout (only authorized backend can call)
+    /// let result = escrow_client.batch_payout(&program_id, &Some(track_id), &winners, &prizes);
+    /// println!("Paid {} winners", winners.len());
+    /// println!("Remaining: {}", result.remaining_balance);
     /// ```
     ///
     /// # Production Usage
@@ -1310,6 +2204,7 @@ impl ProgramEscrowContract {
     pub fn batch_payout(
         env: Env,
         program_id: String,
+        track_id: Option<u32>,
         recipients: Vec<Address>,
         amounts: Vec<i128>,
     ) -> ProgramData {
@@ -1324,12 +2219,13 @@ impl ProgramEscrowContract {
 
         // Get program data
         let program_key = DataKey::Program(program_id.clone());
-        let program_data: ProgramData = env
-            .storage()
-            .instance()
-            .get(&program_key)
+        let program_data: ProgramData = Self::load_persistent(&env, &program_key)
             .unwrap_or_else(|| panic!("Program not found"));
 
+        if !program_data.status.allows_payouts() {
+            panic!("Payouts not allowed in current program state");
+        }
+
         // Apply rate limiting to the authorized payout key
         anti_abuse::check_rate_limit(&env, program_data.authorized_payout_key.clone());
 
@@ -1347,7 +2243,7 @@ impl ProgramEscrowContract {
 
         // Calculate total with overflow protection
         let mut total_payout: i128 = 0;
-        let fee_config = Self::get_fee_config_internal(&env);
+        let fee_config = Self::get_program_fee_config_internal(&env, &program_id);
         let limits = Self::get_amount_limits(env.clone());
         
         for i in 0..amounts.len() {
@@ -1377,13 +2273,14 @@ impl ProgramEscrowContract {
         if total_payout > program_data.remaining_balance {
             panic!("Insufficient balance");
         }
+        Self::draw_from_budget(&env, &program_data, track_id, total_payout);
 
         // Calculate fees if enabled
-        let fee_config = Self::get_fee_config_internal(&env);
+        let fee_config = Self::get_program_fee_config_internal(&env, &program_id);
         let mut total_fees: i128 = 0;
 
         // Execute transfers
-        let mut updated_history = program_data.payout_history.clone();
+        let mut updated_data = program_data.clone();
         let timestamp = env.ledger().timestamp();
         let contract_address = env.current_contract_address();
         let token_client = token::Client::new(&env, &program_data.token_address);
@@ -1415,7 +2312,7 @@ impl ProgramEscrowContract {
                 amount: net_amount,
                 timestamp,
             };
-            updated_history.push_back(payout_record);
+            Self::record_payout(&env, &mut updated_data, payout_record);
         }
 
         // Emit fee collected event if applicable
@@ -1432,12 +2329,10 @@ impl ProgramEscrowContract {
         }
 
         // Update program data
-        let mut updated_data = program_data.clone();
         updated_data.remaining_balance -= total_payout; // Total includes fees
-        updated_data.payout_history = updated_history;
 
         // Store updated data
-        env.storage().instance().set(&program_key, &updated_data);
+        Self::store_persistent(&env, &program_key, &updated_data);
 
         // Emit event
         env.events().publish(
@@ -1457,6 +2352,7 @@ impl ProgramEscrowContract {
     ///
     /// # Arguments
     /// * `env` - The contract environment
+    /// * `track_id` - Prize track to draw from (`None` for the unallocated pool)
     /// * `recipient` - Address of the prize recipient
     /// * `amount` - Amount to transfer (in token's smallest denomination)
     ///
@@ -1493,7 +2389,7 @@ impl ProgramEscrowContract {
     /// let prize = 1_000_0000000; // $1,000 USDC
     ///
     /// // Execute single payout
-    /// let result = escrow_client.single_payout(&winner, &prize);
+    /// let result = escrow_client.single_payout(&program_id, &None, &winner, &prize);
     /// println!("Paid {} to winner", prize);
     /// ```
     ///
@@ -1507,6 +2403,7 @@ impl ProgramEscrowContract {
     pub fn single_payout(
         env: Env,
         program_id: String,
+        track_id: Option<u32>,
         recipient: Address,
         amount: i128,
     ) -> ProgramData {
@@ -1516,12 +2413,13 @@ impl ProgramEscrowContract {
         }
         // Get program data
         let program_key = DataKey::Program(program_id.clone());
-        let program_data: ProgramData = env
-            .storage()
-            .instance()
-            .get(&program_key)
+        let program_data: ProgramData = Self::load_persistent(&env, &program_key)
             .unwrap_or_else(|| panic!("Program not found"));
 
+        if !program_data.status.allows_payouts() {
+            panic!("Payouts not allowed in current program state");
+        }
+
         program_data.authorized_payout_key.require_auth();
         // Apply rate limiting to the authorized payout key
         anti_abuse::check_rate_limit(&env, program_data.authorized_payout_key.clone());
@@ -1538,7 +2436,7 @@ impl ProgramEscrowContract {
         }
 
         // Check payout amount limits (considering fees)
-        let fee_config = Self::get_fee_config_internal(&env);
+        let fee_config = Self::get_program_fee_config_internal(&env, &program_id);
         let fee_amount = if fee_config.fee_enabled && fee_config.payout_fee_rate > 0 {
             Self::calculate_fee(amount, fee_config.payout_fee_rate)
         } else {
@@ -1555,9 +2453,10 @@ impl ProgramEscrowContract {
         if amount > program_data.remaining_balance {
             panic!("Insufficient balance");
         }
+        Self::draw_from_budget(&env, &program_data, track_id, amount);
 
         // Calculate and collect fee if enabled
-        let fee_config = Self::get_fee_config_internal(&env);
+        let fee_config = Self::get_program_fee_config_internal(&env, &program_id);
         let fee_amount = if fee_config.fee_enabled && fee_config.payout_fee_rate > 0 {
             Self::calculate_fee(amount, fee_config.payout_fee_rate)
         } else {
@@ -1593,16 +2492,13 @@ impl ProgramEscrowContract {
             timestamp,
         };
 
-        let mut updated_history = program_data.payout_history.clone();
-        updated_history.push_back(payout_record);
-
         // Update program data
         let mut updated_data = program_data.clone();
+        Self::record_payout(&env, &mut updated_data, payout_record);
         updated_data.remaining_balance -= amount; // Total amount (includes fee)
-        updated_data.payout_history = updated_history;
 
         // Store updated data
-        env.storage().instance().set(&program_key, &updated_data);
+        Self::store_persistent(&env, &program_key, &updated_data);
 
         // Emit Payout event (with net amount after fee)
         // Emit event
@@ -1676,10 +2572,7 @@ impl ProgramEscrowContract {
 
         // Get program data
         let program_key = DataKey::Program(program_id.clone());
-        let program_data: ProgramData = env
-            .storage()
-            .instance()
-            .get(&program_key)
+        let program_data: ProgramData = Self::load_persistent(&env, &program_key)
             .unwrap_or_else(|| panic!("Program not found"));
 
         // Apply rate limiting to the authorized payout key
@@ -1698,18 +2591,14 @@ impl ProgramEscrowContract {
             panic!("Release timestamp must be in the future");
         }
 
-        // Check sufficient remaining balance
-        let scheduled_total = get_program_total_scheduled_amount(&env, &program_id);
-        if scheduled_total + amount > program_data.remaining_balance {
+        // Check sufficient unreserved balance
+        if amount > Self::unallocated_balance(&env, &program_data) {
             panic!("Insufficient balance for scheduled amount");
         }
 
         // Get next schedule ID
-        let schedule_id: u64 = env
-            .storage()
-            .persistent()
-            .get(&DataKey::NextScheduleId(program_id.clone()))
-            .unwrap_or(1);
+        let schedule_id: u64 =
+            Self::load_persistent(&env, &DataKey::NextScheduleId(program_id.clone())).unwrap_or(1);
 
         // Create release schedule
         let schedule = ProgramReleaseSchedule {
@@ -1723,17 +2612,24 @@ impl ProgramEscrowContract {
         };
 
         // Store schedule
-        env.storage().persistent().set(
+        Self::store_persistent(
+            &env,
             &DataKey::ReleaseSchedule(program_id.clone(), schedule_id),
             &schedule,
         );
 
         // Update next schedule ID
-        env.storage().persistent().set(
+        Self::store_persistent(
+            &env,
             &DataKey::NextScheduleId(program_id.clone()),
             &(schedule_id + 1),
         );
 
+        // Reserve the amount so payouts cannot spend it
+        let mut reserved_data = program_data.clone();
+        reserved_data.reserved_for_schedules += amount;
+        Self::store_persistent(&env, &program_key, &reserved_data);
+
         // Emit program schedule created event
         env.events().publish(
             (PROG_SCHEDULE_CREATED,),
@@ -1760,7 +2656,7 @@ impl ProgramEscrowContract {
         monitoring::emit_performance(&env, symbol_short!("create_p"), duration);
 
         // Return updated program data
-        let updated_data: ProgramData = env.storage().instance().get(&program_key).unwrap();
+        let updated_data: ProgramData = Self::load_persistent(&env, &program_key).unwrap();
         updated_data
     }
 
@@ -1806,12 +2702,13 @@ impl ProgramEscrowContract {
 
         // Get program data
         let program_key = DataKey::Program(program_id.clone());
-        let program_data: ProgramData = env
-            .storage()
-            .instance()
-            .get(&program_key)
+        let program_data: ProgramData = Self::load_persistent(&env, &program_key)
             .unwrap_or_else(|| panic!("Program not found"));
 
+        if !program_data.status.allows_payouts() {
+            panic!("Payouts not allowed in current program state");
+        }
+
         // Get schedule
         if !env
             .storage()
@@ -1821,11 +2718,11 @@ impl ProgramEscrowContract {
             panic!("Schedule not found");
         }
 
-        let mut schedule: ProgramReleaseSchedule = env
-            .storage()
-            .persistent()
-            .get(&DataKey::ReleaseSchedule(program_id.clone(), schedule_id))
-            .unwrap();
+        let mut schedule: ProgramReleaseSchedule = Self::load_persistent(
+            &env,
+            &DataKey::ReleaseSchedule(program_id.clone(), schedule_id),
+        )
+        .unwrap();
 
         // Check if already released
         if schedule.released {
@@ -1853,6 +2750,7 @@ impl ProgramEscrowContract {
         // Update program data
         let mut updated_data = program_data.clone();
         updated_data.remaining_balance -= schedule.amount;
+        updated_data.reserved_for_schedules -= schedule.amount;
 
         // Add to release history
         let history_entry = ProgramReleaseHistory {
@@ -1865,22 +2763,19 @@ impl ProgramEscrowContract {
             release_type: ReleaseType::Automatic,
         };
 
-        let mut history: Vec<ProgramReleaseHistory> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::ReleaseHistory(program_id.clone()))
-            .unwrap_or(vec![&env]);
+        let mut history: Vec<ProgramReleaseHistory> =
+            Self::load_persistent(&env, &DataKey::ReleaseHistory(program_id.clone()))
+                .unwrap_or(vec![&env]);
         history.push_back(history_entry);
 
         // Store updates
-        env.storage().persistent().set(
+        Self::store_persistent(
+            &env,
             &DataKey::ReleaseSchedule(program_id.clone(), schedule_id),
             &schedule,
         );
-        env.storage().instance().set(&program_key, &updated_data);
-        env.storage()
-            .persistent()
-            .set(&DataKey::ReleaseHistory(program_id.clone()), &history);
+        Self::store_persistent(&env, &program_key, &updated_data);
+        Self::store_persistent(&env, &DataKey::ReleaseHistory(program_id.clone()), &history);
 
         // Emit program schedule released event
         env.events().publish(
@@ -1943,12 +2838,13 @@ impl ProgramEscrowContract {
 
         // Get program data
         let program_key = DataKey::Program(program_id.clone());
-        let program_data: ProgramData = env
-            .storage()
-            .instance()
-            .get(&program_key)
+        let program_data: ProgramData = Self::load_persistent(&env, &program_key)
             .unwrap_or_else(|| panic!("Program not found"));
 
+        if !program_data.status.allows_payouts() {
+            panic!("Payouts not allowed in current program state");
+        }
+
         // Apply rate limiting to the authorized payout key
         anti_abuse::check_rate_limit(&env, program_data.authorized_payout_key.clone());
 
@@ -1964,11 +2860,11 @@ impl ProgramEscrowContract {
             panic!("Schedule not found");
         }
 
-        let mut schedule: ProgramReleaseSchedule = env
-            .storage()
-            .persistent()
-            .get(&DataKey::ReleaseSchedule(program_id.clone(), schedule_id))
-            .unwrap();
+        let mut schedule: ProgramReleaseSchedule = Self::load_persistent(
+            &env,
+            &DataKey::ReleaseSchedule(program_id.clone(), schedule_id),
+        )
+        .unwrap();
 
         // Check if already released
         if schedule.released {
@@ -1991,6 +2887,7 @@ impl ProgramEscrowContract {
         // Update program data
         let mut updated_data = program_data.clone();
         updated_data.remaining_balance -= schedule.amount;
+        updated_data.reserved_for_schedules -= schedule.amount;
 
         // Add to release history
         let history_entry = ProgramReleaseHistory {
@@ -2003,22 +2900,19 @@ impl ProgramEscrowContract {
             release_type: ReleaseType::Manual,
         };
 
-        let mut history: Vec<ProgramReleaseHistory> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::ReleaseHistory(program_id.clone()))
-            .unwrap_or(vec![&env]);
+        let mut history: Vec<ProgramReleaseHistory> =
+            Self::load_persistent(&env, &DataKey::ReleaseHistory(program_id.clone()))
+                .unwrap_or(vec![&env]);
         history.push_back(history_entry);
 
         // Store updates
-        env.storage().persistent().set(
+        Self::store_persistent(
+            &env,
             &DataKey::ReleaseSchedule(program_id.clone(), schedule_id),
             &schedule,
         );
-        env.storage().instance().set(&program_key, &updated_data);
-        env.storage()
-            .persistent()
-            .set(&DataKey::ReleaseHistory(program_id.clone()), &history);
+        Self::store_persistent(&env, &program_key, &updated_data);
+        Self::store_persistent(&env, &DataKey::ReleaseHistory(program_id.clone()), &history);
 
         // Emit program schedule released event
         env.events().publish(
@@ -2047,1205 +2941,3573 @@ impl ProgramEscrowContract {
         monitoring::emit_performance(&env, symbol_short!("rel_man"), duration);
     }
 
-    // ========================================================================
-    // View Functions (Read-only)
-    // ========================================================================
-
-    /// Retrieves complete program information.
-    ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    ///
-    /// # Returns
-    /// * `Ok(ProgramData)` - Complete program state including:
-    ///   - Program ID
-    ///   - Total funds locked
-    ///   - Remaining balance
-    ///   - Authorized payout key
-    ///   - Complete payout history
-    ///   - Token contract address
-    /// * `Err(Error::NotInitialized)` - Program not initialized
-    ///
-    /// # Use Cases
-    /// - Verifying program configuration
-    /// - Checking balances before payouts
-    /// - Auditing payout history
-    /// - Displaying program status in UI
-    ///
-    /// # Example
-    /// ```rust
-    /// let info = escrow_client.get_program_info();
-    /// println!("Program: {}", info.program_id);
-    /// println!("Total Locked: {}", info.total_funds);
-    /// println!("Remaining: {}", info.remaining_balance);
-    /// println!("Payouts Made: {}", info.payout_history.len());
-    /// ```
-    ///
-    /// # Gas Cost
-    /// Very Low - Single storage read
-    pub fn get_program_info(env: Env, program_id: String) -> ProgramData {
-        let program_key = DataKey::Program(program_id);
-        env.storage()
-            .instance()
-            .get(&program_key)
-            .unwrap_or_else(|| panic!("Program not found"))
-    }
-
-    /// Retrieves the remaining balance for a specific program.
+    /// Changes the amount, release time and recipient of an unreleased
+    /// program release schedule.
     ///
     /// # Arguments
-    /// * `program_id` - The program ID to query
+    /// * `program_id` - The program containing the schedule
+    /// * `schedule_id` - The schedule to change
+    /// * `caller` - The authorized payout key or the contract admin
+    /// * `amount` - New amount to release
+    /// * `release_timestamp` - New release time, must be in the future
+    /// * `recipient` - New recipient
     ///
     /// # Returns
-    /// * `i128` - Remaining balance
+    /// * `ProgramReleaseSchedule` - The updated schedule
     ///
     /// # Panics
-    /// * If program doesn't exist
-    pub fn get_remaining_balance(env: Env, program_id: String) -> i128 {
-        let program_key = DataKey::Program(program_id);
-        let program_data: ProgramData = env
-            .storage()
-            .instance()
-            .get(&program_key)
-            .unwrap_or_else(|| panic!("Program not found"));
-
-        program_data.remaining_balance
-    }
-
-    /// Update fee configuration (admin only - uses authorized_payout_key)
+    /// * If the program does not allow payouts
+    /// * If caller is neither the payout key nor the admin
+    /// * If schedule doesn't exist or was already released
+    /// * If the new amount exceeds the unscheduled remaining balance
     ///
-    /// # Arguments
-    /// * `lock_fee_rate` - Optional new lock fee rate (basis points)
-    /// * `payout_fee_rate` - Optional new payout fee rate (basis points)
-    /// * `fee_recipient` - Optional new fee recipient address
-    /// * `fee_enabled` - Optional fee enable/disable flag
-    pub fn update_fee_config(
+    /// # Events
+    /// Emits: `prg_sch_u` with `ProgramScheduleUpdated`
+    pub fn update_program_release_schedule(
         env: Env,
-        lock_fee_rate: Option<i128>,
-        payout_fee_rate: Option<i128>,
-        fee_recipient: Option<Address>,
-        fee_enabled: Option<bool>,
-    ) {
-        // Verify authorization
-        let program_data: ProgramData = env.storage().instance().get(&PROGRAM_DATA).unwrap();
-
-        // Note: In Soroban, we check authorization by requiring auth from the authorized key
-        // For this function, we'll require auth from the authorized_payout_key
-        program_data.authorized_payout_key.require_auth();
+        program_id: String,
+        schedule_id: u64,
+        caller: Address,
+        amount: i128,
+        release_timestamp: u64,
+        recipient: Address,
+    ) -> ProgramReleaseSchedule {
+        let _guard = ReentrancyGuardRAII::new(&env).expect("Reentrancy detected");
+        if Self::is_paused_internal(&env) {
+            panic!("Contract is paused");
+        }
 
-        let mut fee_config = Self::get_fee_config_internal(&env);
+        let program_data = Self::get_program_info(env.clone(), program_id.clone());
+        let mut schedule = Self::get_pending_release_schedule(
+            &env,
+            &program_data,
+            &program_id,
+            schedule_id,
+            &caller,
+        );
 
-        if let Some(rate) = lock_fee_rate {
-            if !(0..=MAX_FEE_RATE).contains(&rate) {
-                panic!(
-                    "Invalid lock fee rate: must be between 0 and {}",
-                    MAX_FEE_RATE
-                );
-            }
-            fee_config.lock_fee_rate = rate;
+        if amount <= 0 {
+            panic!("Amount must be greater than zero");
         }
-
-        if let Some(rate) = payout_fee_rate {
-            if !(0..=MAX_FEE_RATE).contains(&rate) {
-                panic!(
-                    "Invalid payout fee rate: must be between 0 and {}",
-                    MAX_FEE_RATE
-                );
-            }
-            fee_config.payout_fee_rate = rate;
+        if release_timestamp <= env.ledger().timestamp() {
+            panic!("Release timestamp must be in the future");
         }
 
-        if let Some(recipient) = fee_recipient {
-            fee_config.fee_recipient = recipient;
+        // The schedule's current amount is already reserved
+        if amount - schedule.amount > Self::unallocated_balance(&env, &program_data) {
+            panic!("Insufficient balance for scheduled amount");
         }
 
-        if let Some(enabled) = fee_enabled {
-            fee_config.fee_enabled = enabled;
-        }
+        let mut program_data = program_data;
+        program_data.reserved_for_schedules += amount - schedule.amount;
+        Self::store_persistent(&env, &DataKey::Program(program_id.clone()), &program_data);
 
-        env.storage().instance().set(&FEE_CONFIG, &fee_config);
+        let old_amount = schedule.amount;
+        schedule.amount = amount;
+        schedule.release_timestamp = release_timestamp;
+        schedule.recipient = recipient.clone();
+        Self::store_persistent(
+            &env,
+            &DataKey::ReleaseSchedule(program_id.clone(), schedule_id),
+            &schedule,
+        );
 
-        // Emit fee config updated event
         env.events().publish(
-            (symbol_short!("fee_cfg"),),
-            (
-                fee_config.lock_fee_rate,
-                fee_config.payout_fee_rate,
-                fee_config.fee_recipient,
-                fee_config.fee_enabled,
-            ),
+            (PROG_SCHEDULE_UPDATED,),
+            ProgramScheduleUpdated {
+                program_id,
+                schedule_id,
+                old_amount,
+                amount,
+                release_timestamp,
+                recipient,
+                updated_by: caller,
+            },
         );
-    }
 
-    /// Get current fee configuration (view function)
-    pub fn get_fee_config(env: Env) -> FeeConfig {
-        Self::get_fee_config_internal(&env)
+        schedule
     }
 
-    /// Update amount limits configuration (admin only)
-    pub fn update_amount_limits(
+    /// Cancels an unreleased program release schedule, releasing its
+    /// reservation back to the program's available balance.
+    ///
+    /// # Arguments
+    /// * `program_id` - The program containing the schedule
+    /// * `schedule_id` - The schedule to cancel
+    /// * `caller` - The authorized payout key or the contract admin
+    ///
+    /// # Panics
+    /// * If the program does not allow payouts
+    /// * If caller is neither the payout key nor the admin
+    /// * If schedule doesn't exist or was already released
+    ///
+    /// # Events
+    /// Emits: `prg_sch_x` with `ProgramScheduleCancelled`
+    pub fn cancel_program_release_schedule(
         env: Env,
-        min_lock_amount: i128,
-        max_lock_amount: i128,
-        min_payout: i128,
-        max_payout: i128,
+        program_id: String,
+        schedule_id: u64,
+        caller: Address,
     ) {
-        // Get admin and require authorization
-        let admin = anti_abuse::get_admin(&env).expect("Admin not set");
-        admin.require_auth();
-
-        // Validate limits
-        if min_lock_amount < 0 || max_lock_amount < 0 || min_payout < 0 || max_payout < 0 {
-            panic!("Invalid amount: amounts cannot be negative");
-        }
-        if min_lock_amount > max_lock_amount || min_payout > max_payout {
-            panic!("Invalid amount: minimum cannot exceed maximum");
+        let _guard = ReentrancyGuardRAII::new(&env).expect("Reentrancy detected");
+        if Self::is_paused_internal(&env) {
+            panic!("Contract is paused");
         }
 
-        let limits = AmountLimits {
-            min_lock_amount,
-            max_lock_amount,
-            min_payout,
-            max_payout,
-        };
+        let program_data = Self::get_program_info(env.clone(), program_id.clone());
+        let schedule = Self::get_pending_release_schedule(
+            &env,
+            &program_data,
+            &program_id,
+            schedule_id,
+            &caller,
+        );
 
-        env.storage().instance().set(&DataKey::AmountLimits, &limits);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::ReleaseSchedule(program_id.clone(), schedule_id));
+
+        let mut program_data = program_data;
+        program_data.reserved_for_schedules -= schedule.amount;
+        Self::store_persistent(&env, &DataKey::Program(program_id.clone()), &program_data);
 
-        // Emit event
         env.events().publish(
-            (symbol_short!("amt_lmt"),),
-            (min_lock_amount, max_lock_amount, min_payout, max_payout),
+            (PROG_SCHEDULE_CANCELLED,),
+            ProgramScheduleCancelled {
+                program_id,
+                schedule_id,
+                amount: schedule.amount,
+                recipient: schedule.recipient,
+                cancelled_by: caller,
+            },
         );
     }
 
-    /// Get current amount limits configuration (view function)
-    pub fn get_amount_limits(env: Env) -> AmountLimits {
-        env.storage()
-            .instance()
-            .get(&DataKey::AmountLimits)
-            .unwrap_or(AmountLimits {
-                min_lock_amount: 1,
-                max_lock_amount: i128::MAX,
-                min_payout: 1,
-                max_payout: i128::MAX,
-            })
+    /// Loads an unreleased schedule after authorizing `caller` as the
+    /// program's payout key or the contract admin. Schedules of programs
+    /// that do not allow payouts can no longer change.
+    fn get_pending_release_schedule(
+        env: &Env,
+        program_data: &ProgramData,
+        program_id: &String,
+        schedule_id: u64,
+        caller: &Address,
+    ) -> ProgramReleaseSchedule {
+        if !program_data.status.allows_payouts() {
+            panic!("Payouts not allowed in current program state");
+        }
+        let is_admin = anti_abuse::get_admin(env) == Some(caller.clone());
+        if *caller != program_data.authorized_payout_key && !is_admin {
+            panic!("Unauthorized: only the payout key or admin can modify schedules");
+        }
+        caller.require_auth();
+
+        let schedule: ProgramReleaseSchedule = Self::load_persistent(
+            env,
+            &DataKey::ReleaseSchedule(program_id.clone(), schedule_id),
+        )
+        .unwrap_or_else(|| panic!("Schedule not found"));
+        if schedule.released {
+            panic!("Schedule already released");
+        }
+        schedule
     }
 
-    /// Gets the total number of programs registered.
+    /// Creates a vesting schedule with a cliff, a linear period and optional
+    /// periodic tranches.
+    ///
+    /// # Arguments
+    /// * `program_id` - The program funding the grant
+    /// * `recipient` - Address that withdraws vested funds
+    /// * `total_amount` - Amount vesting over the schedule
+    /// * `start_timestamp` - When vesting starts accruing
+    /// * `cliff_timestamp` - Nothing is withdrawable before this
+    /// * `end_timestamp` - When the full amount has vested
+    /// * `tranche_period` - Vest in steps of this many seconds (0 = continuous)
     ///
     /// # Returns
-    /// * `u32` - Count of registered programs
-    pub fn get_program_count(env: Env) -> u32 {
-        let registry: Vec<String> = env
-            .storage()
-            .instance()
-            .get(&PROGRAM_REGISTRY)
-            .unwrap_or(vec![&env]);
-
-        registry.len()
-    }
+    /// * `u64` - The schedule ID
+    ///
+    /// # Authorization
+    /// - Only authorized payout key can call this function
+    ///
+    /// # Panics
+    /// * If `start <= cliff <= end` does not hold or `start == end`
+    /// * If the tranche period is longer than the vesting period
+    /// * If amount exceeds the unscheduled remaining balance
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_program_vesting_schedule(
+        env: Env,
+        program_id: String,
+        recipient: Address,
+        total_amount: i128,
+        start_timestamp: u64,
+        cliff_timestamp: u64,
+        end_timestamp: u64,
+        tranche_period: u64,
+    ) -> u64 {
+        let _guard = ReentrancyGuardRAII::new(&env).expect("Reentrancy detected");
+        if Self::is_paused_internal(&env) {
+            panic!("Contract is paused");
+        }
 
-    // ========================================================================
-    // Monitoring & Analytics Functions
-    // ========================================================================
+        let program_data = Self::get_program_info(env.clone(), program_id.clone());
+        anti_abuse::check_rate_limit(&env, program_data.authorized_payout_key.clone());
+        program_data.authorized_payout_key.require_auth();
 
-    /// Health check - returns contract health status
-    pub fn health_check(env: Env) -> monitoring::HealthStatus {
-        monitoring::health_check(&env)
-    }
+        if total_amount <= 0 {
+            panic!("Amount must be greater than zero");
+        }
+        if start_timestamp >= end_timestamp
+            || cliff_timestamp < start_timestamp
+            || cliff_timestamp > end_timestamp
+        {
+            panic!("Invalid vesting period");
+        }
+        if tranche_period > end_timestamp - start_timestamp {
+            panic!("Tranche period exceeds vesting period");
+        }
 
-    /// Get analytics - returns usage analytics
-    pub fn get_analytics(env: Env) -> monitoring::Analytics {
-        monitoring::get_analytics(&env)
-    }
+        if total_amount > Self::unallocated_balance(&env, &program_data) {
+            panic!("Insufficient balance for scheduled amount");
+        }
 
-    /// Get state snapshot - returns current state
-    pub fn get_state_snapshot(env: Env) -> monitoring::StateSnapshot {
-        monitoring::get_state_snapshot(&env)
-    }
+        let schedule_id: u64 =
+            Self::load_persistent(&env, &DataKey::NextScheduleId(program_id.clone())).unwrap_or(1);
 
-    /// Get performance stats for a function
-    pub fn get_performance_stats(env: Env, function_name: Symbol) -> monitoring::PerformanceStats {
-        monitoring::get_performance_stats(&env, function_name)
+        let schedule = ProgramVestingSchedule {
+            schedule_id,
+            recipient: recipient.clone(),
+            total_amount,
+            start_timestamp,
+            cliff_timestamp,
+            end_timestamp,
+            tranche_period,
+            withdrawn: 0,
+            revoked_at: None,
+        };
+        Self::store_persistent(
+            &env,
+            &DataKey::VestingSchedule(program_id.clone(), schedule_id),
+            &schedule,
+        );
+        Self::store_persistent(
+            &env,
+            &DataKey::NextScheduleId(program_id.clone()),
+            &(schedule_id + 1),
+        );
+
+        let mut program_data = program_data;
+        program_data.reserved_for_schedules += total_amount;
+        Self::store_persistent(&env, &DataKey::Program(program_id.clone()), &program_data);
+
+        env.events().publish(
+            (PROG_VESTING_CREATED,),
+            (program_id, schedule_id, recipient, total_amount, cliff_timestamp, end_timestamp),
+        );
+
+        schedule_id
     }
 
-    // ========================================================================
-    // Anti-Abuse Administrative Functions
-    // ========================================================================
+    /// Withdraws everything vested so far but not yet withdrawn.
+    ///
+    /// # Authorization
+    /// - Requires auth from the schedule's recipient
+    ///
+    /// # State Changes
+    /// - Transfers the withdrawable amount to the recipient
+    /// - Decreases `remaining_balance` by the same amount
+    /// - Adds a `Vesting` entry to the release history
+    ///
+    /// # Returns
+    /// * `i128` - Amount withdrawn
+    pub fn withdraw_vested_release(env: Env, program_id: String, schedule_id: u64) -> i128 {
+        let _guard = ReentrancyGuardRAII::new(&env).expect("Reentrancy detected");
+        if Self::is_paused_internal(&env) {
+            panic!("Contract is paused");
+        }
 
-    /// Sets the administrative address for anti-abuse configuration.
-    /// Can only be called once or by the existing admin.
-    pub fn set_admin(env: Env, new_admin: Address) {
-        if let Some(current_admin) = anti_abuse::get_admin(&env) {
-            current_admin.require_auth();
+        let program_key = DataKey::Program(program_id.clone());
+        let mut program_data = Self::get_program_info(env.clone(), program_id.clone());
+        if !program_data.status.allows_payouts() {
+            panic!("Payouts not allowed in current program state");
         }
-        anti_abuse::set_admin(&env, new_admin);
-    }
 
-    pub fn get_admin(env: Env) -> Option<Address> {
-        anti_abuse::get_admin(&env)
+        let mut schedule =
+            Self::get_program_vesting_schedule(env.clone(), program_id.clone(), schedule_id);
+        schedule.recipient.require_auth();
+
+        let amount = schedule.vested_at(env.ledger().timestamp()) - schedule.withdrawn;
+        if amount <= 0 {
+            panic!("Nothing vested to withdraw");
+        }
+
+        let recipient = schedule.recipient.clone();
+        Self::release_vested(&env, &mut program_data, &mut schedule, amount, recipient);
+        Self::store_persistent(&env, &program_key, &program_data);
+
+        amount
     }
 
-    /// Updates the rate limit configuration.
-    /// Only the admin can call this.
-    pub fn update_rate_limit_config(
-        env: Env,
-        window_size: u64,
-        max_operations: u32,
-        cooldown_period: u64,
+    /// Pays `amount` of a vesting schedule to its recipient, records it in
+    /// the release history and stores the schedule. The caller persists
+    /// `program_data`.
+    fn release_vested(
+        env: &Env,
+        program_data: &mut ProgramData,
+        schedule: &mut ProgramVestingSchedule,
+        amount: i128,
+        released_by: Address,
     ) {
-        let admin = anti_abuse::get_admin(&env).expect("Admin not set");
-        admin.require_auth();
+        let program_id = program_data.program_id.clone();
+        let schedule_id = schedule.schedule_id;
+        let now = env.ledger().timestamp();
 
-        anti_abuse::set_config(
-            &env,
-            anti_abuse::AntiAbuseConfig {
-                window_size,
-                max_operations,
-                cooldown_period,
-            },
+        let token_client = token::Client::new(env, &program_data.token_address);
+        token_client.transfer(&env.current_contract_address(), &schedule.recipient, &amount);
+
+        schedule.withdrawn += amount;
+        program_data.remaining_balance -= amount;
+        program_data.reserved_for_schedules -= amount;
+        Self::store_persistent(
+            env,
+            &DataKey::VestingSchedule(program_id.clone(), schedule_id),
+            schedule,
         );
-    }
 
-    /// Adds or removes an address from the whitelist.
-    /// Only the admin can call this.
-    pub fn set_whitelist(env: Env, address: Address, whitelisted: bool) {
-        let admin = anti_abuse::get_admin(&env).expect("Admin not set");
-        admin.require_auth();
+        let mut history: Vec<ProgramReleaseHistory> =
+            Self::load_persistent(env, &DataKey::ReleaseHistory(program_id.clone()))
+                .unwrap_or(vec![env]);
+        history.push_back(ProgramReleaseHistory {
+            schedule_id,
+            program_id: program_id.clone(),
+            amount,
+            recipient: schedule.recipient.clone(),
+            released_at: now,
+            released_by: released_by.clone(),
+            release_type: ReleaseType::Vesting,
+        });
+        Self::store_persistent(env, &DataKey::ReleaseHistory(program_id.clone()), &history);
 
-        anti_abuse::set_whitelist(&env, address, whitelisted);
+        env.events().publish(
+            (PROG_SCHEDULE_RELEASED,),
+            ProgramScheduleReleased {
+                program_id,
+                schedule_id,
+                amount,
+                recipient: schedule.recipient.clone(),
+                released_at: now,
+                released_by,
+                release_type: ReleaseType::Vesting,
+            },
+        );
     }
 
-    /// Checks if an address is whitelisted.
-    pub fn is_whitelisted(env: Env, address: Address) -> bool {
-        anti_abuse::is_whitelisted(&env, address)
-    }
+    /// Pays every vesting recipient what has vested but not been withdrawn
+    /// yet and revokes the unvested remainder, so that a program being
+    /// wound down only refunds funds nobody is entitled to.
+    fn settle_vesting_schedules(env: &Env, program_data: &mut ProgramData) {
+        let program_id = program_data.program_id.clone();
+        let next_id: u64 =
+            Self::load_persistent(env, &DataKey::NextScheduleId(program_id.clone())).unwrap_or(1);
+        let now = env.ledger().timestamp();
 
-    /// Gets the current rate limit configuration.
-    pub fn get_rate_limit_config(env: Env) -> anti_abuse::AntiAbuseConfig {
-        anti_abuse::get_config(&env)
-    }
+        for schedule_id in 1..next_id {
+            let key = DataKey::VestingSchedule(program_id.clone(), schedule_id);
+            let mut schedule: ProgramVestingSchedule = match Self::load_persistent(env, &key) {
+                Some(schedule) => schedule,
+                None => continue,
+            };
 
-    // ========================================================================
-    // Schedule View Functions
-    // ========================================================================
+            let vested = schedule.vested_at(now);
+            if schedule.revoked_at.is_none() {
+                let unvested = schedule.total_amount - vested;
+                schedule.total_amount = vested;
+                schedule.revoked_at = Some(now);
+                program_data.reserved_for_schedules -= unvested;
+                Self::store_persistent(env, &key, &schedule);
 
-    /// Retrieves a specific program release schedule.
-    ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `program_id` - The program containing the schedule
-    /// * `schedule_id` - The schedule ID to retrieve
-    ///
-    /// # Returns
-    /// * `ProgramReleaseSchedule` - The schedule details
-    ///
-    /// # Panics
-    /// * If schedule doesn't exist
-    pub fn get_program_release_schedule(
-        env: Env,
-        program_id: String,
-        schedule_id: u64,
-    ) -> ProgramReleaseSchedule {
-        env.storage()
-            .persistent()
-            .get(&DataKey::ReleaseSchedule(program_id, schedule_id))
-            .unwrap_or_else(|| panic!("Schedule not found"))
+                env.events().publish(
+                    (PROG_VESTING_REVOKED,),
+                    (program_id.clone(), schedule_id, unvested),
+                );
+            }
+
+            let owed = vested - schedule.withdrawn;
+            if owed > 0 {
+                Self::release_vested(
+                    env,
+                    program_data,
+                    &mut schedule,
+                    owed,
+                    env.current_contract_address(),
+                );
+            }
+        }
     }
 
-    /// Retrieves all release schedules for a program.
-    ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `program_id` - The program to query
-    ///
-    /// # Returns
-    /// * `Vec<ProgramReleaseSchedule>` - All schedules for the program
-    pub fn get_all_prog_release_schedules(
-        env: Env,
-        program_id: String,
-    ) -> Vec<ProgramReleaseSchedule> {
-        let mut schedules = Vec::new(&env);
-        let next_id: u64 = env
-            .storage()
-            .persistent()
-            .get(&DataKey::NextScheduleId(program_id.clone()))
-            .unwrap_or(1);
+    /// Cancels every unreleased release schedule of a program being wound
+    /// down and drops their reservations, so their amounts are refunded
+    /// with the rest of the balance.
+    fn cancel_release_schedules(env: &Env, program_data: &mut ProgramData) {
+        let program_id = program_data.program_id.clone();
+        let next_id: u64 =
+            Self::load_persistent(env, &DataKey::NextScheduleId(program_id.clone())).unwrap_or(1);
 
         for schedule_id in 1..next_id {
-            if env
-                .storage()
-                .persistent()
-                .has(&DataKey::ReleaseSchedule(program_id.clone(), schedule_id))
-            {
-                let schedule: ProgramReleaseSchedule = env
-                    .storage()
-                    .persistent()
-                    .get(&DataKey::ReleaseSchedule(program_id.clone(), schedule_id))
-                    .unwrap();
-                schedules.push_back(schedule);
+            let key = DataKey::ReleaseSchedule(program_id.clone(), schedule_id);
+            let schedule: ProgramReleaseSchedule = match Self::load_persistent(env, &key) {
+                Some(schedule) => schedule,
+                None => continue,
+            };
+            if schedule.released {
+                continue;
             }
-        }
 
-        schedules
-    }
+            env.storage().persistent().remove(&key);
+            program_data.reserved_for_schedules -= schedule.amount;
 
-    /// Retrieves pending (unreleased) schedules for a program.
-    ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `program_id` - The program to query
-    ///
-    /// # Returns
-    /// * `Vec<ProgramReleaseSchedule>` - All pending schedules
-    pub fn get_pending_program_schedules(
-        env: Env,
-        program_id: String,
-    ) -> Vec<ProgramReleaseSchedule> {
-        let all_schedules = Self::get_all_prog_release_schedules(env.clone(), program_id.clone());
-        let mut pending = Vec::new(&env);
+            env.events().publish(
+                (PROG_SCHEDULE_CANCELLED,),
+                ProgramScheduleCancelled {
+                    program_id: program_id.clone(),
+                    schedule_id,
+                    amount: schedule.amount,
+                    recipient: schedule.recipient,
+                    cancelled_by: env.current_contract_address(),
+                },
+            );
+        }
+    }
 
-        for schedule in all_schedules.iter() {
-            if !schedule.released {
-                pending.push_back(schedule.clone());
+    /// Sums what unfinalized distributions of a program still owe to
+    /// winners who have not claimed yet.
+    fn outstanding_distribution_total(env: &Env, program_id: &String) -> i128 {
+        let count: u32 =
+            Self::load_persistent(env, &DataKey::DistributionCount(program_id.clone()))
+                .unwrap_or(0);
+
+        let mut total = 0i128;
+        for distribution_id in 0..count {
+            let key = DataKey::Distribution(program_id.clone(), distribution_id);
+            if let Some(distribution) = Self::load_persistent::<Distribution>(env, &key) {
+                if !distribution.finalized {
+                    total += distribution.total - distribution.claimed;
+                }
             }
         }
-
-        pending
+        total
     }
 
-    /// Retrieves due schedules (timestamp passed but not released) for a program.
-    ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `program_id` - The program to query
-    ///
-    /// # Returns
-    /// * `Vec<ProgramReleaseSchedule>` - All due but unreleased schedules
-    pub fn get_due_program_schedules(env: Env, program_id: String) -> Vec<ProgramReleaseSchedule> {
-        let pending = Self::get_pending_program_schedules(env.clone(), program_id.clone());
-        let mut due = Vec::new(&env);
-        let now = env.ledger().timestamp();
+    /// Sums what a program still owes to unreleased release schedules and
+    /// unwithdrawn vesting schedules.
+    fn outstanding_schedule_total(env: &Env, program_id: &String) -> i128 {
+        let next_id: u64 =
+            Self::load_persistent(env, &DataKey::NextScheduleId(program_id.clone())).unwrap_or(1);
 
-        for schedule in pending.iter() {
-            if schedule.release_timestamp <= now {
-                due.push_back(schedule.clone());
+        let mut total = 0i128;
+        for schedule_id in 1..next_id {
+            let release_key = DataKey::ReleaseSchedule(program_id.clone(), schedule_id);
+            let vesting_key = DataKey::VestingSchedule(program_id.clone(), schedule_id);
+            if let Some(schedule) =
+                Self::load_persistent::<ProgramReleaseSchedule>(env, &release_key)
+            {
+                if !schedule.released {
+                    total += schedule.amount;
+                }
+            } else if let Some(vesting) =
+                Self::load_persistent::<ProgramVestingSchedule>(env, &vesting_key)
+            {
+                total += vesting.outstanding();
             }
         }
-
-        due
+        total
     }
 
-    /// Retrieves release history for a program.
+    /// Revokes the unvested remainder of a vesting schedule back to the
+    /// program. What has vested so far stays withdrawable by the recipient.
     ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `program_id` - The program to query
+    /// # Authorization
+    /// - Requires auth from the program's organizer
     ///
     /// # Returns
-    /// * `Vec<ProgramReleaseHistory>` - Complete release history
-    pub fn get_program_release_history(env: Env, program_id: String) -> Vec<ProgramReleaseHistory> {
-        env.storage()
-            .persistent()
-            .get(&DataKey::ReleaseHistory(program_id))
-            .unwrap_or(vec![&env])
-    }
-
-    pub fn expire_program(env: Env, program_id: String) {
+    /// * `i128` - Unvested amount released back to the program balance
+    ///
+    /// # Events
+    /// Emits: `prg_vst_x(program_id, schedule_id, unvested_amount)`
+    ///
+    /// # Panics
+    /// * If the contract is paused or the schedule was already revoked
+    pub fn revoke_vesting_schedule(env: Env, program_id: String, schedule_id: u64) -> i128 {
         if Self::is_paused_internal(&env) {
             panic!("Contract is paused");
         }
 
-        let program_key = DataKey::Program(program_id.clone());
-        let program_data: ProgramData = env
-            .storage()
-            .instance()
-            .get(&program_key)
-            .unwrap_or_else(|| panic!("Program not found"));
+        let mut program_data = Self::get_program_info(env.clone(), program_id.clone());
+        program_data.organizer.require_auth();
 
-        let deadline = program_data
-            .deadline
-            .unwrap_or_else(|| panic!("Program has no deadline"));
+        let mut schedule =
+            Self::get_program_vesting_schedule(env.clone(), program_id.clone(), schedule_id);
+        if schedule.revoked_at.is_some() {
+            panic!("Vesting schedule already revoked");
+        }
 
         let now = env.ledger().timestamp();
-        if now < deadline {
-            panic!("Deadline has not passed yet");
-        }
+        let vested = schedule.vested_at(now);
+        let unvested = schedule.total_amount - vested;
+        schedule.total_amount = vested;
+        schedule.revoked_at = Some(now);
+        Self::store_persistent(
+            &env,
+            &DataKey::VestingSchedule(program_id.clone(), schedule_id),
+            &schedule,
+        );
 
-        if program_data.remaining_balance <= 0 {
-            panic!("No funds to refund");
-        }
+        program_data.reserved_for_schedules -= unvested;
+        Self::store_persistent(&env, &DataKey::Program(program_id.clone()), &program_data);
 
-        let token_client = token::Client::new(&env, &program_data.token_address);
-        let contract_balance = token_client.balance(&env.current_contract_address());
+        env.events().publish(
+            (PROG_VESTING_REVOKED,),
+            (program_id, schedule_id, unvested),
+        );
 
-        if contract_balance < program_data.remaining_balance {
-            panic!("Insufficient contract balance");
-        }
+        unvested
+    }
 
-        token_client.transfer(
-            &env.current_contract_address(),
-            &program_data.organizer,
-            &program_data.remaining_balance,
+    /// Retrieves a vesting schedule of a program.
+    pub fn get_program_vesting_schedule(
+        env: Env,
+        program_id: String,
+        schedule_id: u64,
+    ) -> ProgramVestingSchedule {
+        Self::load_persistent(&env, &DataKey::VestingSchedule(program_id, schedule_id))
+            .unwrap_or_else(|| panic!("Schedule not found"))
+    }
+
+    /// Amount the recipient of a vesting schedule can withdraw right now.
+    pub fn get_withdrawable_vested(env: Env, program_id: String, schedule_id: u64) -> i128 {
+        let schedule = Self::get_program_vesting_schedule(env.clone(), program_id, schedule_id);
+        schedule.vested_at(env.ledger().timestamp()) - schedule.withdrawn
+    }
+
+    // ========================================================================
+    // View Functions (Read-only)
+    // ========================================================================
+
+    /// Retrieves complete program information.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    ///
+    /// # Returns
+    /// * `Ok(ProgramData)` - Complete program state including:
+    ///   - Program ID
+    ///   - Total funds locked
+    ///   - Remaining balance
+    ///   - Authorized payout key
+    ///   - Number of payouts made
+    ///   - Token contract address
+    ///   - Lifecycle status
+    /// * `Err(Error::NotInitialized)` - Program not initialized
+    ///
+    /// # Use Cases
+    /// - Verifying program configuration
+    /// - Checking balances before payouts
+    /// - Auditing payout history
+    /// - Displaying program status in UI
+    ///
+    /// # Example
+    /// ```rust
+    /// let info = escrow_client.get_program_info();
+    /// println!("Program: {}", info.program_id);
+    /// println!("Total Locked: {}", info.total_funds);
+    /// println!("Remaining: {}", info.remaining_balance);
+    /// println!("Payouts Made: {}", info.payout_count);
+    /// ```
+    ///
+    /// # Gas Cost
+    /// Very Low - Single storage read
+    pub fn get_program_info(env: Env, program_id: String) -> ProgramData {
+        let program_key = DataKey::Program(program_id);
+        Self::load_persistent(&env, &program_key).unwrap_or_else(|| panic!("Program not found"))
+    }
+
+    /// Retrieves the remaining balance for a specific program.
+    ///
+    /// # Arguments
+    /// * `program_id` - The program ID to query
+    ///
+    /// # Returns
+    /// * `i128` - Remaining balance
+    ///
+    /// # Panics
+    /// * If program doesn't exist
+    pub fn get_remaining_balance(env: Env, program_id: String) -> i128 {
+        let program_key = DataKey::Program(program_id);
+        let program_data: ProgramData = Self::load_persistent(&env, &program_key)
+            .unwrap_or_else(|| panic!("Program not found"));
+
+        program_data.remaining_balance
+    }
+
+    /// Retrieves the part of a program's remaining balance that is not
+    /// reserved for release or vesting schedules.
+    ///
+    /// # Arguments
+    /// * `program_id` - The program ID to query
+    ///
+    /// # Returns
+    /// * `i128` - `remaining_balance - reserved_for_schedules`
+    ///
+    /// # Panics
+    /// * If program doesn't exist
+    pub fn get_available_balance(env: Env, program_id: String) -> i128 {
+        let program_data = Self::get_program_info(env, program_id);
+        program_data.remaining_balance - program_data.reserved_for_schedules
+    }
+
+    /// Returns a page of a program's payout history, oldest first.
+    ///
+    /// # Arguments
+    /// * `program_id` - The program to query
+    /// * `cursor` - Index of the first payout to return
+    /// * `limit` - Maximum number of records (1 to 100)
+    ///
+    /// # Returns
+    /// * `Vec<PayoutRecord>` - Up to `limit` records starting at `cursor`;
+    ///   empty once `cursor` reaches `payout_count`
+    ///
+    /// # Panics
+    /// * If program doesn't exist or `limit` is out of range
+    pub fn get_payout_history(
+        env: Env,
+        program_id: String,
+        cursor: u32,
+        limit: u32,
+    ) -> Vec<PayoutRecord> {
+        Self::validate_page_limit(limit);
+        let program_data = Self::get_program_info(env.clone(), program_id.clone());
+
+        let end = cursor.saturating_add(limit).min(program_data.payout_count);
+        let mut records = vec![&env];
+        for index in cursor..end {
+            let record: PayoutRecord =
+                Self::load_persistent(&env, &DataKey::Payout(program_id.clone(), index)).unwrap();
+            records.push_back(record);
+        }
+        records
+    }
+
+    /// Returns a page of the payouts a recipient received from a program,
+    /// oldest first.
+    ///
+    /// # Arguments
+    /// * `program_id` - The program to query
+    /// * `recipient` - The recipient to filter by
+    /// * `cursor` - Position among the recipient's payouts to start at
+    /// * `limit` - Maximum number of records (1 to 100)
+    ///
+    /// # Panics
+    /// * If `limit` is out of range
+    pub fn get_payouts_by_recipient(
+        env: Env,
+        program_id: String,
+        recipient: Address,
+        cursor: u32,
+        limit: u32,
+    ) -> Vec<PayoutRecord> {
+        Self::validate_page_limit(limit);
+
+        let count: u32 = Self::load_persistent(
+            &env,
+            &DataKey::RecipientPayoutCount(program_id.clone(), recipient.clone()),
+        )
+        .unwrap_or(0);
+
+        let end = cursor.saturating_add(limit).min(count);
+        let mut records = vec![&env];
+        for n in cursor..end {
+            let index: u32 = Self::load_persistent(
+                &env,
+                &DataKey::RecipientPayout(program_id.clone(), recipient.clone(), n),
+            )
+            .unwrap();
+            let record: PayoutRecord =
+                Self::load_persistent(&env, &DataKey::Payout(program_id.clone(), index)).unwrap();
+            records.push_back(record);
+        }
+        records
+    }
+
+    /// Checks that the contract's token holdings cover every program's
+    /// `remaining_balance` and unclaimed distribution amounts, one entry per
+    /// token in use.
+    ///
+    /// # Returns
+    /// * `Vec<TokenReconciliation>` - Balance vs. obligations for each token
+    ///
+    /// # Usage
+    /// Funds recorded through `lock_program_funds` without a prior transfer
+    /// show up here as `is_covered == false`.
+    pub fn reconcile_program_balances(env: Env) -> Vec<TokenReconciliation> {
+        let registry = Self::list_programs(env.clone());
+
+        let mut tokens: Vec<Address> = vec![&env];
+        let mut totals: Vec<i128> = vec![&env];
+        for program_id in registry.iter() {
+            let program_data: ProgramData =
+                match Self::load_persistent(&env, &DataKey::Program(program_id)) {
+                    Some(data) => data,
+                    None => continue,
+                };
+            // Published distributions are already taken out of
+            // `remaining_balance` but stay held until claimed or finalized
+            let owed = program_data.remaining_balance
+                + Self::outstanding_distribution_total(&env, &program_data.program_id);
+            match tokens.first_index_of(&program_data.token_address) {
+                Some(i) => {
+                    let total = totals.get(i).unwrap() + owed;
+                    totals.set(i, total);
+                }
+                None => {
+                    tokens.push_back(program_data.token_address);
+                    totals.push_back(owed);
+                }
+            }
+        }
+
+        let contract_address = env.current_contract_address();
+        let mut report = vec![&env];
+        for i in 0..tokens.len() {
+            let token_address = tokens.get(i).unwrap();
+            let total_remaining = totals.get(i).unwrap();
+            let contract_balance =
+                token::Client::new(&env, &token_address).balance(&contract_address);
+            report.push_back(TokenReconciliation {
+                token_address,
+                contract_balance,
+                total_remaining,
+                is_covered: contract_balance >= total_remaining,
+            });
+        }
+        report
+    }
+
+    /// Update the default fee configuration (admin only)
+    ///
+    /// Defaults apply to every program without an approved override.
+    ///
+    /// # Arguments
+    /// * `lock_fee_rate` - Optional new lock fee rate (basis points)
+    /// * `payout_fee_rate` - Optional new payout fee rate (basis points)
+    /// * `fee_recipient` - Optional new fee recipient address
+    /// * `fee_enabled` - Optional fee enable/disable flag
+    pub fn update_fee_config(
+        env: Env,
+        lock_fee_rate: Option<i128>,
+        payout_fee_rate: Option<i128>,
+        fee_recipient: Option<Address>,
+        fee_enabled: Option<bool>,
+    ) {
+        let admin = anti_abuse::get_admin(&env).expect("Admin not set");
+        admin.require_auth();
+
+        let mut fee_config = Self::get_fee_config_internal(&env);
+
+        if let Some(rate) = lock_fee_rate {
+            fee_config.lock_fee_rate = rate;
+        }
+
+        if let Some(rate) = payout_fee_rate {
+            fee_config.payout_fee_rate = rate;
+        }
+
+        if let Some(recipient) = fee_recipient {
+            fee_config.fee_recipient = recipient;
+        }
+
+        if let Some(enabled) = fee_enabled {
+            fee_config.fee_enabled = enabled;
+        }
+
+        Self::validate_fee_rates(fee_config.lock_fee_rate, fee_config.payout_fee_rate);
+        env.storage().instance().set(&FEE_CONFIG, &fee_config);
+
+        // Emit fee config updated event
+        env.events().publish(
+            (symbol_short!("fee_cfg"),),
+            (
+                fee_config.lock_fee_rate,
+                fee_config.payout_fee_rate,
+                fee_config.fee_recipient,
+                fee_config.fee_enabled,
+            ),
         );
+    }
+
+    /// Get the default fee configuration (view function)
+    pub fn get_fee_config(env: Env) -> FeeConfig {
+        Self::get_fee_config_internal(&env)
+    }
+
+    /// Proposes a fee override for a program. It only takes effect once the
+    /// admin approves it with `approve_program_fee_config`.
+    ///
+    /// # Authorization
+    /// - Requires auth from the program's authorized payout key
+    ///
+    /// # Events
+    /// Emits: `fee_prop(program_id, lock_fee_rate, payout_fee_rate, fee_recipient, fee_enabled)`
+    pub fn propose_program_fee_config(
+        env: Env,
+        program_id: String,
+        lock_fee_rate: i128,
+        payout_fee_rate: i128,
+        fee_recipient: Address,
+        fee_enabled: bool,
+    ) {
+        let program_data: ProgramData =
+            Self::load_persistent(&env, &DataKey::Program(program_id.clone()))
+                .unwrap_or_else(|| panic!("Program not found"));
+        program_data.authorized_payout_key.require_auth();
 
-        let mut updated_program = program_data.clone();
-        updated_program.remaining_balance = 0;
-        env.storage().instance().set(&program_key, &updated_program);
+        Self::validate_fee_rates(lock_fee_rate, payout_fee_rate);
+        let proposal = FeeConfig {
+            lock_fee_rate,
+            payout_fee_rate,
+            fee_recipient,
+            fee_enabled,
+        };
+        let pending_key = DataKey::PendingFeeConfig(program_id.clone());
+        Self::store_persistent(&env, &pending_key, &proposal);
 
         env.events().publish(
-            (symbol_short!("expired"),),
+            (symbol_short!("fee_prop"),),
             (
                 program_id,
-                program_data.remaining_balance,
-                program_data.organizer,
-                now,
+                proposal.lock_fee_rate,
+                proposal.payout_fee_rate,
+                proposal.fee_recipient,
+                proposal.fee_enabled,
             ),
         );
     }
-    // ========================================================================
-    // Admin Functions
-    // ========================================================================
 
-    /// Update Admin
+    /// Approves the pending fee override of a program (admin only).
     ///
-    /// # Arguments
-    /// * `new_admin` - New Admin address
-    /// Admin Require Auth
-    pub fn update_admin(env: Env, new_admin: Address) {
-        let current_admin = anti_abuse::get_admin(&env).unwrap();
-        current_admin.require_auth();
+    /// `expected` must equal the pending proposal, so a proposal replaced
+    /// after the admin reviewed it cannot be approved by mistake.
+    ///
+    /// # Events
+    /// Emits: `fee_appr(program_id, lock_fee_rate, payout_fee_rate, fee_recipient, fee_enabled)`
+    pub fn approve_program_fee_config(env: Env, program_id: String, expected: FeeConfig) {
+        let admin = anti_abuse::get_admin(&env).expect("Admin not set");
+        admin.require_auth();
 
-        let last_update: u64 = env
-            .storage()
-            .instance()
-            .get(&anti_abuse::AntiAbuseKey::LastAdminUpdate)
-            .unwrap_or(0);
-        let current_time = env.ledger().timestamp();
-        if current_time < last_update + ADMIN_UPDATE_TIMELOCK {
-            panic!("TimeLock");
+        let pending_key = DataKey::PendingFeeConfig(program_id.clone());
+        let fee_config: FeeConfig = Self::load_persistent(&env, &pending_key)
+            .unwrap_or_else(|| panic!("No pending fee config"));
+        if fee_config != expected {
+            panic!("Pending fee config does not match");
         }
 
+        env.storage().persistent().remove(&pending_key);
+        let config_key = DataKey::ProgramFeeConfig(program_id.clone());
+        Self::store_persistent(&env, &config_key, &fee_config);
+
+        env.events().publish(
+            (symbol_short!("fee_appr"),),
+            (
+                program_id,
+                fee_config.lock_fee_rate,
+                fee_config.payout_fee_rate,
+                fee_config.fee_recipient,
+                fee_config.fee_enabled,
+            ),
+        );
+    }
+
+    /// Drops a program's fee override so it falls back to the defaults
+    /// (admin only).
+    ///
+    /// # Events
+    /// Emits: `fee_clr(program_id)`
+    pub fn clear_program_fee_config(env: Env, program_id: String) {
+        let admin = anti_abuse::get_admin(&env).expect("Admin not set");
+        admin.require_auth();
+
         env.storage()
-            .instance()
-            .set(&anti_abuse::AntiAbuseKey::Admin, &new_admin);
+            .persistent()
+            .remove(&DataKey::ProgramFeeConfig(program_id.clone()));
         env.storage()
-            .instance()
-            .set(&anti_abuse::AntiAbuseKey::LastAdminUpdate, &current_time);
+            .persistent()
+            .remove(&DataKey::PendingFeeConfig(program_id.clone()));
+
+        env.events()
+            .publish((symbol_short!("fee_clr"),), (program_id,));
+    }
+
+    /// Returns the fee configuration in effect for a program (view function)
+    pub fn get_program_fee_config(env: Env, program_id: String) -> FeeConfig {
+        if !env
+            .storage()
+            .persistent()
+            .has(&DataKey::Program(program_id.clone()))
+        {
+            panic!("Program not found");
+        }
+        Self::get_program_fee_config_internal(&env, &program_id)
+    }
+
+    /// Returns the fee override awaiting admin approval for a program, if any
+    pub fn get_pending_program_fee_config(env: Env, program_id: String) -> Option<FeeConfig> {
+        Self::load_persistent(&env, &DataKey::PendingFeeConfig(program_id))
+    }
+
+    /// Update amount limits configuration (admin only)
+    pub fn update_amount_limits(
+        env: Env,
+        min_lock_amount: i128,
+        max_lock_amount: i128,
+        min_payout: i128,
+        max_payout: i128,
+    ) {
+        // Get admin and require authorization
+        let admin = anti_abuse::get_admin(&env).expect("Admin not set");
+        admin.require_auth();
+
+        // Validate limits
+        if min_lock_amount < 0 || max_lock_amount < 0 || min_payout < 0 || max_payout < 0 {
+            panic!("Invalid amount: amounts cannot be negative");
+        }
+        if min_lock_amount > max_lock_amount || min_payout > max_payout {
+            panic!("Invalid amount: minimum cannot exceed maximum");
+        }
+
+        let limits = AmountLimits {
+            min_lock_amount,
+            max_lock_amount,
+            min_payout,
+            max_payout,
+        };
+
+        env.storage().instance().set(&DataKey::AmountLimits, &limits);
+
+        // Emit event
+        env.events().publish(
+            (symbol_short!("amt_lmt"),),
+            (min_lock_amount, max_lock_amount, min_payout, max_payout),
+        );
+    }
+
+    /// Get current amount limits configuration (view function)
+    pub fn get_amount_limits(env: Env) -> AmountLimits {
+        env.storage()
+            .instance()
+            .get(&DataKey::AmountLimits)
+            .unwrap_or(AmountLimits {
+                min_lock_amount: 1,
+                max_lock_amount: i128::MAX,
+                min_payout: 1,
+                max_payout: i128::MAX,
+            })
+    }
+
+    /// Gets the total number of programs registered.
+    ///
+    /// # Returns
+    /// * `u32` - Count of registered programs
+    pub fn get_program_count(env: Env) -> u32 {
+        Self::load_persistent(&env, &DataKey::ProgramCount).unwrap_or(0)
+    }
+
+    // ========================================================================
+    // Monitoring & Analytics Functions
+    // ========================================================================
+
+    /// Health check - returns contract health status
+    pub fn health_check(env: Env) -> monitoring::HealthStatus {
+        monitoring::health_check(&env)
+    }
+
+    /// Get analytics - returns usage analytics
+    pub fn get_analytics(env: Env) -> monitoring::Analytics {
+        monitoring::get_analytics(&env)
+    }
+
+    /// Get state snapshot - returns current state
+    pub fn get_state_snapshot(env: Env) -> monitoring::StateSnapshot {
+        monitoring::get_state_snapshot(&env)
+    }
+
+    /// Get performance stats for a function
+    pub fn get_performance_stats(env: Env, function_name: Symbol) -> monitoring::PerformanceStats {
+        monitoring::get_performance_stats(&env, function_name)
+    }
+
+    // ========================================================================
+    // Anti-Abuse Administrative Functions
+    // ========================================================================
+
+    /// Sets the administrative address for anti-abuse configuration.
+    /// Can only be called once or by the existing admin.
+    pub fn set_admin(env: Env, new_admin: Address) {
+        if let Some(current_admin) = anti_abuse::get_admin(&env) {
+            current_admin.require_auth();
+        }
+        anti_abuse::set_admin(&env, new_admin);
+    }
+
+    pub fn get_admin(env: Env) -> Option<Address> {
+        anti_abuse::get_admin(&env)
+    }
+
+    /// Updates the rate limit configuration.
+    /// Only the admin can call this.
+    pub fn update_rate_limit_config(
+        env: Env,
+        window_size: u64,
+        max_operations: u32,
+        cooldown_period: u64,
+    ) {
+        let admin = anti_abuse::get_admin(&env).expect("Admin not set");
+        admin.require_auth();
+
+        anti_abuse::set_config(
+            &env,
+            anti_abuse::AntiAbuseConfig {
+                window_size,
+                max_operations,
+                cooldown_period,
+            },
+        );
+    }
+
+    /// Adds or removes an address from the whitelist.
+    /// Only the admin can call this.
+    pub fn set_whitelist(env: Env, address: Address, whitelisted: bool) {
+        let admin = anti_abuse::get_admin(&env).expect("Admin not set");
+        admin.require_auth();
+
+        anti_abuse::set_whitelist(&env, address, whitelisted);
+    }
+
+    /// Checks if an address is whitelisted.
+    pub fn is_whitelisted(env: Env, address: Address) -> bool {
+        anti_abuse::is_whitelisted(&env, address)
+    }
+
+    /// Gets the current rate limit configuration.
+    pub fn get_rate_limit_config(env: Env) -> anti_abuse::AntiAbuseConfig {
+        anti_abuse::get_config(&env)
+    }
+
+    // ========================================================================
+    // Schedule View Functions
+    // ========================================================================
+
+    /// Retrieves a specific program release schedule.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `program_id` - The program containing the schedule
+    /// * `schedule_id` - The schedule ID to retrieve
+    ///
+    /// # Returns
+    /// * `ProgramReleaseSchedule` - The schedule details
+    ///
+    /// # Panics
+    /// * If schedule doesn't exist
+    pub fn get_program_release_schedule(
+        env: Env,
+        program_id: String,
+        schedule_id: u64,
+    ) -> ProgramReleaseSchedule {
+        Self::load_persistent(&env, &DataKey::ReleaseSchedule(program_id, schedule_id))
+            .unwrap_or_else(|| panic!("Schedule not found"))
+    }
+
+    /// Retrieves all release schedules for a program.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `program_id` - The program to query
+    ///
+    /// # Returns
+    /// * `Vec<ProgramReleaseSchedule>` - All schedules for the program
+    pub fn get_all_prog_release_schedules(
+        env: Env,
+        program_id: String,
+    ) -> Vec<ProgramReleaseSchedule> {
+        let mut schedules = Vec::new(&env);
+        let next_id: u64 =
+            Self::load_persistent(&env, &DataKey::NextScheduleId(program_id.clone())).unwrap_or(1);
+
+        for schedule_id in 1..next_id {
+            if env
+                .storage()
+                .persistent()
+                .has(&DataKey::ReleaseSchedule(program_id.clone(), schedule_id))
+            {
+                let schedule: ProgramReleaseSchedule = Self::load_persistent(
+                    &env,
+                    &DataKey::ReleaseSchedule(program_id.clone(), schedule_id),
+                )
+                .unwrap();
+                schedules.push_back(schedule);
+            }
+        }
+
+        schedules
+    }
+
+    /// Retrieves pending (unreleased) schedules for a program.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `program_id` - The program to query
+    ///
+    /// # Returns
+    /// * `Vec<ProgramReleaseSchedule>` - All pending schedules
+    pub fn get_pending_program_schedules(
+        env: Env,
+        program_id: String,
+    ) -> Vec<ProgramReleaseSchedule> {
+        let all_schedules = Self::get_all_prog_release_schedules(env.clone(), program_id.clone());
+        let mut pending = Vec::new(&env);
+
+        for schedule in all_schedules.iter() {
+            if !schedule.released {
+                pending.push_back(schedule.clone());
+            }
+        }
+
+        pending
+    }
+
+    /// Retrieves due schedules (timestamp passed but not released) for a program.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `program_id` - The program to query
+    ///
+    /// # Returns
+    /// * `Vec<ProgramReleaseSchedule>` - All due but unreleased schedules
+    pub fn get_due_program_schedules(env: Env, program_id: String) -> Vec<ProgramReleaseSchedule> {
+        let pending = Self::get_pending_program_schedules(env.clone(), program_id.clone());
+        let mut due = Vec::new(&env);
+        let now = env.ledger().timestamp();
+
+        for schedule in pending.iter() {
+            if schedule.release_timestamp <= now {
+                due.push_back(schedule.clone());
+            }
+        }
+
+        due
+    }
+
+    /// Retrieves release history for a program.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `program_id` - The program to query
+    ///
+    /// # Returns
+    /// * `Vec<ProgramReleaseHistory>` - Complete release history
+    pub fn get_program_release_history(env: Env, program_id: String) -> Vec<ProgramReleaseHistory> {
+        Self::load_persistent(&env, &DataKey::ReleaseHistory(program_id)).unwrap_or(vec![&env])
+    }
+
+    /// Refunds the unspent balance of a program whose deadline has passed.
+    ///
+    /// Sponsors that funded the program through `lock_program_funds_from` get
+    /// the balance back pro-rata to their contributions; see
+    /// `get_program_sponsors`. Programs without recorded sponsors are
+    /// refunded to their organizer.
+    ///
+    /// The program moves to `Closed`.
+    ///
+    /// # Panics
+    /// * If the contract is paused or the program doesn't exist
+    /// * If the program is not `Open`, `Judging` or `Payout`
+    /// * If the program has no deadline or it has not passed yet
+    /// * If there are no funds left to refund
+    pub fn expire_program(env: Env, program_id: String) {
+        if Self::is_paused_internal(&env) {
+            panic!("Contract is paused");
+        }
+
+        let program_key = DataKey::Program(program_id.clone());
+        let mut program_data: ProgramData = Self::load_persistent(&env, &program_key)
+            .unwrap_or_else(|| panic!("Program not found"));
+
+        if !program_data.status.allows_payouts() {
+            panic!("Program cannot expire in its current state");
+        }
+
+        let deadline = program_data
+            .deadline
+            .unwrap_or_else(|| panic!("Program has no deadline"));
+
+        let now = env.ledger().timestamp();
+        if now < deadline {
+            panic!("Deadline has not passed yet");
+        }
+
+        if program_data.remaining_balance <= 0 {
+            panic!("No funds to refund");
+        }
+
+        let refunded = Self::refund_remaining(&env, &mut program_data);
+        Self::apply_status(
+            &env,
+            &mut program_data,
+            ProgramStatus::Closed,
+            env.current_contract_address(),
+        );
+        Self::store_persistent(&env, &program_key, &program_data);
+
+        env.events().publish(
+            (symbol_short!("expired"),),
+            (program_id, refunded, program_data.organizer, now),
+        );
+    }
+
+    /// Closes a program once payouts are done, refunding its unspent
+    /// balance the same way `expire_program` does. The program moves from
+    /// `Payout` to `Closed`, subject to the same transition rules as
+    /// `set_program_status`.
+    ///
+    /// # Authorization
+    /// - Requires auth from the program's authorized payout key
+    ///
+    /// # Events
+    /// Emits: `closed(program_id, refunded_amount, timestamp)`
+    ///
+    /// # Panics
+    /// * If the contract is paused or the program doesn't exist
+    /// * If the program is not in `Payout`
+    pub fn close_program(env: Env, program_id: String) {
+        if Self::is_paused_internal(&env) {
+            panic!("Contract is paused");
+        }
+
+        let program_key = DataKey::Program(program_id.clone());
+        let mut program_data: ProgramData = Self::load_persistent(&env, &program_key)
+            .unwrap_or_else(|| panic!("Program not found"));
+
+        program_data.authorized_payout_key.require_auth();
+
+        let payout_key = program_data.authorized_payout_key.clone();
+        let refunded =
+            Self::transition_program(&env, &mut program_data, ProgramStatus::Closed, payout_key);
+        Self::store_persistent(&env, &program_key, &program_data);
+
+        env.events().publish(
+            (symbol_short!("closed"),),
+            (program_id, refunded, env.ledger().timestamp()),
+        );
+    }
+
+    /// Moves a program to another lifecycle state.
+    ///
+    /// Allowed transitions are `Draft -> Open -> Judging -> Payout -> Closed`,
+    /// `Judging -> Open`, and `Draft`/`Open`/`Judging -> Cancelled`. Closing
+    /// or cancelling refunds any unspent balance to the sponsors.
+    ///
+    /// # Arguments
+    /// * `program_id` - The program to update
+    /// * `caller` - The program's organizer or the contract admin
+    /// * `status` - The state to move to
+    ///
+    /// # Events
+    /// Emits: `prg_state(program_id, from, to, caller, timestamp)`
+    ///
+    /// # Panics
+    /// * If the contract is paused
+    /// * If `caller` is neither the organizer nor the admin
+    /// * If the transition is not allowed
+    pub fn set_program_status(
+        env: Env,
+        program_id: String,
+        caller: Address,
+        status: ProgramStatus,
+    ) -> ProgramData {
+        if Self::is_paused_internal(&env) {
+            panic!("Contract is paused");
+        }
+
+        let program_key = DataKey::Program(program_id.clone());
+        let mut program_data: ProgramData = Self::load_persistent(&env, &program_key)
+            .unwrap_or_else(|| panic!("Program not found"));
+
+        let is_admin = anti_abuse::get_admin(&env) == Some(caller.clone());
+        if caller != program_data.organizer && !is_admin {
+            panic!("Unauthorized: only the organizer or admin can change program state");
+        }
+        caller.require_auth();
+
+        Self::transition_program(&env, &mut program_data, status, caller);
+        Self::store_persistent(&env, &program_key, &program_data);
+
+        program_data
+    }
+
+    /// Checks a requested lifecycle transition and applies it, refunding the
+    /// unspent balance when the program is closed or cancelled. Returns the
+    /// amount refunded. The caller persists `program_data`.
+    fn transition_program(
+        env: &Env,
+        program_data: &mut ProgramData,
+        status: ProgramStatus,
+        actor: Address,
+    ) -> i128 {
+        if !program_data.status.can_transition_to(status) {
+            panic!("Invalid program state transition");
+        }
+
+        let mut refunded = 0;
+        if matches!(status, ProgramStatus::Closed | ProgramStatus::Cancelled)
+            && program_data.remaining_balance > 0
+        {
+            refunded = Self::refund_remaining(env, program_data);
+        }
+        Self::apply_status(env, program_data, status, actor);
+        refunded
+    }
+
+    /// Sets the program's status and emits the transition event. The caller
+    /// persists `program_data`.
+    fn apply_status(
+        env: &Env,
+        program_data: &mut ProgramData,
+        status: ProgramStatus,
+        actor: Address,
+    ) {
+        let from = program_data.status;
+        program_data.status = status;
+
+        env.events().publish(
+            (symbol_short!("prg_state"),),
+            (
+                program_data.program_id.clone(),
+                from,
+                status,
+                actor,
+                env.ledger().timestamp(),
+            ),
+        );
+    }
+
+    /// Returns the sponsors of a program and how much each contributed.
+    ///
+    /// # Arguments
+    /// * `program_id` - The program to query
+    ///
+    /// # Returns
+    /// * `Vec<SponsorContribution>` - Sponsors in order of first contribution
+    pub fn get_program_sponsors(env: Env, program_id: String) -> Vec<SponsorContribution> {
+        Self::load_persistent(&env, &DataKey::Sponsors(program_id)).unwrap_or(vec![&env])
+    }
+
+    /// Returns the net amount the organizer locked through
+    /// [`lock_program_funds`](Self::lock_program_funds).
+    ///
+    /// # Arguments
+    /// * `program_id` - The program to query
+    pub fn get_organizer_contribution(env: Env, program_id: String) -> i128 {
+        Self::load_persistent(&env, &DataKey::OrganizerFunds(program_id)).unwrap_or(0)
+    }
+
+    /// Sets the address receiving rounding dust left over by pro-rata
+    /// sponsor refunds. Only the admin can call this.
+    pub fn set_dust_recipient(env: Env, recipient: Address) {
+        let admin = anti_abuse::get_admin(&env).expect("Admin not set");
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&DataKey::DustRecipient, &recipient);
+    }
+
+    /// Address receiving refund rounding dust (`None` means the organizer).
+    pub fn get_dust_recipient(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::DustRecipient)
+    }
+
+    /// Pays out a program's remaining balance pro-rata to what each funder
+    /// contributed, sending rounding dust to the dust recipient, and zeroes
+    /// the balance. The organizer's share of funds locked through
+    /// `lock_program_funds` goes back to the organizer and only the rest is
+    /// split between sponsors. Programs without sponsors go to the organizer.
+    /// Unspent track budgets are refunded with it and drop to zero.
+    ///
+    /// Vested but unwithdrawn amounts are paid to their recipients first and
+    /// are not part of the refund. Pending release schedules are cancelled
+    /// and their amounts refunded. Returns the amount refunded.
+    fn refund_remaining(env: &Env, program_data: &mut ProgramData) -> i128 {
+        Self::settle_vesting_schedules(env, program_data);
+        Self::cancel_release_schedules(env, program_data);
+
+        let amount = program_data.remaining_balance;
+        let contract_address = env.current_contract_address();
+        let token_client = token::Client::new(env, &program_data.token_address);
+
+        if token_client.balance(&contract_address) < amount {
+            panic!("Insufficient contract balance");
+        }
+
+        let sponsors_key = DataKey::Sponsors(program_data.program_id.clone());
+        let mut sponsors: Vec<SponsorContribution> =
+            Self::load_persistent(env, &sponsors_key).unwrap_or(vec![env]);
+
+        let mut sponsored: i128 = 0;
+        for entry in sponsors.iter() {
+            sponsored += entry.contributed;
+        }
+
+        if sponsored <= 0 {
+            token_client.transfer(&contract_address, &program_data.organizer, &amount);
+        } else {
+            let organizer_funds: i128 = Self::load_persistent(
+                env,
+                &DataKey::OrganizerFunds(program_data.program_id.clone()),
+            )
+            .unwrap_or(0);
+            let total_contributed = sponsored + organizer_funds;
+
+            let mut distributed: i128 = 0;
+            let organizer_share = amount
+                .checked_mul(organizer_funds)
+                .unwrap_or_else(|| panic!("Refund amount overflow"))
+                / total_contributed;
+            if organizer_share > 0 {
+                token_client.transfer(&contract_address, &program_data.organizer, &organizer_share);
+                distributed += organizer_share;
+
+                env.events().publish(
+                    (symbol_short!("org_refnd"),),
+                    (
+                        program_data.program_id.clone(),
+                        program_data.organizer.clone(),
+                        organizer_share,
+                    ),
+                );
+            }
+
+            for i in 0..sponsors.len() {
+                let mut entry = sponsors.get(i).unwrap();
+                let share = amount
+                    .checked_mul(entry.contributed)
+                    .unwrap_or_else(|| panic!("Refund amount overflow"))
+                    / total_contributed;
+                if share == 0 {
+                    continue;
+                }
+
+                token_client.transfer(&contract_address, &entry.sponsor, &share);
+                entry.refunded += share;
+                distributed += share;
+
+                env.events().publish(
+                    (symbol_short!("sp_refund"),),
+                    (program_data.program_id.clone(), entry.sponsor.clone(), share),
+                );
+                sponsors.set(i, entry);
+            }
+            Self::store_persistent(env, &sponsors_key, &sponsors);
+
+            let dust = amount - distributed;
+            if dust > 0 {
+                let dust_recipient: Address = env
+                    .storage()
+                    .instance()
+                    .get(&DataKey::DustRecipient)
+                    .unwrap_or(program_data.organizer.clone());
+                token_client.transfer(&contract_address, &dust_recipient, &dust);
+            }
+        }
+
+        program_data.remaining_balance = 0;
+        program_data.reserved_for_schedules = 0;
+
+        for mut track in Self::get_program_tracks(env.clone(), program_data.program_id.clone()) {
+            if track.remaining != 0 {
+                track.remaining = 0;
+                Self::store_track(env, &program_data.program_id, &track);
+            }
+        }
+        amount
+    }
+
+    // ========================================================================
+    // Admin Functions
+    // ========================================================================
+
+    /// Migrates programs registered by earlier versions of the contract out
+    /// of instance storage (admin only).
+    ///
+    /// Moves programs from the legacy instance registry into persistent
+    /// storage, splitting their inline payout history into keyed payout
+    /// records and rebuilding the reservation of pending schedules, and
+    /// extends the TTL of every entry it writes.
+    ///
+    /// Each call copies at most `limit` payout records and migrates at most
+    /// `limit` programs. A program whose history does not fit is resumed
+    /// from the next uncopied record on the following call. Call repeatedly
+    /// until it returns 0; until a program is migrated it is reported as
+    /// not found.
+    ///
+    /// # Returns
+    /// * `u32` - Number of legacy programs still awaiting migration
+    ///
+    /// # Events
+    /// Emits: `migrated(program_id, payout_count)` per program
+    pub fn migrate_legacy_programs(env: Env, limit: u32) -> u32 {
+        let admin = anti_abuse::get_admin(&env).expect("Admin not set");
+        admin.require_auth();
+
+        let mut legacy: Vec<String> = env
+            .storage()
+            .instance()
+            .get(&PROGRAM_REGISTRY)
+            .unwrap_or(vec![&env]);
+
+        let mut migrated = 0;
+        let mut copied = 0;
+        while migrated < limit && !legacy.is_empty() {
+            let program_id = legacy.get(0).unwrap();
+            let program_key = DataKey::Program(program_id.clone());
+            let old: LegacyProgramData = match env.storage().instance().get(&program_key) {
+                Some(old) => old,
+                None => {
+                    legacy.pop_front();
+                    migrated += 1;
+                    continue;
+                }
+            };
+
+            let progress_key = DataKey::LegacyPayoutsMigrated(program_id.clone());
+            let done: u32 = env.storage().instance().get(&progress_key).unwrap_or(0);
+            let mut program_data = ProgramData {
+                program_id: old.program_id,
+                total_funds: old.total_funds,
+                remaining_balance: old.remaining_balance,
+                authorized_payout_key: old.authorized_payout_key,
+                payout_count: done,
+                token_address: old.token_address,
+                deadline: old.deadline,
+                organizer: old.organizer,
+                status: ProgramStatus::Open,
+                reserved_for_schedules: 0,
+            };
+
+            let end = old
+                .payout_history
+                .len()
+                .min(done.saturating_add(limit - copied));
+            for index in done..end {
+                let record = old.payout_history.get(index).unwrap();
+                Self::record_payout(&env, &mut program_data, record);
+            }
+            copied += end - done;
+            if end < old.payout_history.len() {
+                env.storage().instance().set(&progress_key, &end);
+                break;
+            }
+
+            env.storage().instance().remove(&progress_key);
+            env.storage().instance().remove(&program_key);
+            program_data.reserved_for_schedules =
+                Self::outstanding_schedule_total(&env, &program_id);
+            Self::store_persistent(&env, &program_key, &program_data);
+            Self::register_program_id(&env, program_id.clone());
+            legacy.pop_front();
+            migrated += 1;
+
+            env.events().publish(
+                (symbol_short!("migrated"),),
+                (program_id, program_data.payout_count),
+            );
+        }
+
+        if legacy.is_empty() {
+            env.storage().instance().remove(&PROGRAM_REGISTRY);
+        } else {
+            env.storage().instance().set(&PROGRAM_REGISTRY, &legacy);
+        }
+        legacy.len()
+    }
+
+    /// Update Admin
+    ///
+    /// # Arguments
+    /// * `new_admin` - New Admin address
+    /// Admin Require Auth
+    pub fn update_admin(env: Env, new_admin: Address) {
+        let current_admin = anti_abuse::get_admin(&env).unwrap();
+        current_admin.require_auth();
+
+        let last_update: u64 = env
+            .storage()
+            .instance()
+            .get(&anti_abuse::AntiAbuseKey::LastAdminUpdate)
+            .unwrap_or(0);
+        let current_time = env.ledger().timestamp();
+        if current_time < last_update + ADMIN_UPDATE_TIMELOCK {
+            panic!("TimeLock");
+        }
+
+        env.storage()
+            .instance()
+            .set(&anti_abuse::AntiAbuseKey::Admin, &new_admin);
+        env.storage()
+            .instance()
+            .set(&anti_abuse::AntiAbuseKey::LastAdminUpdate, &current_time);
+
+        emit_update_admin(
+            &env,
+            UpdateAdminEvent {
+                admin: current_admin,
+                new_admin,
+                timestamp: current_time,
+            },
+        );
+    }
+
+    /// Update Authorized Payout Key
+    ///
+    /// # Arguments
+    /// * `new_admin` - New Authorized Payout Key address
+    /// Admin Require Auth
+    pub fn update_authorized_payout_key(
+        env: Env,
+        program_id: String,
+        authorized_payout_key: Address,
+    ) {
+        let current_admin = anti_abuse::get_admin(&env).unwrap();
+        current_admin.require_auth();
+
+        let program_key = DataKey::Program(program_id.clone());
+        let mut program_data = Self::get_program_info(env.clone(), program_id);
+        program_data.authorized_payout_key = authorized_payout_key.clone();
+        Self::store_persistent(&env, &program_key, &program_data);
+
+        emit_update_authorized_key(
+            &env,
+            UpdateAuthorizedKeyEvent {
+                old_authorized_payout_key: program_data.authorized_payout_key,
+                new_authorized_payout_key: authorized_payout_key,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+    }
+}
+
+/// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{
+        testutils::{storage::Persistent as _, Address as _, Ledger},
+        token, Address, Env, String,
+    };
+
+    // Test helper to create a mock token contract
+    fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::Client<'a> {
+        let token_address = env.register_stellar_asset_contract(admin.clone());
+        token::Client::new(env, &token_address)
+    }
+
+    // ========================================================================
+    // Program Registration Tests
+    // ========================================================================
+
+    fn setup_program_with_schedule(
+        env: &Env,
+        client: &ProgramEscrowContractClient<'static>,
+        authorized_key: &Address,
+        token: &Address,
+        program_id: &String,
+        total_amount: i128,
+        winner: &Address,
+        release_timestamp: u64,
+    ) {
+        // Register program
+        client.initialize_program(program_id, authorized_key, token, authorized_key, &None);
+        client.set_program_status(program_id, authorized_key, &ProgramStatus::Open);
+
+        // Create and fund token
+        let token_client = create_token_contract(env, authorized_key);
+        let token_admin = token::StellarAssetClient::new(env, &token_client.address);
+        token_admin.mint(authorized_key, &total_amount);
+
+        // Lock funds for program
+        token_client.approve(
+            authorized_key,
+            &env.current_contract_address(),
+            &total_amount,
+            &1000,
+        );
+        client.lock_program_funds(program_id, &total_amount);
+
+        // Create release schedule
+        client.create_program_release_schedule(
+            program_id,
+            &total_amount,
+            &release_timestamp,
+            &winner,
+        );
+    }
+
+    #[test]
+    fn test_single_program_release_schedule() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+        let authorized_key = Address::generate(&env);
+        let winner = Address::generate(&env);
+        let token = Address::generate(&env);
+        let program_id = String::from_str(&env, "Hackathon2024");
+        let amount = 1000_0000000;
+        let release_timestamp = 1000;
+
+        env.mock_all_auths();
+
+        // Setup program with schedule
+        setup_program_with_schedule(
+            &env,
+            &client,
+            &authorized_key,
+            &token,
+            &program_id,
+            amount,
+            &winner,
+            release_timestamp,
+        );
+
+        // Verify schedule was created
+        let schedule = client.get_program_release_schedule(&program_id, &1);
+        assert_eq!(schedule.schedule_id, 1);
+        assert_eq!(schedule.amount, amount);
+        assert_eq!(schedule.release_timestamp, release_timestamp);
+        assert_eq!(schedule.recipient, winner);
+        assert!(!schedule.released);
+
+        // Check pending schedules
+        let pending = client.get_pending_program_schedules(&program_id);
+        assert_eq!(pending.len(), 1);
+
+        // Event verification can be added later - focusing on core functionality
+    }
+
+    #[test]
+    fn test_multiple_program_release_schedules() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+        let authorized_key = Address::generate(&env);
+        let winner1 = Address::generate(&env);
+        let winner2 = Address::generate(&env);
+        let token = Address::generate(&env);
+        let program_id = String::from_str(&env, "Hackathon2024");
+        let amount1 = 600_0000000;
+        let amount2 = 400_0000000;
+        let total_amount = amount1 + amount2;
+
+        env.mock_all_auths();
+
+        // Register program
+        client.initialize_program(&program_id, &authorized_key, &token, &authorized_key, &None);
+
+        // Create and fund token
+        let token_client = create_token_contract(&env, &authorized_key);
+        let token_admin = token::StellarAssetClient::new(&env, &token_client.address);
+        token_admin.mint(&authorized_key, &total_amount);
+
+        // Lock funds for program
+        token_client.approve(
+            &authorized_key,
+            &env.current_contract_address(),
+            &total_amount,
+            &1000,
+        );
+        client.lock_program_funds(&program_id, &total_amount);
+
+        // Create first release schedule
+        client.create_program_release_schedule(&program_id, &amount1, &1000, &winner1.clone());
+
+        // Create second release schedule
+        client.create_program_release_schedule(&program_id, &amount2, &2000, &winner2.clone());
+
+        // Verify both schedules exist
+        let all_schedules = client.get_all_prog_release_schedules(&program_id);
+        assert_eq!(all_schedules.len(), 2);
+
+        // Verify schedule IDs
+        let schedule1 = client.get_program_release_schedule(&program_id, &1);
+        let schedule2 = client.get_program_release_schedule(&program_id, &2);
+        assert_eq!(schedule1.schedule_id, 1);
+        assert_eq!(schedule2.schedule_id, 2);
+
+        // Verify amounts
+        assert_eq!(schedule1.amount, amount1);
+        assert_eq!(schedule2.amount, amount2);
+
+        // Verify recipients
+        assert_eq!(schedule1.recipient, winner1);
+        assert_eq!(schedule2.recipient, winner2);
+
+        // Check pending schedules
+        let pending = client.get_pending_program_schedules(&program_id);
+        assert_eq!(pending.len(), 2);
+
+        // Event verification can be added later - focusing on core functionality
+    }
+
+    #[test]
+    fn test_program_automatic_release_at_timestamp() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+        let authorized_key = Address::generate(&env);
+        let winner = Address::generate(&env);
+        let token = Address::generate(&env);
+        let program_id = String::from_str(&env, "Hackathon2024");
+        let amount = 1000_0000000;
+        let release_timestamp = 1000;
+
+        env.mock_all_auths();
+
+        // Setup program with schedule
+        setup_program_with_schedule(
+            &env,
+            &client,
+            &authorized_key,
+            &token,
+            &program_id,
+            amount,
+            &winner,
+            release_timestamp,
+        );
+
+        // Try to release before timestamp (should fail)
+        env.ledger().set_timestamp(999);
+        let result = client.try_release_prog_schedule_automatic(&program_id, &1);
+        assert!(result.is_err());
+
+        // Advance time to after release timestamp
+        env.ledger().set_timestamp(1001);
+
+        // Release automatically
+        client.release_prog_schedule_automatic(&program_id, &1);
+
+        // Verify schedule was released
+        let schedule = client.get_program_release_schedule(&program_id, &1);
+        assert!(schedule.released);
+        assert_eq!(schedule.released_at, Some(1001));
+        assert_eq!(schedule.released_by, Some(env.current_contract_address()));
+
+        // Check no pending schedules
+        let pending = client.get_pending_program_schedules(&program_id);
+        assert_eq!(pending.len(), 0);
+
+        // Verify release history
+        let history = client.get_program_release_history(&program_id);
+        assert_eq!(history.len(), 1);
+        assert_eq!(history.get(0).unwrap().release_type, ReleaseType::Automatic);
+
+        // Event verification can be added later - focusing on core functionality
+    }
+
+    #[test]
+    fn test_program_manual_trigger_before_after_timestamp() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+        let authorized_key = Address::generate(&env);
+        let winner = Address::generate(&env);
+        let token = Address::generate(&env);
+        let program_id = String::from_str(&env, "Hackathon2024");
+        let amount = 1000_0000000;
+        let release_timestamp = 1000;
+
+        env.mock_all_auths();
+
+        // Setup program with schedule
+        setup_program_with_schedule(
+            &env,
+            &client,
+            &authorized_key,
+            &token,
+            &program_id,
+            amount,
+            &winner,
+            release_timestamp,
+        );
+
+        // Manually release before timestamp (authorized key can do this)
+        env.ledger().set_timestamp(999);
+        client.release_program_schedule_manual(&program_id, &1);
+
+        // Verify schedule was released
+        let schedule = client.get_program_release_schedule(&program_id, &1);
+        assert!(schedule.released);
+        assert_eq!(schedule.released_at, Some(999));
+        assert_eq!(schedule.released_by, Some(authorized_key.clone()));
+
+        // Verify release history
+        let history = client.get_program_release_history(&program_id);
+        assert_eq!(history.len(), 1);
+        assert_eq!(history.get(0).unwrap().release_type, ReleaseType::Manual);
+
+        // Event verification can be added later - focusing on core functionality
+    }
+
+    #[test]
+    fn test_verify_program_schedule_tracking_and_history() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+        let authorized_key = Address::generate(&env);
+        let winner1 = Address::generate(&env);
+        let winner2 = Address::generate(&env);
+        let token = Address::generate(&env);
+        let program_id = String::from_str(&env, "Hackathon2024");
+        let amount1 = 600_0000000;
+        let amount2 = 400_0000000;
+        let total_amount = amount1 + amount2;
+
+        env.mock_all_auths();
+
+        // Register program
+        client.initialize_program(&program_id, &authorized_key, &token, &authorized_key, &None);
+
+        // Create and fund token
+        let token_client = create_token_contract(&env, &authorized_key);
+        let token_admin = token::StellarAssetClient::new(&env, &token_client.address);
+        token_admin.mint(&authorized_key, &total_amount);
+
+        // Lock funds for program
+        token_client.approve(
+            &authorized_key,
+            &env.current_contract_address(),
+            &total_amount,
+            &1000,
+        );
+        client.lock_program_funds(&program_id, &total_amount);
+
+        // Create first schedule
+        client.create_program_release_schedule(&program_id, &amount1, &1000, &winner1.clone());
+
+        // Create second schedule
+        client.create_program_release_schedule(&program_id, &amount2, &2000, &winner2.clone());
+
+        // Release first schedule manually
+        client.release_program_schedule_manual(&program_id, &1);
+
+        // Advance time and release second schedule automatically
+        env.ledger().set_timestamp(2001);
+        client.release_prog_schedule_automatic(&program_id, &2);
+
+        // Verify complete history
+        let history = client.get_program_release_history(&program_id);
+        assert_eq!(history.len(), 2);
+
+        // Check first release (manual)
+        let first_release = history.get(0).unwrap();
+        assert_eq!(first_release.schedule_id, 1);
+        assert_eq!(first_release.amount, amount1);
+        assert_eq!(first_release.recipient, winner1);
+        assert_eq!(first_release.release_type, ReleaseType::Manual);
+
+        // Check second release (automatic)
+        let second_release = history.get(1).unwrap();
+        assert_eq!(second_release.schedule_id, 2);
+        assert_eq!(second_release.amount, amount2);
+        assert_eq!(second_release.recipient, winner2);
+        assert_eq!(second_release.release_type, ReleaseType::Automatic);
+
+        // Verify no pending schedules
+        let pending = client.get_pending_program_schedules(&program_id);
+        assert_eq!(pending.len(), 0);
+
+        // Verify all schedules are marked as released
+        let all_schedules = client.get_all_prog_release_schedules(&program_id);
+        assert_eq!(all_schedules.len(), 2);
+        assert!(all_schedules.get(0).unwrap().released);
+        assert!(all_schedules.get(1).unwrap().released);
+    }
+
+    #[test]
+    fn test_program_overlapping_schedules() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+        let authorized_key = Address::generate(&env);
+        let winner1 = Address::generate(&env);
+        let winner2 = Address::generate(&env);
+        let winner3 = Address::generate(&env);
+        let token = Address::generate(&env);
+        let program_id = String::from_str(&env, "Hackathon2024");
+        let amount1 = 300_0000000;
+        let amount2 = 300_0000000;
+        let amount3 = 400_0000000;
+        let total_amount = amount1 + amount2 + amount3;
+        let base_timestamp = 1000;
+
+        env.mock_all_auths();
+
+        // Register program
+        client.initialize_program(&program_id, &authorized_key, &token, &authorized_key, &None);
+
+        // Create and fund token
+        let token_client = create_token_contract(&env, &authorized_key);
+        let token_admin = token::StellarAssetClient::new(&env, &token_client.address);
+        token_admin.mint(&authorized_key, &total_amount);
+
+        // Lock funds for program
+        token_client.approve(
+            &authorized_key,
+            &env.current_contract_address(),
+            &total_amount,
+            &1000,
+        );
+        client.lock_program_funds(&program_id, &total_amount);
+
+        // Create overlapping schedules (all at same timestamp)
+        client.create_program_release_schedule(
+            &program_id,
+            &amount1,
+            &base_timestamp,
+            &winner1.clone(),
+        );
+
+        client.create_program_release_schedule(
+            &program_id,
+            &amount2,
+            &base_timestamp,
+            &winner2.clone(),
+        );
+
+        client.create_program_release_schedule(
+            &program_id,
+            &amount3,
+            &base_timestamp,
+            &winner3.clone(),
+        );
+
+        // Advance time to after release timestamp
+        env.ledger().set_timestamp(base_timestamp + 1);
+
+        // Check due schedules (should be all 3)
+        let due = client.get_due_program_schedules(&program_id);
+        assert_eq!(due.len(), 3);
+
+        // Release schedules one by one
+        client.release_prog_schedule_automatic(&program_id, &1);
+        client.release_prog_schedule_automatic(&program_id, &2);
+        client.release_prog_schedule_automatic(&program_id, &3);
+
+        // Verify all schedules are released
+        let pending = client.get_pending_program_schedules(&program_id);
+        assert_eq!(pending.len(), 0);
+
+        // Verify complete history
+        let history = client.get_program_release_history(&program_id);
+        assert_eq!(history.len(), 3);
+
+        // Verify all were automatic releases
+        for release in history.iter() {
+            assert_eq!(release.release_type, ReleaseType::Automatic);
+        }
+
+        // Event verification can be added later - focusing on core functionality
+    }
+
+    #[test]
+    fn test_register_single_program() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+        let backend = Address::generate(&env);
+        let token = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Hackathon2024");
+
+        // Register program
+        let program = client.initialize_program(&prog_id, &backend, &token, &backend, &None);
+
+        // Verify program data
+        assert_eq!(program.program_id, prog_id);
+        assert_eq!(program.authorized_payout_key, backend);
+        assert_eq!(program.token_address, token);
+        assert_eq!(program.total_funds, 0);
+        assert_eq!(program.remaining_balance, 0);
+        assert_eq!(program.payout_count, 0);
+        assert_eq!(client.get_payout_history(&prog_id, &0, &10).len(), 0);
+
+        // Verify it exists
+        assert!(client.program_exists(&prog_id));
+        assert_eq!(client.get_program_count(), 1);
+    }
+
+    #[test]
+    fn test_multiple_programs_isolation() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+        let backend1 = Address::generate(&env);
+        let backend2 = Address::generate(&env);
+        let backend3 = Address::generate(&env);
+        let token = Address::generate(&env);
+
+        // Register three programs
+        let prog1 = String::from_str(&env, "ETHGlobal2024");
+        let prog2 = String::from_str(&env, "Stellar2024");
+        let prog3 = String::from_str(&env, "BuildathonQ1");
+
+        client.initialize_program(&prog1, &backend1, &token, &backend1, &None);
+        client.initialize_program(&prog2, &backend2, &token, &backend2, &None);
+        client.initialize_program(&prog3, &backend3, &token, &backend3, &None);
+
+        // Verify all exist
+        assert!(client.program_exists(&prog1));
+        assert!(client.program_exists(&prog2));
+        assert!(client.program_exists(&prog3));
+        assert_eq!(client.get_program_count(), 3);
+
+        // Verify complete isolation
+        let info1 = client.get_program_info(&prog1);
+        let info2 = client.get_program_info(&prog2);
+        let info3 = client.get_program_info(&prog3);
+
+        assert_eq!(info1.program_id, prog1);
+        assert_eq!(info2.program_id, prog2);
+        assert_eq!(info3.program_id, prog3);
+
+        assert_eq!(info1.authorized_payout_key, backend1);
+        assert_eq!(info2.authorized_payout_key, backend2);
+        assert_eq!(info3.authorized_payout_key, backend3);
+
+        // Verify list programs
+        let programs = client.list_programs();
+        assert_eq!(programs.len(), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "Program already exists")]
+    fn test_duplicate_program_registration() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+        let backend = Address::generate(&env);
+        let token = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Hackathon2024");
+
+        // Register once - should succeed
+        client.initialize_program(&prog_id, &backend, &token, &backend, &None);
+
+        // Register again - should panic
+        client.initialize_program(&prog_id, &backend, &token, &backend, &None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Program ID cannot be empty")]
+    fn test_empty_program_id() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+        let backend = Address::generate(&env);
+        let token = Address::generate(&env);
+        let empty_id = String::from_str(&env, "");
+
+        client.initialize_program(&empty_id, &backend, &token, &backend, &None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Program not found")]
+    fn test_get_nonexistent_program() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+        let prog_id = String::from_str(&env, "DoesNotExist");
+        client.get_program_info(&prog_id);
+    }
+
+    // ========================================================================
+    // Fund Locking Tests
+    // ========================================================================
+
+    #[test]
+    fn test_lock_funds_single_program() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let token_client = create_token_contract(&env, &admin);
+
+        let backend = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Hackathon2024");
+
+        // Register program
+        client.initialize_program(&prog_id, &backend, &token_client.address, &backend, &None);
+
+        // Lock funds
+        let amount = 10_000_0000000i128; // 10,000 USDC
+        let updated = client.lock_program_funds(&prog_id, &amount);
+
+        assert_eq!(updated.total_funds, amount);
+        assert_eq!(updated.remaining_balance, amount);
+    }
+
+    #[test]
+    fn test_lock_funds_multiple_programs_isolation() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let token_client = create_token_contract(&env, &admin);
+
+        let backend1 = Address::generate(&env);
+        let backend2 = Address::generate(&env);
+
+        let prog1 = String::from_str(&env, "Program1");
+        let prog2 = String::from_str(&env, "Program2");
+
+        // Register programs
+        client.initialize_program(&prog1, &backend1, &token_client.address, &backend1, &None);
+        client.initialize_program(&prog2, &backend2, &token_client.address, &backend2, &None);
+
+        // Lock different amounts in each program
+        let amount1 = 5_000_0000000i128;
+        let amount2 = 10_000_0000000i128;
+
+        client.lock_program_funds(&prog1, &amount1);
+        client.lock_program_funds(&prog2, &amount2);
+
+        // Verify isolation - funds don't mix
+        let info1 = client.get_program_info(&prog1);
+        let info2 = client.get_program_info(&prog2);
+
+        assert_eq!(info1.total_funds, amount1);
+        assert_eq!(info1.remaining_balance, amount1);
+        assert_eq!(info2.total_funds, amount2);
+        assert_eq!(info2.remaining_balance, amount2);
+    }
+
+    #[test]
+    fn test_lock_funds_cumulative() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let token_client = create_token_contract(&env, &admin);
+
+        let backend = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Hackathon2024");
+
+        client.initialize_program(&prog_id, &backend, &token_client.address, &backend, &None);
+
+        // Lock funds multiple times
+        client.lock_program_funds(&prog_id, &1_000_0000000);
+        client.lock_program_funds(&prog_id, &2_000_0000000);
+        client.lock_program_funds(&prog_id, &3_000_0000000);
+
+        let info = client.get_program_info(&prog_id);
+        assert_eq!(info.total_funds, 6_000_0000000);
+        assert_eq!(info.remaining_balance, 6_000_0000000);
+    }
+
+    #[test]
+    #[should_panic(expected = "Amount must be greater than zero")]
+    fn test_lock_zero_funds() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+        let backend = Address::generate(&env);
+        let token = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Hackathon2024");
 
-        emit_update_admin(
-            &env,
-            UpdateAdminEvent {
-                admin: current_admin,
-                new_admin,
-                timestamp: current_time,
-            },
-        );
+        client.initialize_program(&prog_id, &backend, &token, &backend, &None);
+        client.lock_program_funds(&prog_id, &0);
     }
 
-    /// Update Authorized Payout Key
-    ///
-    /// # Arguments
-    /// * `new_admin` - New Authorized Payout Key address
-    /// Admin Require Auth
-    pub fn update_authorized_payout_key(
-        env: Env,
-        program_id: String,
-        authorized_payout_key: Address,
-    ) {
-        let current_admin = anti_abuse::get_admin(&env).unwrap();
-        current_admin.require_auth();
+    #[test]
+    fn test_lock_funds_from_pulls_tokens() {
+        let env = Env::default();
+        env.mock_all_auths();
 
-        let program_key = DataKey::Program(program_id.clone());
-        let mut program_data = Self::get_program_info(env.clone(), program_id);
-        program_data.authorized_payout_key = authorized_payout_key.clone();
-        env.storage().instance().set(&program_key, &program_data);
+        let admin = Address::generate(&env);
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let token_client = create_token_contract(&env, &admin);
+        let token_admin = token::StellarAssetClient::new(&env, &token_client.address);
 
-        emit_update_authorized_key(
-            &env,
-            UpdateAuthorizedKeyEvent {
-                old_authorized_payout_key: program_data.authorized_payout_key,
-                new_authorized_payout_key: authorized_payout_key,
-                timestamp: env.ledger().timestamp(),
-            },
-        );
-    }
-}
+        let backend = Address::generate(&env);
+        let funder = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Hackathon2024");
+        token_admin.mint(&funder, &10_000);
 
-/// Helper function to calculate total scheduled amount for a program.
-fn get_program_total_scheduled_amount(env: &Env, program_id: &String) -> i128 {
-    let next_id: u64 = env
-        .storage()
-        .persistent()
-        .get(&DataKey::NextScheduleId(program_id.clone()))
-        .unwrap_or(1);
-
-    let mut total = 0i128;
-    for schedule_id in 1..next_id {
-        if env
-            .storage()
-            .persistent()
-            .has(&DataKey::ReleaseSchedule(program_id.clone(), schedule_id))
-        {
-            let schedule: ProgramReleaseSchedule = env
-                .storage()
-                .persistent()
-                .get(&DataKey::ReleaseSchedule(program_id.clone(), schedule_id))
-                .unwrap();
-            if !schedule.released {
-                total += schedule.amount;
-            }
-        }
+        client.initialize_program(&prog_id, &backend, &token_client.address, &backend, &None);
+        let updated = client.lock_program_funds_from(&prog_id, &funder, &4_000);
+
+        assert_eq!(updated.total_funds, 4_000);
+        assert_eq!(updated.remaining_balance, 4_000);
+        assert_eq!(token_client.balance(&funder), 6_000);
+        assert_eq!(token_client.balance(&contract_id), 4_000);
+
+        let report = client.reconcile_program_balances();
+        assert_eq!(report.len(), 1);
+        let entry = report.get(0).unwrap();
+        assert_eq!(entry.token_address, token_client.address);
+        assert_eq!((entry.contract_balance, entry.total_remaining), (4_000, 4_000));
+        assert!(entry.is_covered);
     }
 
-    total
-}
+    #[test]
+    #[should_panic]
+    fn test_lock_funds_from_without_tokens() {
+        let env = Env::default();
+        env.mock_all_auths();
 
-/// ============================================================================
-// Tests
-// ============================================================================
+        let admin = Address::generate(&env);
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let token_client = create_token_contract(&env, &admin);
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use soroban_sdk::{
-        testutils::{Address as _, Ledger},
-        token, Address, Env, String,
-    };
+        let backend = Address::generate(&env);
+        let funder = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Hackathon2024");
 
-    // Test helper to create a mock token contract
-    fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::Client<'a> {
-        let token_address = env.register_stellar_asset_contract(admin.clone());
-        token::Client::new(env, &token_address)
+        client.initialize_program(&prog_id, &backend, &token_client.address, &backend, &None);
+        client.lock_program_funds_from(&prog_id, &funder, &4_000);
     }
 
-    // ========================================================================
-    // Program Registration Tests
-    // ========================================================================
+    #[test]
+    fn test_reconciliation_flags_unbacked_locks() {
+        let env = Env::default();
+        env.mock_all_auths();
 
-    fn setup_program_with_schedule(
-        env: &Env,
-        client: &ProgramEscrowContractClient<'static>,
-        authorized_key: &Address,
-        token: &Address,
-        program_id: &String,
-        total_amount: i128,
-        winner: &Address,
-        release_timestamp: u64,
-    ) {
-        // Register program
-        client.initialize_program(program_id, authorized_key, token, authorized_key, &None);
+        let admin = Address::generate(&env);
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let token_a = create_token_contract(&env, &admin);
+        let token_b = create_token_contract(&env, &admin);
+        token::StellarAssetClient::new(&env, &token_a.address).mint(&contract_id, &1_500);
 
-        // Create and fund token
-        let token_client = create_token_contract(env, authorized_key);
-        let token_admin = token::StellarAssetClient::new(env, &token_client.address);
-        token_admin.mint(authorized_key, &total_amount);
+        let backend = Address::generate(&env);
+        let prog1 = String::from_str(&env, "Program1");
+        let prog2 = String::from_str(&env, "Program2");
+        let prog3 = String::from_str(&env, "Program3");
 
-        // Lock funds for program
-        token_client.approve(
-            authorized_key,
-            &env.current_contract_address(),
-            &total_amount,
-            &1000,
-        );
-        client.lock_program_funds(program_id, &total_amount);
+        client.initialize_program(&prog1, &backend, &token_a.address, &backend, &None);
+        client.initialize_program(&prog2, &backend, &token_a.address, &backend, &None);
+        client.initialize_program(&prog3, &backend, &token_b.address, &backend, &None);
 
-        // Create release schedule
-        client.create_program_release_schedule(
-            program_id,
-            &total_amount,
-            &release_timestamp,
-            &winner,
-        );
+        // Recorded without any transfer backing them beyond the 1_500 minted above
+        client.lock_program_funds(&prog1, &1_000);
+        client.lock_program_funds(&prog2, &1_000);
+        client.lock_program_funds(&prog3, &500);
+
+        let report = client.reconcile_program_balances();
+        assert_eq!(report.len(), 2);
+
+        let entry_a = report.get(0).unwrap();
+        assert_eq!(entry_a.token_address, token_a.address);
+        assert_eq!((entry_a.contract_balance, entry_a.total_remaining), (1_500, 2_000));
+        assert!(!entry_a.is_covered);
+
+        let entry_b = report.get(1).unwrap();
+        assert_eq!(entry_b.token_address, token_b.address);
+        assert_eq!((entry_b.contract_balance, entry_b.total_remaining), (0, 500));
+        assert!(!entry_b.is_covered);
     }
 
     #[test]
-    fn test_single_program_release_schedule() {
+    fn test_expiry_refunds_sponsors_pro_rata() {
         let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
         let contract_id = env.register_contract(None, ProgramEscrowContract);
         let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let token_client = create_token_contract(&env, &admin);
+        let token_admin = token::StellarAssetClient::new(&env, &token_client.address);
 
-        let authorized_key = Address::generate(&env);
+        let backend = Address::generate(&env);
+        let organizer = Address::generate(&env);
+        let dust = Address::generate(&env);
         let winner = Address::generate(&env);
-        let token = Address::generate(&env);
-        let program_id = String::from_str(&env, "Hackathon2024");
-        let amount = 1000_0000000;
-        let release_timestamp = 1000;
+        let sponsor_a = Address::generate(&env);
+        let sponsor_b = Address::generate(&env);
+        let sponsor_c = Address::generate(&env);
+        for sponsor in [&sponsor_a, &sponsor_b, &sponsor_c] {
+            token_admin.mint(sponsor, &10_000);
+        }
 
-        env.mock_all_auths();
+        client.set_admin(&admin);
+        client.set_dust_recipient(&dust);
 
-        // Setup program with schedule
-        setup_program_with_schedule(
-            &env,
-            &client,
-            &authorized_key,
-            &token,
-            &program_id,
-            amount,
-            &winner,
-            release_timestamp,
-        );
+        let prog_id = String::from_str(&env, "Hackathon2024");
+        client.initialize_program(&prog_id, &backend, &token_client.address, &organizer, &Some(1_000));
+        client.set_program_status(&prog_id, &organizer, &ProgramStatus::Open);
+        client.lock_program_funds_from(&prog_id, &sponsor_a, &500);
+        client.lock_program_funds_from(&prog_id, &sponsor_b, &1_000);
+        client.lock_program_funds_from(&prog_id, &sponsor_c, &1_000);
+        env.ledger().set_timestamp(100);
+        client.lock_program_funds_from(&prog_id, &sponsor_a, &500);
+
+        let sponsors = client.get_program_sponsors(&prog_id);
+        assert_eq!(sponsors.len(), 3);
+        assert_eq!(sponsors.get(0).unwrap().sponsor, sponsor_a);
+        assert_eq!(sponsors.get(0).unwrap().contributed, 1_000);
+
+        // 2_000 left, split three ways with 2 units of dust
+        client.single_payout(&prog_id, &None, &winner, &1_000);
+        env.ledger().set_timestamp(2_000);
+        client.expire_program(&prog_id);
+
+        for sponsor in [&sponsor_a, &sponsor_b, &sponsor_c] {
+            assert_eq!(token_client.balance(sponsor), 9_000 + 666);
+        }
+        assert_eq!(token_client.balance(&dust), 2);
+        assert_eq!(token_client.balance(&organizer), 0);
+        assert_eq!(token_client.balance(&contract_id), 0);
+        assert_eq!(client.get_remaining_balance(&prog_id), 0);
+        assert_eq!(client.get_program_sponsors(&prog_id).get(1).unwrap().refunded, 666);
+    }
 
-        // Verify schedule was created
-        let schedule = client.get_program_release_schedule(&program_id, &1);
-        assert_eq!(schedule.schedule_id, 1);
-        assert_eq!(schedule.amount, amount);
-        assert_eq!(schedule.release_timestamp, release_timestamp);
-        assert_eq!(schedule.recipient, winner);
-        assert!(!schedule.released);
+    #[test]
+    fn test_close_refunds_organizer_funds_to_organizer() {
+        let env = Env::default();
+        env.mock_all_auths();
 
-        // Check pending schedules
-        let pending = client.get_pending_program_schedules(&program_id);
-        assert_eq!(pending.len(), 1);
+        let admin = Address::generate(&env);
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let token_client = create_token_contract(&env, &admin);
+        let token_admin = token::StellarAssetClient::new(&env, &token_client.address);
 
-        // Event verification can be added later - focusing on core functionality
+        let backend = Address::generate(&env);
+        let organizer = Address::generate(&env);
+        let winner = Address::generate(&env);
+        let sponsor_a = Address::generate(&env);
+        let sponsor_b = Address::generate(&env);
+        token_admin.mint(&sponsor_a, &1_000);
+        token_admin.mint(&sponsor_b, &2_000);
+        token_admin.mint(&contract_id, &1_000);
+
+        let prog_id = String::from_str(&env, "Hackathon2024");
+        client.initialize_program(&prog_id, &backend, &token_client.address, &organizer, &None);
+        client.set_program_status(&prog_id, &organizer, &ProgramStatus::Open);
+        client.lock_program_funds(&prog_id, &1_000);
+        client.lock_program_funds_from(&prog_id, &sponsor_a, &1_000);
+        env.ledger().set_timestamp(100);
+        client.lock_program_funds_from(&prog_id, &sponsor_b, &2_000);
+        assert_eq!(client.get_organizer_contribution(&prog_id), 1_000);
+
+        // Half of the 4_000 is spent, so every funder gets half back
+        client.single_payout(&prog_id, &None, &winner, &2_000);
+        client.set_program_status(&prog_id, &organizer, &ProgramStatus::Judging);
+        client.set_program_status(&prog_id, &organizer, &ProgramStatus::Payout);
+        client.close_program(&prog_id);
+
+        assert_eq!(token_client.balance(&organizer), 500);
+        assert_eq!(token_client.balance(&sponsor_a), 500);
+        assert_eq!(token_client.balance(&sponsor_b), 1_000);
+        assert_eq!(token_client.balance(&contract_id), 0);
+        let sponsors = client.get_program_sponsors(&prog_id);
+        assert_eq!(sponsors.get(0).unwrap().refunded, 500);
+        assert_eq!(sponsors.get(1).unwrap().refunded, 1_000);
     }
 
     #[test]
-    fn test_multiple_program_release_schedules() {
+    fn test_close_without_sponsors_refunds_organizer() {
         let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
         let contract_id = env.register_contract(None, ProgramEscrowContract);
         let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let token_client = create_token_contract(&env, &admin);
+        token::StellarAssetClient::new(&env, &token_client.address).mint(&contract_id, &3_000);
 
-        let authorized_key = Address::generate(&env);
-        let winner1 = Address::generate(&env);
-        let winner2 = Address::generate(&env);
-        let token = Address::generate(&env);
-        let program_id = String::from_str(&env, "Hackathon2024");
-        let amount1 = 600_0000000;
-        let amount2 = 400_0000000;
-        let total_amount = amount1 + amount2;
+        let backend = Address::generate(&env);
+        let organizer = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Hackathon2024");
 
-        env.mock_all_auths();
+        client.initialize_program(&prog_id, &backend, &token_client.address, &organizer, &None);
+        client.set_program_status(&prog_id, &organizer, &ProgramStatus::Open);
+        client.lock_program_funds(&prog_id, &3_000);
+        client.set_program_status(&prog_id, &organizer, &ProgramStatus::Judging);
+        client.set_program_status(&prog_id, &organizer, &ProgramStatus::Payout);
+        client.close_program(&prog_id);
+
+        assert_eq!(token_client.balance(&organizer), 3_000);
+        assert_eq!(client.get_remaining_balance(&prog_id), 0);
+        assert_eq!(client.get_program_sponsors(&prog_id).len(), 0);
+        assert_eq!(client.get_dust_recipient(), None);
+    }
 
-        // Register program
-        client.initialize_program(&program_id, &authorized_key, &token, &authorized_key, &None);
+    #[test]
+    fn test_program_fee_override_requires_approval() {
+        let env = Env::default();
+        env.mock_all_auths();
 
-        // Create and fund token
-        let token_client = create_token_contract(&env, &authorized_key);
+        let admin = Address::generate(&env);
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let token_client = create_token_contract(&env, &admin);
         let token_admin = token::StellarAssetClient::new(&env, &token_client.address);
-        token_admin.mint(&authorized_key, &total_amount);
 
-        // Lock funds for program
-        token_client.approve(
-            &authorized_key,
-            &env.current_contract_address(),
-            &total_amount,
-            &1000,
-        );
-        client.lock_program_funds(&program_id, &total_amount);
-
-        // Create first release schedule
-        client.create_program_release_schedule(&program_id, &amount1, &1000, &winner1.clone());
+        let backend = Address::generate(&env);
+        let funder = Address::generate(&env);
+        let treasury = Address::generate(&env);
+        let sponsor_treasury = Address::generate(&env);
+        token_admin.mint(&funder, &100_000);
 
-        // Create second release schedule
-        client.create_program_release_schedule(&program_id, &amount2, &2000, &winner2.clone());
+        client.set_admin(&admin);
+        client.update_fee_config(&Some(100), &Some(200), &Some(treasury.clone()), &Some(true));
 
-        // Verify both schedules exist
-        let all_schedules = client.get_all_prog_release_schedules(&program_id);
-        assert_eq!(all_schedules.len(), 2);
+        let prog1 = String::from_str(&env, "Program1");
+        let prog2 = String::from_str(&env, "Program2");
+        client.initialize_program(&prog1, &backend, &token_client.address, &backend, &None);
+        client.propose_program_fee_config(&prog1, &500, &0, &sponsor_treasury, &true);
+
+        // Registering another program no longer resets the defaults
+        client.initialize_program(&prog2, &backend, &token_client.address, &backend, &None);
+        assert_eq!(client.get_fee_config().lock_fee_rate, 100);
+        client.set_program_status(&prog1, &admin, &ProgramStatus::Open);
+        client.set_program_status(&prog2, &admin, &ProgramStatus::Open);
+
+        // The proposal is not in effect until approved
+        assert_eq!(client.get_program_fee_config(&prog1).lock_fee_rate, 100);
+        assert!(client.get_pending_program_fee_config(&prog1).is_some());
+
+        let pending = client.get_pending_program_fee_config(&prog1).unwrap();
+        client.approve_program_fee_config(&prog1, &pending);
+        assert_eq!(client.get_pending_program_fee_config(&prog1), None);
+        let effective = client.get_program_fee_config(&prog1);
+        assert_eq!((effective.lock_fee_rate, effective.payout_fee_rate), (500, 0));
+        assert_eq!(effective.fee_recipient, sponsor_treasury);
+
+        client.lock_program_funds_from(&prog1, &funder, &10_000);
+        assert_eq!(client.get_remaining_balance(&prog1), 9_500);
+        assert_eq!(token_client.balance(&sponsor_treasury), 500);
+
+        env.ledger().set_timestamp(100);
+        client.lock_program_funds_from(&prog2, &funder, &10_000);
+        assert_eq!(client.get_remaining_balance(&prog2), 9_900);
+        assert_eq!(token_client.balance(&treasury), 100);
+
+        // Payouts use each program's own rate
+        let winner = Address::generate(&env);
+        client.single_payout(&prog1, &None, &winner, &1_000);
+        assert_eq!(token_client.balance(&winner), 1_000);
+        env.ledger().set_timestamp(200);
+        client.single_payout(&prog2, &None, &winner, &1_000);
+        assert_eq!(token_client.balance(&winner), 1_000 + 980);
+        assert_eq!(token_client.balance(&treasury), 100 + 20);
+
+        client.clear_program_fee_config(&prog1);
+        assert_eq!(client.get_program_fee_config(&prog1), client.get_fee_config());
+    }
 
-        // Verify schedule IDs
-        let schedule1 = client.get_program_release_schedule(&program_id, &1);
-        let schedule2 = client.get_program_release_schedule(&program_id, &2);
-        assert_eq!(schedule1.schedule_id, 1);
-        assert_eq!(schedule2.schedule_id, 2);
+    #[test]
+    #[should_panic(expected = "Pending fee config does not match")]
+    fn test_program_fee_approval_rejects_replaced_proposal() {
+        let env = Env::default();
+        env.mock_all_auths();
 
-        // Verify amounts
-        assert_eq!(schedule1.amount, amount1);
-        assert_eq!(schedule2.amount, amount2);
+        let admin = Address::generate(&env);
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
 
-        // Verify recipients
-        assert_eq!(schedule1.recipient, winner1);
-        assert_eq!(schedule2.recipient, winner2);
+        let backend = Address::generate(&env);
+        let token = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Hackathon2024");
 
-        // Check pending schedules
-        let pending = client.get_pending_program_schedules(&program_id);
-        assert_eq!(pending.len(), 2);
+        client.set_admin(&admin);
+        client.initialize_program(&prog_id, &backend, &token, &backend, &None);
+        client.propose_program_fee_config(&prog_id, &100, &0, &backend, &true);
+        let reviewed = client.get_pending_program_fee_config(&prog_id).unwrap();
 
-        // Event verification can be added later - focusing on core functionality
+        // The proposer swaps in a higher rate before the admin approves
+        client.propose_program_fee_config(&prog_id, &1_000, &0, &backend, &true);
+        client.approve_program_fee_config(&prog_id, &reviewed);
     }
 
     #[test]
-    fn test_program_automatic_release_at_timestamp() {
+    #[should_panic(expected = "Invalid lock fee rate")]
+    fn test_program_fee_proposal_rejects_excessive_rate() {
         let env = Env::default();
+        env.mock_all_auths();
+
         let contract_id = env.register_contract(None, ProgramEscrowContract);
         let client = ProgramEscrowContractClient::new(&env, &contract_id);
 
-        let authorized_key = Address::generate(&env);
-        let winner = Address::generate(&env);
+        let backend = Address::generate(&env);
         let token = Address::generate(&env);
-        let program_id = String::from_str(&env, "Hackathon2024");
-        let amount = 1000_0000000;
-        let release_timestamp = 1000;
-
-        env.mock_all_auths();
-
-        // Setup program with schedule
-        setup_program_with_schedule(
-            &env,
-            &client,
-            &authorized_key,
-            &token,
-            &program_id,
-            amount,
-            &winner,
-            release_timestamp,
-        );
-
-        // Try to release before timestamp (should fail)
-        env.ledger().set_timestamp(999);
-        let result = client.try_release_prog_schedule_automatic(&program_id, &1);
-        assert!(result.is_err());
-
-        // Advance time to after release timestamp
-        env.ledger().set_timestamp(1001);
+        let prog_id = String::from_str(&env, "Hackathon2024");
 
-        // Release automatically
-        client.release_prog_schedule_automatic(&program_id, &1);
+        client.initialize_program(&prog_id, &backend, &token, &backend, &None);
+        client.propose_program_fee_config(&prog_id, &5_000, &0, &backend, &true);
+    }
 
-        // Verify schedule was released
-        let schedule = client.get_program_release_schedule(&program_id, &1);
-        assert!(schedule.released);
-        assert_eq!(schedule.released_at, Some(1001));
-        assert_eq!(schedule.released_by, Some(env.current_contract_address()));
+    #[test]
+    fn test_payout_history_pages() {
+        let env = Env::default();
+        env.mock_all_auths();
 
-        // Check no pending schedules
-        let pending = client.get_pending_program_schedules(&program_id);
-        assert_eq!(pending.len(), 0);
+        let admin = Address::generate(&env);
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let token_client = create_token_contract(&env, &admin);
+        token::StellarAssetClient::new(&env, &token_client.address).mint(&contract_id, &10_000);
 
-        // Verify release history
-        let history = client.get_program_release_history(&program_id);
-        assert_eq!(history.len(), 1);
-        assert_eq!(history.get(0).unwrap().release_type, ReleaseType::Automatic);
+        let backend = Address::generate(&env);
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Hackathon2024");
 
-        // Event verification can be added later - focusing on core functionality
+        client.initialize_program(&prog_id, &backend, &token_client.address, &backend, &None);
+        client.lock_program_funds(&prog_id, &10_000);
+        client.set_program_status(&prog_id, &backend, &ProgramStatus::Open);
+
+        let recipients = vec![&env, alice.clone(), bob.clone(), alice.clone()];
+        let amounts = vec![&env, 100, 200, 300];
+        client.batch_payout(&prog_id, &None, &recipients, &amounts);
+        env.ledger().set_timestamp(100);
+        let info = client.single_payout(&prog_id, &None, &bob, &400);
+        assert_eq!(info.payout_count, 4);
+
+        let page = client.get_payout_history(&prog_id, &0, &3);
+        assert_eq!(page.len(), 3);
+        assert_eq!(page.get(1).unwrap().recipient, bob);
+        let page = client.get_payout_history(&prog_id, &3, &3);
+        assert_eq!(page.len(), 1);
+        assert_eq!(page.get(0).unwrap().amount, 400);
+        assert_eq!(client.get_payout_history(&prog_id, &4, &3).len(), 0);
+
+        let alice_payouts = client.get_payouts_by_recipient(&prog_id, &alice, &0, &10);
+        assert_eq!(alice_payouts.len(), 2);
+        assert_eq!(alice_payouts.get(1).unwrap().amount, 300);
+        let bob_payouts = client.get_payouts_by_recipient(&prog_id, &bob, &1, &10);
+        assert_eq!(bob_payouts.len(), 1);
+        assert_eq!(bob_payouts.get(0).unwrap().amount, 400);
     }
 
     #[test]
-    fn test_program_manual_trigger_before_after_timestamp() {
+    #[should_panic(expected = "Invalid page limit")]
+    fn test_payout_history_rejects_oversized_page() {
         let env = Env::default();
         let contract_id = env.register_contract(None, ProgramEscrowContract);
         let client = ProgramEscrowContractClient::new(&env, &contract_id);
 
-        let authorized_key = Address::generate(&env);
-        let winner = Address::generate(&env);
+        let backend = Address::generate(&env);
         let token = Address::generate(&env);
-        let program_id = String::from_str(&env, "Hackathon2024");
-        let amount = 1000_0000000;
-        let release_timestamp = 1000;
+        let prog_id = String::from_str(&env, "Hackathon2024");
+
+        client.initialize_program(&prog_id, &backend, &token, &backend, &None);
+        client.get_payout_history(&prog_id, &0, &101);
+    }
 
+    #[test]
+    fn test_migrate_legacy_programs() {
+        let env = Env::default();
         env.mock_all_auths();
 
-        // Setup program with schedule
-        setup_program_with_schedule(
-            &env,
-            &client,
-            &authorized_key,
-            &token,
-            &program_id,
-            amount,
-            &winner,
-            release_timestamp,
-        );
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
 
-        // Manually release before timestamp (authorized key can do this)
-        env.ledger().set_timestamp(999);
-        client.release_program_schedule_manual(&program_id, &1);
+        let admin = Address::generate(&env);
+        let backend = Address::generate(&env);
+        let token = Address::generate(&env);
+        let winner = Address::generate(&env);
+        let prog1 = String::from_str(&env, "Legacy1");
+        let prog2 = String::from_str(&env, "Legacy2");
+        client.set_admin(&admin);
 
-        // Verify schedule was released
-        let schedule = client.get_program_release_schedule(&program_id, &1);
-        assert!(schedule.released);
-        assert_eq!(schedule.released_at, Some(999));
-        assert_eq!(schedule.released_by, Some(authorized_key.clone()));
+        // Lay out state the way earlier versions stored it
+        env.as_contract(&contract_id, || {
+            let mut registry: Vec<String> = vec![&env];
+            for program_id in [prog1.clone(), prog2.clone()] {
+                let legacy = LegacyProgramData {
+                    program_id: program_id.clone(),
+                    total_funds: 1_000,
+                    remaining_balance: 700,
+                    authorized_payout_key: backend.clone(),
+                    payout_history: vec![
+                        &env,
+                        PayoutRecord {
+                            recipient: winner.clone(),
+                            amount: 100,
+                            timestamp: 10,
+                        },
+                        PayoutRecord {
+                            recipient: winner.clone(),
+                            amount: 200,
+                            timestamp: 20,
+                        },
+                    ],
+                    token_address: token.clone(),
+                    deadline: None,
+                    organizer: backend.clone(),
+                };
+                env.storage()
+                    .instance()
+                    .set(&DataKey::Program(program_id.clone()), &legacy);
+                registry.push_back(program_id);
+            }
+            env.storage().instance().set(&PROGRAM_REGISTRY, &registry);
+
+            // One released and one pending release schedule on Legacy2
+            for (schedule_id, amount, released) in [(1u64, 100i128, true), (2, 250, false)] {
+                let schedule = ProgramReleaseSchedule {
+                    schedule_id,
+                    amount,
+                    release_timestamp: 1_000,
+                    recipient: winner.clone(),
+                    released,
+                    released_at: None,
+                    released_by: None,
+                };
+                env.storage().persistent().set(
+                    &DataKey::ReleaseSchedule(prog2.clone(), schedule_id),
+                    &schedule,
+                );
+            }
+            env.storage()
+                .persistent()
+                .set(&DataKey::NextScheduleId(prog2.clone()), &3u64);
+        });
+        assert!(!client.program_exists(&prog1));
+
+        // The limit also caps payout records, so Legacy1 spans two calls
+        assert_eq!(client.migrate_legacy_programs(&1), 2);
+        assert!(!client.program_exists(&prog1));
+        assert_eq!(client.migrate_legacy_programs(&1), 1);
+        assert!(client.program_exists(&prog1));
+        assert!(!client.program_exists(&prog2));
+        assert_eq!(client.migrate_legacy_programs(&10), 0);
 
-        // Verify release history
-        let history = client.get_program_release_history(&program_id);
-        assert_eq!(history.len(), 1);
-        assert_eq!(history.get(0).unwrap().release_type, ReleaseType::Manual);
+        let resumed = client.get_payout_history(&prog1, &0, &10);
+        assert_eq!(resumed.len(), 2);
+        assert_eq!(resumed.get(0).unwrap().amount, 100);
+        assert_eq!(resumed.get(1).unwrap().amount, 200);
+        assert_eq!(client.get_payouts_by_recipient(&prog1, &winner, &0, &10).len(), 2);
 
-        // Event verification can be added later - focusing on core functionality
+        assert_eq!(client.get_program_count(), 2);
+        assert_eq!(client.list_programs(), vec![&env, prog1.clone(), prog2.clone()]);
+
+        let info = client.get_program_info(&prog2);
+        assert_eq!(info.remaining_balance, 700);
+        assert_eq!(info.payout_count, 2);
+        assert_eq!(info.reserved_for_schedules, 250);
+        assert_eq!(client.get_available_balance(&prog2), 450);
+        assert_eq!(client.get_program_info(&prog1).reserved_for_schedules, 0);
+        let history = client.get_payout_history(&prog2, &0, &10);
+        assert_eq!(history.get(1).unwrap().amount, 200);
+        assert_eq!(client.get_payouts_by_recipient(&prog2, &winner, &0, &10).len(), 2);
+
+        env.as_contract(&contract_id, || {
+            assert!(!env.storage().instance().has(&PROGRAM_REGISTRY));
+            assert!(!env.storage().instance().has(&DataKey::Program(prog1.clone())));
+            let progress_key = DataKey::LegacyPayoutsMigrated(prog1.clone());
+            assert!(!env.storage().instance().has(&progress_key));
+
+            let persistent = env.storage().persistent();
+            for key in [
+                DataKey::Program(prog2.clone()),
+                DataKey::ProgramIdAt(1),
+                DataKey::ProgramCount,
+                DataKey::Payout(prog2.clone(), 1),
+                DataKey::RecipientPayout(prog2.clone(), winner.clone(), 1),
+                DataKey::RecipientPayoutCount(prog2.clone(), winner.clone()),
+            ] {
+                assert_eq!(persistent.get_ttl(&key), PROGRAM_TTL_EXTEND_TO);
+            }
+        });
     }
 
     #[test]
-    fn test_verify_program_schedule_tracking_and_history() {
+    fn test_program_entries_extend_ttl_on_write() {
         let env = Env::default();
-        let contract_id = env.register_contract(None, ProgramEscrowContract);
-        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let (client, organizer, _token, prog_id) = setup_lifecycle_program(&env);
+        client.set_program_status(&prog_id, &organizer, &ProgramStatus::Open);
+
+        let track_id = client.create_track(&prog_id, &String::from_str(&env, "DeFi"));
+        let grantee = Address::generate(&env);
+        let schedule_id =
+            client.create_program_vesting_schedule(&prog_id, &grantee, &1_000, &0, &0, &1_000, &0);
+
+        env.as_contract(&client.address, || {
+            let persistent = env.storage().persistent();
+            for key in [
+                DataKey::Program(prog_id.clone()),
+                DataKey::OrganizerFunds(prog_id.clone()),
+                DataKey::Track(prog_id.clone(), track_id),
+                DataKey::TrackCount(prog_id.clone()),
+                DataKey::VestingSchedule(prog_id.clone(), schedule_id),
+                DataKey::NextScheduleId(prog_id.clone()),
+            ] {
+                assert_eq!(persistent.get_ttl(&key), PROGRAM_TTL_EXTEND_TO);
+            }
+        });
+    }
 
-        let authorized_key = Address::generate(&env);
-        let winner1 = Address::generate(&env);
-        let winner2 = Address::generate(&env);
-        let token = Address::generate(&env);
-        let program_id = String::from_str(&env, "Hackathon2024");
-        let amount1 = 600_0000000;
-        let amount2 = 400_0000000;
-        let total_amount = amount1 + amount2;
+    // ========================================================================
+    // Lifecycle Tests
+    // ========================================================================
 
+    fn setup_lifecycle_program<'a>(
+        env: &Env,
+    ) -> (ProgramEscrowContractClient<'a>, Address, token::Client<'a>, String) {
         env.mock_all_auths();
+        let admin = Address::generate(env);
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(env, &contract_id);
+        let token_client = create_token_contract(env, &admin);
+        token::StellarAssetClient::new(env, &token_client.address).mint(&contract_id, &5_000);
+
+        let organizer = Address::generate(env);
+        let prog_id = String::from_str(env, "Hackathon2024");
+        client.initialize_program(&prog_id, &organizer, &token_client.address, &organizer, &None);
+        client.lock_program_funds(&prog_id, &5_000);
+        (client, organizer, token_client, prog_id)
+    }
 
-        // Register program
-        client.initialize_program(&program_id, &authorized_key, &token, &authorized_key, &None);
-
-        // Create and fund token
-        let token_client = create_token_contract(&env, &authorized_key);
-        let token_admin = token::StellarAssetClient::new(&env, &token_client.address);
-        token_admin.mint(&authorized_key, &total_amount);
+    #[test]
+    fn test_program_lifecycle_happy_path() {
+        let env = Env::default();
+        let (client, organizer, token_client, prog_id) = setup_lifecycle_program(&env);
+        assert_eq!(client.get_program_info(&prog_id).status, ProgramStatus::Draft);
 
-        // Lock funds for program
-        token_client.approve(
-            &authorized_key,
-            &env.current_contract_address(),
-            &total_amount,
-            &1000,
-        );
-        client.lock_program_funds(&program_id, &total_amount);
+        for status in [ProgramStatus::Open, ProgramStatus::Judging, ProgramStatus::Payout] {
+            let info = client.set_program_status(&prog_id, &organizer, &status);
+            assert_eq!(info.status, status);
+        }
 
-        // Create first schedule
-        client.create_program_release_schedule(&program_id, &amount1, &1000, &winner1.clone());
+        let winner = Address::generate(&env);
+        client.single_payout(&prog_id, &None, &winner, &3_000);
 
-        // Create second schedule
-        client.create_program_release_schedule(&program_id, &amount2, &2000, &winner2.clone());
+        let info = client.set_program_status(&prog_id, &organizer, &ProgramStatus::Closed);
+        assert_eq!(info.status, ProgramStatus::Closed);
+        assert_eq!(info.remaining_balance, 0);
+        assert_eq!(token_client.balance(&organizer), 2_000);
+    }
 
-        // Release first schedule manually
-        client.release_program_schedule_manual(&program_id, &1);
+    #[test]
+    #[should_panic(expected = "Payouts not allowed in current program state")]
+    fn test_no_payouts_in_draft() {
+        let env = Env::default();
+        let (client, _organizer, _token, prog_id) = setup_lifecycle_program(&env);
+        client.single_payout(&prog_id, &None, &Address::generate(&env), &1_000);
+    }
 
-        // Advance time and release second schedule automatically
-        env.ledger().set_timestamp(2001);
-        client.release_prog_schedule_automatic(&program_id, &2);
+    #[test]
+    #[should_panic(expected = "Program is not accepting funds")]
+    fn test_no_funding_after_cancel() {
+        let env = Env::default();
+        let (client, organizer, token_client, prog_id) = setup_lifecycle_program(&env);
 
-        // Verify complete history
-        let history = client.get_program_release_history(&program_id);
-        assert_eq!(history.len(), 2);
+        let info = client.set_program_status(&prog_id, &organizer, &ProgramStatus::Cancelled);
+        assert_eq!(info.remaining_balance, 0);
+        assert_eq!(token_client.balance(&organizer), 5_000);
 
-        // Check first release (manual)
-        let first_release = history.get(0).unwrap();
-        assert_eq!(first_release.schedule_id, 1);
-        assert_eq!(first_release.amount, amount1);
-        assert_eq!(first_release.recipient, winner1);
-        assert_eq!(first_release.release_type, ReleaseType::Manual);
+        env.ledger().set_timestamp(100);
+        client.lock_program_funds(&prog_id, &1_000);
+    }
 
-        // Check second release (automatic)
-        let second_release = history.get(1).unwrap();
-        assert_eq!(second_release.schedule_id, 2);
-        assert_eq!(second_release.amount, amount2);
-        assert_eq!(second_release.recipient, winner2);
-        assert_eq!(second_release.release_type, ReleaseType::Automatic);
+    #[test]
+    #[should_panic(expected = "Invalid program state transition")]
+    fn test_invalid_program_transition() {
+        let env = Env::default();
+        let (client, organizer, _token, prog_id) = setup_lifecycle_program(&env);
+        client.set_program_status(&prog_id, &organizer, &ProgramStatus::Payout);
+    }
 
-        // Verify no pending schedules
-        let pending = client.get_pending_program_schedules(&program_id);
-        assert_eq!(pending.len(), 0);
+    #[test]
+    #[should_panic(expected = "Invalid program state transition")]
+    fn test_close_program_follows_transition_rules() {
+        let env = Env::default();
+        let (client, organizer, _token, prog_id) = setup_lifecycle_program(&env);
+        client.set_program_status(&prog_id, &organizer, &ProgramStatus::Open);
+        client.close_program(&prog_id);
+    }
 
-        // Verify all schedules are marked as released
-        let all_schedules = client.get_all_prog_release_schedules(&program_id);
-        assert_eq!(all_schedules.len(), 2);
-        assert!(all_schedules.get(0).unwrap().released);
-        assert!(all_schedules.get(1).unwrap().released);
+    #[test]
+    #[should_panic(expected = "Contract is paused")]
+    fn test_program_transition_blocked_while_paused() {
+        let env = Env::default();
+        let (client, organizer, _token, prog_id) = setup_lifecycle_program(&env);
+        client.pause();
+        client.set_program_status(&prog_id, &organizer, &ProgramStatus::Open);
     }
 
     #[test]
-    fn test_program_overlapping_schedules() {
+    #[should_panic(expected = "only the organizer or admin")]
+    fn test_program_transition_requires_organizer_or_admin() {
         let env = Env::default();
-        let contract_id = env.register_contract(None, ProgramEscrowContract);
-        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let (client, _organizer, _token, prog_id) = setup_lifecycle_program(&env);
+        client.set_program_status(&prog_id, &Address::generate(&env), &ProgramStatus::Open);
+    }
 
-        let authorized_key = Address::generate(&env);
-        let winner1 = Address::generate(&env);
-        let winner2 = Address::generate(&env);
-        let winner3 = Address::generate(&env);
-        let token = Address::generate(&env);
-        let program_id = String::from_str(&env, "Hackathon2024");
-        let amount1 = 300_0000000;
-        let amount2 = 300_0000000;
-        let amount3 = 400_0000000;
-        let total_amount = amount1 + amount2 + amount3;
-        let base_timestamp = 1000;
+    // ========================================================================
+    // Prize Track Tests
+    // ========================================================================
 
-        env.mock_all_auths();
+    #[test]
+    fn test_track_budgets_and_payouts() {
+        let env = Env::default();
+        let (client, organizer, token_client, prog_id) = setup_lifecycle_program(&env);
+        client.set_program_status(&prog_id, &organizer, &ProgramStatus::Open);
 
-        // Register program
-        client.initialize_program(&program_id, &authorized_key, &token, &authorized_key, &None);
+        let defi = client.create_track(&prog_id, &String::from_str(&env, "DeFi"));
+        let tooling = client.create_track(&prog_id, &String::from_str(&env, "Tooling"));
+        assert_eq!((defi, tooling), (0, 1));
 
-        // Create and fund token
-        let token_client = create_token_contract(&env, &authorized_key);
-        let token_admin = token::StellarAssetClient::new(&env, &token_client.address);
-        token_admin.mint(&authorized_key, &total_amount);
+        client.move_track_funds(&prog_id, &None, &Some(defi), &3_000);
+        client.move_track_funds(&prog_id, &None, &Some(tooling), &1_500);
+        client.move_track_funds(&prog_id, &Some(defi), &Some(tooling), &500);
+        assert_eq!(client.get_unallocated_balance(&prog_id), 500);
 
-        // Lock funds for program
-        token_client.approve(
-            &authorized_key,
-            &env.current_contract_address(),
-            &total_amount,
-            &1000,
+        let winner = Address::generate(&env);
+        let recipients = vec![&env, winner.clone(), Address::generate(&env)];
+        let amounts = vec![&env, 1_000, 800];
+        client.batch_payout(&prog_id, &Some(defi), &recipients, &amounts);
+        client.single_payout(&prog_id, &Some(tooling), &winner, &2_000);
+        assert_eq!(token_client.balance(&winner), 3_000);
+
+        let tracks = client.get_program_tracks(&prog_id);
+        assert_eq!(tracks.len(), 2);
+        let defi_track = tracks.get(0).unwrap();
+        assert_eq!(defi_track.name, String::from_str(&env, "DeFi"));
+        assert_eq!(
+            (defi_track.funded, defi_track.paid, defi_track.remaining),
+            (2_500, 1_800, 700)
+        );
+        let tooling_track = client.get_track(&prog_id, &tooling);
+        assert_eq!(
+            (tooling_track.funded, tooling_track.paid, tooling_track.remaining),
+            (2_000, 2_000, 0)
         );
-        client.lock_program_funds(&program_id, &total_amount);
 
-        // Create overlapping schedules (all at same timestamp)
-        client.create_program_release_schedule(
-            &program_id,
-            &amount1,
-            &base_timestamp,
-            &winner1.clone(),
+        // Unspent track budget can go back to the pool
+        client.move_track_funds(&prog_id, &Some(defi), &None, &700);
+        assert_eq!(client.get_unallocated_balance(&prog_id), 1_200);
+        assert_eq!(client.get_remaining_balance(&prog_id), 1_200);
+    }
+
+    #[test]
+    fn test_refund_clears_track_budgets() {
+        let env = Env::default();
+        let (client, organizer, token_client, prog_id) = setup_lifecycle_program(&env);
+        client.set_program_status(&prog_id, &organizer, &ProgramStatus::Open);
+
+        let defi = client.create_track(&prog_id, &String::from_str(&env, "DeFi"));
+        client.move_track_funds(&prog_id, &None, &Some(defi), &3_000);
+        client.single_payout(&prog_id, &Some(defi), &Address::generate(&env), &1_000);
+
+        let info = client.set_program_status(&prog_id, &organizer, &ProgramStatus::Cancelled);
+        assert_eq!(info.remaining_balance, 0);
+        assert_eq!(token_client.balance(&organizer), 4_000);
+
+        let track = client.get_track(&prog_id, &defi);
+        assert_eq!(
+            (track.funded, track.paid, track.remaining),
+            (3_000, 1_000, 0)
         );
+        assert_eq!(client.get_unallocated_balance(&prog_id), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Insufficient track budget")]
+    fn test_payout_cannot_exceed_track_budget() {
+        let env = Env::default();
+        let (client, organizer, _token, prog_id) = setup_lifecycle_program(&env);
+        client.set_program_status(&prog_id, &organizer, &ProgramStatus::Open);
+
+        let track = client.create_track(&prog_id, &String::from_str(&env, "Education"));
+        client.move_track_funds(&prog_id, &None, &Some(track), &1_000);
+        client.single_payout(&prog_id, &Some(track), &Address::generate(&env), &1_001);
+    }
+
+    #[test]
+    #[should_panic(expected = "Contract is paused")]
+    fn test_create_track_blocked_while_paused() {
+        let env = Env::default();
+        let (client, organizer, _token, prog_id) = setup_lifecycle_program(&env);
+        client.set_program_status(&prog_id, &organizer, &ProgramStatus::Open);
+
+        client.pause();
+        client.create_track(&prog_id, &String::from_str(&env, "DeFi"));
+    }
+
+    #[test]
+    #[should_panic(expected = "Contract is paused")]
+    fn test_move_track_funds_blocked_while_paused() {
+        let env = Env::default();
+        let (client, organizer, _token, prog_id) = setup_lifecycle_program(&env);
+        client.set_program_status(&prog_id, &organizer, &ProgramStatus::Open);
+        let track = client.create_track(&prog_id, &String::from_str(&env, "DeFi"));
 
-        client.create_program_release_schedule(
-            &program_id,
-            &amount2,
-            &base_timestamp,
-            &winner2.clone(),
-        );
+        client.pause();
+        client.move_track_funds(&prog_id, &None, &Some(track), &1_000);
+    }
 
-        client.create_program_release_schedule(
-            &program_id,
-            &amount3,
-            &base_timestamp,
-            &winner3.clone(),
-        );
+    #[test]
+    #[should_panic(expected = "Program is not accepting funds")]
+    fn test_move_track_funds_rejected_after_close() {
+        let env = Env::default();
+        let (client, organizer, _token, prog_id) = setup_lifecycle_program(&env);
+        client.set_program_status(&prog_id, &organizer, &ProgramStatus::Open);
+        let track = client.create_track(&prog_id, &String::from_str(&env, "DeFi"));
+        client.move_track_funds(&prog_id, &None, &Some(track), &1_000);
+
+        client.set_program_status(&prog_id, &organizer, &ProgramStatus::Judging);
+        client.set_program_status(&prog_id, &organizer, &ProgramStatus::Payout);
+        client.set_program_status(&prog_id, &organizer, &ProgramStatus::Closed);
+        client.move_track_funds(&prog_id, &Some(track), &None, &500);
+    }
 
-        // Advance time to after release timestamp
-        env.ledger().set_timestamp(base_timestamp + 1);
+    #[test]
+    #[should_panic(expected = "Insufficient unallocated balance")]
+    fn test_pool_payout_cannot_spend_track_budget() {
+        let env = Env::default();
+        let (client, organizer, _token, prog_id) = setup_lifecycle_program(&env);
+        client.set_program_status(&prog_id, &organizer, &ProgramStatus::Open);
 
-        // Check due schedules (should be all 3)
-        let due = client.get_due_program_schedules(&program_id);
-        assert_eq!(due.len(), 3);
+        let track = client.create_track(&prog_id, &String::from_str(&env, "DeFi"));
+        client.move_track_funds(&prog_id, &None, &Some(track), &4_000);
+        client.single_payout(&prog_id, &None, &Address::generate(&env), &1_500);
+    }
 
-        // Release schedules one by one
-        client.release_prog_schedule_automatic(&program_id, &1);
-        client.release_prog_schedule_automatic(&program_id, &2);
-        client.release_prog_schedule_automatic(&program_id, &3);
+    #[test]
+    fn test_reconcile_counts_unclaimed_distributions() {
+        let env = Env::default();
+        let (client, organizer, token_client, prog_id) = setup_lifecycle_program(&env);
+        client.set_program_status(&prog_id, &organizer, &ProgramStatus::Open);
+
+        let winners = [
+            (Address::generate(&env), 1_000),
+            (Address::generate(&env), 700),
+            (Address::generate(&env), 300),
+        ];
+        let (root, proofs) = build_distribution(&env, &winners);
+        let id = client.publish_distribution(&prog_id, &root, &2_000, &1_000);
+        client.claim(&prog_id, &id, &0, &winners[0].0, &1_000, &proofs[0]);
+
+        // 3_000 unallocated plus 1_000 still claimable
+        let entry = client.reconcile_program_balances().get(0).unwrap();
+        assert_eq!(entry.token_address, token_client.address);
+        assert_eq!(
+            (entry.contract_balance, entry.total_remaining),
+            (4_000, 4_000)
+        );
+        assert!(entry.is_covered);
+
+        env.ledger().set_timestamp(1_001);
+        client.finalize_distribution(&prog_id, &id);
+        let entry = client.reconcile_program_balances().get(0).unwrap();
+        assert_eq!(
+            (entry.contract_balance, entry.total_remaining),
+            (4_000, 4_000)
+        );
+    }
 
-        // Verify all schedules are released
-        let pending = client.get_pending_program_schedules(&program_id);
-        assert_eq!(pending.len(), 0);
+    // ========================================================================
+    // Merkle Distribution Tests
+    // ========================================================================
 
-        // Verify complete history
-        let history = client.get_program_release_history(&program_id);
-        assert_eq!(history.len(), 3);
+    /// Three-leaf tree: root = H(H(l0, l1), l2)
+    fn build_distribution(
+        env: &Env,
+        winners: &[(Address, i128); 3],
+    ) -> (BytesN<32>, [Vec<BytesN<32>>; 3]) {
+        let leaves: [BytesN<32>; 3] = [
+            merkle::leaf_hash(env, 0, &winners[0].0, winners[0].1),
+            merkle::leaf_hash(env, 1, &winners[1].0, winners[1].1),
+            merkle::leaf_hash(env, 2, &winners[2].0, winners[2].1),
+        ];
+        let left = merkle::node_hash(env, &leaves[0], &leaves[1]);
+        let root = merkle::node_hash(env, &left, &leaves[2]);
+        let proofs = [
+            vec![env, leaves[1].clone(), leaves[2].clone()],
+            vec![env, leaves[0].clone(), leaves[2].clone()],
+            vec![env, left],
+        ];
+        (root, proofs)
+    }
 
-        // Verify all were automatic releases
-        for release in history.iter() {
-            assert_eq!(release.release_type, ReleaseType::Automatic);
-        }
+    #[test]
+    fn test_distribution_claims_and_finalize() {
+        let env = Env::default();
+        let (client, organizer, token_client, prog_id) = setup_lifecycle_program(&env);
+        client.set_program_status(&prog_id, &organizer, &ProgramStatus::Open);
+
+        let winners = [
+            (Address::generate(&env), 1_000),
+            (Address::generate(&env), 700),
+            (Address::generate(&env), 300),
+        ];
+        let (root, proofs) = build_distribution(&env, &winners);
+
+        let id = client.publish_distribution(&prog_id, &root, &2_000, &1_000);
+        assert_eq!(client.get_remaining_balance(&prog_id), 3_000);
+
+        client.claim(&prog_id, &id, &0, &winners[0].0, &1_000, &proofs[0]);
+        client.claim(&prog_id, &id, &2, &winners[2].0, &300, &proofs[2]);
+        assert_eq!(token_client.balance(&winners[0].0), 1_000);
+        assert_eq!(token_client.balance(&winners[2].0), 300);
+        assert!(client.is_claimed(&prog_id, &id, &2));
+        assert!(!client.is_claimed(&prog_id, &id, &1));
+        assert_eq!(client.get_program_info(&prog_id).payout_count, 2);
+
+        // Winner 1 never claims; their prize returns to the program
+        env.ledger().set_timestamp(1_001);
+        assert_eq!(client.finalize_distribution(&prog_id, &id), 700);
+        assert_eq!(client.get_remaining_balance(&prog_id), 3_700);
+        let distribution = client.get_distribution(&prog_id, &id);
+        assert_eq!(distribution.claimed, 1_300);
+        assert!(distribution.finalized);
+    }
 
-        // Event verification can be added later - focusing on core functionality
+    #[test]
+    fn test_finalize_after_close_refunds_unclaimed() {
+        let env = Env::default();
+        let (client, organizer, token_client, prog_id) = setup_lifecycle_program(&env);
+        client.set_program_status(&prog_id, &organizer, &ProgramStatus::Open);
+
+        let winners = [
+            (Address::generate(&env), 1_000),
+            (Address::generate(&env), 700),
+            (Address::generate(&env), 300),
+        ];
+        let (root, proofs) = build_distribution(&env, &winners);
+        let id = client.publish_distribution(&prog_id, &root, &2_000, &1_000);
+        client.claim(&prog_id, &id, &0, &winners[0].0, &1_000, &proofs[0]);
+
+        // Cancelling refunds the 3_000 outside the distribution
+        client.set_program_status(&prog_id, &organizer, &ProgramStatus::Cancelled);
+        assert_eq!(token_client.balance(&organizer), 3_000);
+
+        // No need to wait for the claim deadline once the program is over
+        assert_eq!(client.finalize_distribution(&prog_id, &id), 1_000);
+        assert_eq!(token_client.balance(&organizer), 4_000);
+        assert_eq!(client.get_remaining_balance(&prog_id), 0);
+        assert!(client.get_distribution(&prog_id, &id).finalized);
     }
 
     #[test]
-    fn test_register_single_program() {
+    #[should_panic(expected = "Payouts not allowed in current program state")]
+    fn test_claim_after_close_rejected() {
         let env = Env::default();
-        let contract_id = env.register_contract(None, ProgramEscrowContract);
-        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let (client, organizer, _token, prog_id) = setup_lifecycle_program(&env);
+        client.set_program_status(&prog_id, &organizer, &ProgramStatus::Open);
+
+        let winners = [
+            (Address::generate(&env), 1_000),
+            (Address::generate(&env), 700),
+            (Address::generate(&env), 300),
+        ];
+        let (root, proofs) = build_distribution(&env, &winners);
+        let id = client.publish_distribution(&prog_id, &root, &2_000, &1_000);
+
+        client.set_program_status(&prog_id, &organizer, &ProgramStatus::Cancelled);
+        client.claim(&prog_id, &id, &1, &winners[1].0, &700, &proofs[1]);
+    }
 
-        let backend = Address::generate(&env);
-        let token = Address::generate(&env);
-        let prog_id = String::from_str(&env, "Hackathon2024");
+    #[test]
+    #[should_panic(expected = "Contract is paused")]
+    fn test_finalize_blocked_while_paused() {
+        let env = Env::default();
+        let (client, organizer, _token, prog_id) = setup_lifecycle_program(&env);
+        client.set_program_status(&prog_id, &organizer, &ProgramStatus::Open);
+
+        let winners = [
+            (Address::generate(&env), 1_000),
+            (Address::generate(&env), 700),
+            (Address::generate(&env), 300),
+        ];
+        let (root, _proofs) = build_distribution(&env, &winners);
+        let id = client.publish_distribution(&prog_id, &root, &2_000, &1_000);
+
+        env.ledger().set_timestamp(1_001);
+        client.pause();
+        client.finalize_distribution(&prog_id, &id);
+    }
 
-        // Register program
-        let program = client.initialize_program(&prog_id, &backend, &token, &backend, &None);
+    #[test]
+    #[should_panic(expected = "Already claimed")]
+    fn test_distribution_double_claim() {
+        let env = Env::default();
+        let (client, organizer, _token, prog_id) = setup_lifecycle_program(&env);
+        client.set_program_status(&prog_id, &organizer, &ProgramStatus::Open);
+
+        let winners = [
+            (Address::generate(&env), 1_000),
+            (Address::generate(&env), 700),
+            (Address::generate(&env), 300),
+        ];
+        let (root, proofs) = build_distribution(&env, &winners);
+        let id = client.publish_distribution(&prog_id, &root, &2_000, &1_000);
+
+        client.claim(&prog_id, &id, &1, &winners[1].0, &700, &proofs[1]);
+        client.claim(&prog_id, &id, &1, &winners[1].0, &700, &proofs[1]);
+    }
 
-        // Verify program data
-        assert_eq!(program.program_id, prog_id);
-        assert_eq!(program.authorized_payout_key, backend);
-        assert_eq!(program.token_address, token);
-        assert_eq!(program.total_funds, 0);
-        assert_eq!(program.remaining_balance, 0);
-        assert_eq!(program.payout_history.len(), 0);
+    #[test]
+    #[should_panic(expected = "Invalid Merkle proof")]
+    fn test_distribution_rejects_wrong_amount() {
+        let env = Env::default();
+        let (client, organizer, _token, prog_id) = setup_lifecycle_program(&env);
+        client.set_program_status(&prog_id, &organizer, &ProgramStatus::Open);
+
+        let winners = [
+            (Address::generate(&env), 1_000),
+            (Address::generate(&env), 700),
+            (Address::generate(&env), 300),
+        ];
+        let (root, proofs) = build_distribution(&env, &winners);
+        let id = client.publish_distribution(&prog_id, &root, &2_000, &1_000);
+
+        client.claim(&prog_id, &id, &2, &winners[2].0, &1_300, &proofs[2]);
+    }
 
-        // Verify it exists
-        assert!(client.program_exists(&prog_id));
-        assert_eq!(client.get_program_count(), 1);
+    #[test]
+    #[should_panic(expected = "Claim period has ended")]
+    fn test_distribution_rejects_late_claim() {
+        let env = Env::default();
+        let (client, organizer, _token, prog_id) = setup_lifecycle_program(&env);
+        client.set_program_status(&prog_id, &organizer, &ProgramStatus::Open);
+
+        let winners = [
+            (Address::generate(&env), 1_000),
+            (Address::generate(&env), 700),
+            (Address::generate(&env), 300),
+        ];
+        let (root, proofs) = build_distribution(&env, &winners);
+        let id = client.publish_distribution(&prog_id, &root, &2_000, &1_000);
+
+        env.ledger().set_timestamp(1_001);
+        client.claim(&prog_id, &id, &0, &winners[0].0, &1_000, &proofs[0]);
     }
 
+    // ========================================================================
+    // Vesting Tests
+    // ========================================================================
+
     #[test]
-    fn test_multiple_programs_isolation() {
+    fn test_vesting_cliff_and_linear_withdrawals() {
         let env = Env::default();
-        let contract_id = env.register_contract(None, ProgramEscrowContract);
-        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let (client, organizer, token_client, prog_id) = setup_lifecycle_program(&env);
+        client.set_program_status(&prog_id, &organizer, &ProgramStatus::Open);
 
-        let backend1 = Address::generate(&env);
-        let backend2 = Address::generate(&env);
-        let backend3 = Address::generate(&env);
-        let token = Address::generate(&env);
+        let grantee = Address::generate(&env);
+        let id = client
+            .create_program_vesting_schedule(&prog_id, &grantee, &4_000, &0, &1_000, &4_000, &0);
+        assert_eq!(client.get_withdrawable_vested(&prog_id, &id), 0);
 
-        // Register three programs
-        let prog1 = String::from_str(&env, "ETHGlobal2024");
-        let prog2 = String::from_str(&env, "Stellar2024");
-        let prog3 = String::from_str(&env, "BuildathonQ1");
+        env.ledger().set_timestamp(1_000);
+        assert_eq!(client.get_withdrawable_vested(&prog_id, &id), 1_000);
 
-        client.initialize_program(&prog1, &backend1, &token, &backend1, &None);
-        client.initialize_program(&prog2, &backend2, &token, &backend2, &None);
-        client.initialize_program(&prog3, &backend3, &token, &backend3, &None);
+        env.ledger().set_timestamp(2_500);
+        assert_eq!(client.withdraw_vested_release(&prog_id, &id), 2_500);
+        assert_eq!(client.get_withdrawable_vested(&prog_id, &id), 0);
 
-        // Verify all exist
-        assert!(client.program_exists(&prog1));
-        assert!(client.program_exists(&prog2));
-        assert!(client.program_exists(&prog3));
-        assert_eq!(client.get_program_count(), 3);
+        env.ledger().set_timestamp(10_000);
+        assert_eq!(client.withdraw_vested_release(&prog_id, &id), 1_500);
+        assert_eq!(token_client.balance(&grantee), 4_000);
+        assert_eq!(client.get_program_info(&prog_id).remaining_balance, 1_000);
 
-        // Verify complete isolation
-        let info1 = client.get_program_info(&prog1);
-        let info2 = client.get_program_info(&prog2);
-        let info3 = client.get_program_info(&prog3);
+        let history = client.get_program_release_history(&prog_id);
+        assert_eq!(history.len(), 2);
+        assert_eq!(history.get(0).unwrap().release_type, ReleaseType::Vesting);
+    }
 
-        assert_eq!(info1.program_id, prog1);
-        assert_eq!(info2.program_id, prog2);
-        assert_eq!(info3.program_id, prog3);
+    #[test]
+    fn test_vesting_periodic_tranches() {
+        let env = Env::default();
+        let (client, organizer, _token, prog_id) = setup_lifecycle_program(&env);
+        client.set_program_status(&prog_id, &organizer, &ProgramStatus::Open);
 
-        assert_eq!(info1.authorized_payout_key, backend1);
-        assert_eq!(info2.authorized_payout_key, backend2);
-        assert_eq!(info3.authorized_payout_key, backend3);
+        let grantee = Address::generate(&env);
+        let id = client
+            .create_program_vesting_schedule(&prog_id, &grantee, &4_000, &0, &0, &4_000, &1_000);
 
-        // Verify list programs
-        let programs = client.list_programs();
-        assert_eq!(programs.len(), 3);
+        env.ledger().set_timestamp(1_999);
+        assert_eq!(client.get_withdrawable_vested(&prog_id, &id), 1_000);
+        env.ledger().set_timestamp(2_000);
+        assert_eq!(client.get_withdrawable_vested(&prog_id, &id), 2_000);
     }
 
     #[test]
-    #[should_panic(expected = "Program already exists")]
-    fn test_duplicate_program_registration() {
+    fn test_vesting_revocation_returns_unvested() {
         let env = Env::default();
-        let contract_id = env.register_contract(None, ProgramEscrowContract);
-        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let (client, organizer, token_client, prog_id) = setup_lifecycle_program(&env);
+        client.set_program_status(&prog_id, &organizer, &ProgramStatus::Open);
 
-        let backend = Address::generate(&env);
-        let token = Address::generate(&env);
-        let prog_id = String::from_str(&env, "Hackathon2024");
+        let grantee = Address::generate(&env);
+        let id =
+            client.create_program_vesting_schedule(&prog_id, &grantee, &4_000, &0, &0, &4_000, &0);
 
-        // Register once - should succeed
-        client.initialize_program(&prog_id, &backend, &token, &backend, &None);
+        env.ledger().set_timestamp(1_000);
+        assert_eq!(client.revoke_vesting_schedule(&prog_id, &id), 3_000);
 
-        // Register again - should panic
-        client.initialize_program(&prog_id, &backend, &token, &backend, &None);
+        // Vested funds stay withdrawable, the rest can be scheduled again
+        env.ledger().set_timestamp(4_000);
+        assert_eq!(client.get_withdrawable_vested(&prog_id, &id), 1_000);
+        client.create_program_vesting_schedule(
+            &prog_id, &grantee, &4_000, &4_000, &4_000, &8_000, &0,
+        );
+        assert_eq!(client.withdraw_vested_release(&prog_id, &id), 1_000);
+        assert_eq!(token_client.balance(&grantee), 1_000);
     }
 
     #[test]
-    #[should_panic(expected = "Program ID cannot be empty")]
-    fn test_empty_program_id() {
+    #[should_panic(expected = "Insufficient balance for scheduled amount")]
+    fn test_vesting_cannot_overcommit_balance() {
         let env = Env::default();
-        let contract_id = env.register_contract(None, ProgramEscrowContract);
-        let client = ProgramEscrowContractClient::new(&env, &contract_id);
-
-        let backend = Address::generate(&env);
-        let token = Address::generate(&env);
-        let empty_id = String::from_str(&env, "");
+        let (client, _organizer, _token, prog_id) = setup_lifecycle_program(&env);
+        let grantee = Address::generate(&env);
+        client.create_program_vesting_schedule(&prog_id, &grantee, &3_000, &0, &0, &100, &0);
+        env.ledger().set_timestamp(61);
+        client.create_program_vesting_schedule(&prog_id, &grantee, &2_001, &0, &0, &100, &0);
+    }
 
-        client.initialize_program(&empty_id, &backend, &token, &backend, &None);
+    #[test]
+    #[should_panic(expected = "Invalid vesting period")]
+    fn test_vesting_rejects_cliff_after_end() {
+        let env = Env::default();
+        let (client, _organizer, _token, prog_id) = setup_lifecycle_program(&env);
+        let grantee = Address::generate(&env);
+        client.create_program_vesting_schedule(&prog_id, &grantee, &1_000, &0, &200, &100, &0);
     }
 
     #[test]
-    #[should_panic(expected = "Program not found")]
-    fn test_get_nonexistent_program() {
+    fn test_cancel_pays_vested_before_refunding() {
         let env = Env::default();
-        let contract_id = env.register_contract(None, ProgramEscrowContract);
-        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let (client, organizer, token_client, prog_id) = setup_lifecycle_program(&env);
+        client.set_program_status(&prog_id, &organizer, &ProgramStatus::Open);
+
+        let grantee = Address::generate(&env);
+        let id =
+            client.create_program_vesting_schedule(&prog_id, &grantee, &2_000, &0, &0, &2_000, &0);
+        env.ledger().set_timestamp(500);
+        assert_eq!(client.withdraw_vested_release(&prog_id, &id), 500);
+
+        // 1_000 vested, 500 of it not withdrawn yet
+        env.ledger().set_timestamp(1_000);
+        let info = client.set_program_status(&prog_id, &organizer, &ProgramStatus::Cancelled);
+        assert_eq!(info.remaining_balance, 0);
+        assert_eq!(info.reserved_for_schedules, 0);
+        assert_eq!(token_client.balance(&grantee), 1_000);
+        assert_eq!(token_client.balance(&organizer), 4_000);
+
+        let schedule = client.get_program_vesting_schedule(&prog_id, &id);
+        assert_eq!(schedule.total_amount, 1_000);
+        assert_eq!(schedule.withdrawn, 1_000);
+        assert_eq!(schedule.revoked_at, Some(1_000));
+        assert_eq!(client.get_program_release_history(&prog_id).len(), 2);
+    }
 
-        let prog_id = String::from_str(&env, "DoesNotExist");
-        client.get_program_info(&prog_id);
+    #[test]
+    #[should_panic(expected = "Contract is paused")]
+    fn test_vesting_revoke_blocked_while_paused() {
+        let env = Env::default();
+        let (client, organizer, _token, prog_id) = setup_lifecycle_program(&env);
+        client.set_program_status(&prog_id, &organizer, &ProgramStatus::Open);
+
+        let grantee = Address::generate(&env);
+        let id =
+            client.create_program_vesting_schedule(&prog_id, &grantee, &2_000, &0, &0, &2_000, &0);
+        client.pause();
+        client.revoke_vesting_schedule(&prog_id, &id);
     }
 
     // ========================================================================
-    // Fund Locking Tests
+    // Schedule Amendment Tests
     // ========================================================================
 
     #[test]
-    fn test_lock_funds_single_program() {
+    fn test_update_program_release_schedule() {
         let env = Env::default();
-        env.mock_all_auths();
-
-        let admin = Address::generate(&env);
-        let contract_id = env.register_contract(None, ProgramEscrowContract);
-        let client = ProgramEscrowContractClient::new(&env, &contract_id);
-        let token_client = create_token_contract(&env, &admin);
-
-        let backend = Address::generate(&env);
-        let prog_id = String::from_str(&env, "Hackathon2024");
+        let (client, organizer, _token, prog_id) = setup_lifecycle_program(&env);
+        client.set_program_status(&prog_id, &organizer, &ProgramStatus::Open);
+        let wrong = Address::generate(&env);
+        let winner = Address::generate(&env);
+        client.create_program_release_schedule(&prog_id, &3_000, &1_000, &wrong);
 
-        // Register program
-        client.initialize_program(&prog_id, &backend, &token_client.address, &backend, &None);
+        let schedule = client
+            .update_program_release_schedule(&prog_id, &1, &organizer, &5_000, &2_000, &winner);
+        assert_eq!(schedule.amount, 5_000);
+        assert_eq!(schedule.release_timestamp, 2_000);
+        assert_eq!(schedule.recipient, winner);
+        assert_eq!(client.get_program_release_schedule(&prog_id, &1), schedule);
+    }
 
-        // Lock funds
-        let amount = 10_000_0000000i128; // 10,000 USDC
-        let updated = client.lock_program_funds(&prog_id, &amount);
+    #[test]
+    #[should_panic(expected = "Insufficient balance for scheduled amount")]
+    fn test_update_program_release_schedule_respects_budget() {
+        let env = Env::default();
+        let (client, organizer, _token, prog_id) = setup_lifecycle_program(&env);
+        client.set_program_status(&prog_id, &organizer, &ProgramStatus::Open);
+        let winner = Address::generate(&env);
+        client.create_program_release_schedule(&prog_id, &3_000, &1_000, &winner);
+        env.ledger().set_timestamp(61);
+        client.create_program_release_schedule(&prog_id, &2_000, &1_000, &winner);
 
-        assert_eq!(updated.total_funds, amount);
-        assert_eq!(updated.remaining_balance, amount);
+        client.update_program_release_schedule(&prog_id, &1, &organizer, &3_001, &1_000, &winner);
     }
 
     #[test]
-    fn test_lock_funds_multiple_programs_isolation() {
+    fn test_cancel_program_release_schedule_frees_budget() {
         let env = Env::default();
-        env.mock_all_auths();
-
+        let (client, organizer, _token, prog_id) = setup_lifecycle_program(&env);
+        client.set_program_status(&prog_id, &organizer, &ProgramStatus::Open);
         let admin = Address::generate(&env);
-        let contract_id = env.register_contract(None, ProgramEscrowContract);
-        let client = ProgramEscrowContractClient::new(&env, &contract_id);
-        let token_client = create_token_contract(&env, &admin);
+        client.set_admin(&admin);
+        let winner = Address::generate(&env);
+        client.create_program_release_schedule(&prog_id, &5_000, &1_000, &winner);
+
+        // The admin can override the payout key
+        client.cancel_program_release_schedule(&prog_id, &1, &admin);
+        assert!(client
+            .try_get_program_release_schedule(&prog_id, &1)
+            .is_err());
+
+        env.ledger().set_timestamp(61);
+        client.create_program_release_schedule(&prog_id, &5_000, &1_000, &winner);
+        assert_eq!(
+            client.get_program_release_schedule(&prog_id, &2).amount,
+            5_000
+        );
+    }
 
-        let backend1 = Address::generate(&env);
-        let backend2 = Address::generate(&env);
+    #[test]
+    #[should_panic(expected = "Unauthorized: only the payout key or admin can modify schedules")]
+    fn test_cancel_program_release_schedule_unauthorized() {
+        let env = Env::default();
+        let (client, organizer, _token, prog_id) = setup_lifecycle_program(&env);
+        client.set_program_status(&prog_id, &organizer, &ProgramStatus::Open);
+        let winner = Address::generate(&env);
+        client.create_program_release_schedule(&prog_id, &1_000, &1_000, &winner);
 
-        let prog1 = String::from_str(&env, "Program1");
-        let prog2 = String::from_str(&env, "Program2");
+        client.cancel_program_release_schedule(&prog_id, &1, &winner);
+    }
 
-        // Register programs
-        client.initialize_program(&prog1, &backend1, &token_client.address, &backend1, &None);
-        client.initialize_program(&prog2, &backend2, &token_client.address, &backend2, &None);
+    #[test]
+    #[should_panic(expected = "Schedule already released")]
+    fn test_cancel_released_schedule_fails() {
+        let env = Env::default();
+        let (client, organizer, _token, prog_id) = setup_lifecycle_program(&env);
+        client.set_program_status(&prog_id, &organizer, &ProgramStatus::Open);
+        let winner = Address::generate(&env);
+        client.create_program_release_schedule(&prog_id, &1_000, &1_000, &winner);
+        client.release_program_schedule_manual(&prog_id, &1);
 
-        // Lock different amounts in each program
-        let amount1 = 5_000_0000000i128;
-        let amount2 = 10_000_0000000i128;
+        client.cancel_program_release_schedule(&prog_id, &1, &organizer);
+    }
 
-        client.lock_program_funds(&prog1, &amount1);
-        client.lock_program_funds(&prog2, &amount2);
+    #[test]
+    #[should_panic(expected = "Payouts not allowed in current program state")]
+    fn test_cancel_release_schedule_after_close_rejected() {
+        let env = Env::default();
+        let (client, organizer, _token, prog_id) = setup_lifecycle_program(&env);
+        client.set_program_status(&prog_id, &organizer, &ProgramStatus::Open);
+        let winner = Address::generate(&env);
+        client.create_program_release_schedule(&prog_id, &3_000, &1_000, &winner);
 
-        // Verify isolation - funds don't mix
-        let info1 = client.get_program_info(&prog1);
-        let info2 = client.get_program_info(&prog2);
+        client.set_program_status(&prog_id, &organizer, &ProgramStatus::Judging);
+        client.set_program_status(&prog_id, &organizer, &ProgramStatus::Payout);
+        client.set_program_status(&prog_id, &organizer, &ProgramStatus::Closed);
 
-        assert_eq!(info1.total_funds, amount1);
-        assert_eq!(info1.remaining_balance, amount1);
-        assert_eq!(info2.total_funds, amount2);
-        assert_eq!(info2.remaining_balance, amount2);
+        // Closing refunds the scheduled amount and drops the pending schedule
+        assert_eq!(client.get_pending_program_schedules(&prog_id).len(), 0);
+        assert_eq!(client.get_program_info(&prog_id).reserved_for_schedules, 0);
+
+        client.cancel_program_release_schedule(&prog_id, &1, &organizer);
     }
 
+    // ========================================================================
+    // Scheduled Budget Tests
+    // ========================================================================
+
     #[test]
-    fn test_lock_funds_cumulative() {
+    fn test_reserved_for_schedules_tracks_changes() {
         let env = Env::default();
-        env.mock_all_auths();
-
-        let admin = Address::generate(&env);
-        let contract_id = env.register_contract(None, ProgramEscrowContract);
-        let client = ProgramEscrowContractClient::new(&env, &contract_id);
-        let token_client = create_token_contract(&env, &admin);
-
-        let backend = Address::generate(&env);
-        let prog_id = String::from_str(&env, "Hackathon2024");
+        let (client, organizer, _token, prog_id) = setup_lifecycle_program(&env);
+        client.set_program_status(&prog_id, &organizer, &ProgramStatus::Open);
+        let winner = Address::generate(&env);
 
-        client.initialize_program(&prog_id, &backend, &token_client.address, &backend, &None);
+        client.create_program_release_schedule(&prog_id, &2_000, &1_000, &winner);
+        assert_eq!(
+            client.get_program_info(&prog_id).reserved_for_schedules,
+            2_000
+        );
+        assert_eq!(client.get_available_balance(&prog_id), 3_000);
 
-        // Lock funds multiple times
-        client.lock_program_funds(&prog_id, &1_000_0000000);
-        client.lock_program_funds(&prog_id, &2_000_0000000);
-        client.lock_program_funds(&prog_id, &3_000_0000000);
+        env.ledger().set_timestamp(61);
+        client.create_program_release_schedule(&prog_id, &1_000, &1_000, &winner);
+        client.update_program_release_schedule(&prog_id, &2, &organizer, &500, &1_000, &winner);
+        assert_eq!(client.get_available_balance(&prog_id), 2_500);
 
+        env.ledger().set_timestamp(122);
+        client.release_program_schedule_manual(&prog_id, &1);
+        client.cancel_program_release_schedule(&prog_id, &2, &organizer);
         let info = client.get_program_info(&prog_id);
-        assert_eq!(info.total_funds, 6_000_0000000);
-        assert_eq!(info.remaining_balance, 6_000_0000000);
+        assert_eq!(info.reserved_for_schedules, 0);
+        assert_eq!(info.remaining_balance, 3_000);
+        assert_eq!(client.get_available_balance(&prog_id), 3_000);
     }
 
     #[test]
-    #[should_panic(expected = "Amount must be greater than zero")]
-    fn test_lock_zero_funds() {
+    #[should_panic(expected = "Insufficient unallocated balance")]
+    fn test_single_payout_cannot_spend_reserved_funds() {
         let env = Env::default();
-        let contract_id = env.register_contract(None, ProgramEscrowContract);
-        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let (client, organizer, _token, prog_id) = setup_lifecycle_program(&env);
+        client.set_program_status(&prog_id, &organizer, &ProgramStatus::Open);
+        let grantee = Address::generate(&env);
+        client.create_program_vesting_schedule(&prog_id, &grantee, &4_000, &0, &0, &100, &0);
 
-        let backend = Address::generate(&env);
-        let token = Address::generate(&env);
-        let prog_id = String::from_str(&env, "Hackathon2024");
+        client.single_payout(&prog_id, &None, &Address::generate(&env), &1_001);
+    }
 
-        client.initialize_program(&prog_id, &backend, &token, &backend, &None);
-        client.lock_program_funds(&prog_id, &0);
+    #[test]
+    #[should_panic(expected = "Insufficient unallocated balance")]
+    fn test_batch_payout_cannot_spend_reserved_funds() {
+        let env = Env::default();
+        let (client, organizer, _token, prog_id) = setup_lifecycle_program(&env);
+        client.set_program_status(&prog_id, &organizer, &ProgramStatus::Open);
+        let winner = Address::generate(&env);
+        client.create_program_release_schedule(&prog_id, &4_500, &1_000, &winner);
+
+        let recipients = vec![&env, Address::generate(&env), Address::generate(&env)];
+        let amounts = vec![&env, 300, 300];
+        client.batch_payout(&prog_id, &None, &recipients, &amounts);
     }
 
     // ========================================================================
@@ -3268,11 +6530,12 @@ mod test {
 
         client.initialize_program(&prog_id, &backend, &token_client.address, &backend, &None);
         client.lock_program_funds(&prog_id, &10_000_0000000);
+        client.set_program_status(&prog_id, &backend, &ProgramStatus::Open);
 
         let recipients = soroban_sdk::vec![&env, Address::generate(&env), Address::generate(&env)];
         let amounts = soroban_sdk::vec![&env, 1_000_0000000i128]; // Mismatch!
 
-        client.batch_payout(&prog_id, &recipients, &amounts);
+        client.batch_payout(&prog_id, &None, &recipients, &amounts);
     }
 
     #[test]
@@ -3291,11 +6554,12 @@ mod test {
 
         client.initialize_program(&prog_id, &backend, &token_client.address, &backend, &None);
         client.lock_program_funds(&prog_id, &5_000_0000000);
+        client.set_program_status(&prog_id, &backend, &ProgramStatus::Open);
 
         let recipients = soroban_sdk::vec![&env, Address::generate(&env)];
         let amounts = soroban_sdk::vec![&env, 10_000_0000000i128]; // More than available!
 
-        client.batch_payout(&prog_id, &recipients, &amounts);
+        client.batch_payout(&prog_id, &None, &recipients, &amounts);
     }
 
     #[test]
@@ -19,10 +19,10 @@ fn test_program_escrow_reentrancy_blocked() {
     let res = client.try_lock_program_funds(&String::from_str(&env, "TEST"), &100);
     assert!(res.is_err());
     
-    let res = client.try_batch_payout(&String::from_str(&env, "TEST"), &soroban_sdk::vec![&env, Address::generate(&env)], &soroban_sdk::vec![&env, 100]);
+    let res = client.try_batch_payout(&String::from_str(&env, "TEST"), &None, &soroban_sdk::vec![&env, Address::generate(&env)], &soroban_sdk::vec![&env, 100]);
     assert!(res.is_err());
     
-    let res = client.try_single_payout(&String::from_str(&env, "TEST"), &Address::generate(&env), &100);
+    let res = client.try_single_payout(&String::from_str(&env, "TEST"), &None, &Address::generate(&env), &100);
     assert!(res.is_err());
     
     let res = client.try_create_program_release_schedule(&String::from_str(&env, "TEST"), &100, &1000, &Address::generate(&env));